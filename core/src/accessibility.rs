@@ -0,0 +1,225 @@
+//! Describe widgets to assistive technologies, like screen readers.
+use crate::{Rectangle, Vector};
+
+/// The role of an accessible widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// A button that can be clicked.
+    Button,
+    /// A checkbox that can be toggled.
+    CheckBox,
+    /// A radio button that can be selected.
+    RadioButton,
+    /// A switch that can be toggled.
+    Toggler,
+    /// A slider that selects a value in a range.
+    Slider,
+    /// An editable text field.
+    TextInput,
+    /// Some static text.
+    Text,
+    /// An image.
+    Image,
+    /// A bar indicating the progress of a task.
+    ProgressBar,
+    /// A container that can be scrolled.
+    ScrollView,
+    /// A group of accessible widgets.
+    Group,
+}
+
+/// An action that an assistive technology can perform on a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// The widget can be focused.
+    Focus,
+    /// The widget can be clicked.
+    Click,
+    /// The value of the widget can be set.
+    SetValue,
+}
+
+/// The description of an accessible widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The [`Role`] of the widget.
+    pub role: Role,
+    /// The label of the widget, if any.
+    pub label: Option<String>,
+    /// The current value of the widget, if any.
+    pub value: Option<String>,
+    /// Whether the widget is toggled or not, if it can be toggled.
+    pub toggled: Option<bool>,
+    /// Whether the widget is focused or not.
+    pub is_focused: bool,
+    /// Whether the widget is disabled or not.
+    pub is_disabled: bool,
+    /// The actions supported by the widget.
+    pub actions: Vec<Action>,
+}
+
+impl Node {
+    /// Creates a new [`Node`] with the given [`Role`].
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            toggled: None,
+            is_focused: false,
+            is_disabled: false,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Sets the label of the [`Node`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the current value of the [`Node`].
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets whether the [`Node`] is toggled or not.
+    pub fn toggled(mut self, toggled: bool) -> Self {
+        self.toggled = Some(toggled);
+        self
+    }
+
+    /// Sets whether the [`Node`] is focused or not.
+    pub fn focused(mut self, is_focused: bool) -> Self {
+        self.is_focused = is_focused;
+        self
+    }
+
+    /// Sets whether the [`Node`] is disabled or not.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Adds a supported [`Action`] to the [`Node`].
+    pub fn action(mut self, action: Action) -> Self {
+        if !self.actions.contains(&action) {
+            self.actions.push(action);
+        }
+
+        self
+    }
+}
+
+/// An action requested by an assistive technology.
+///
+/// The target of the action is identified by the [`Id`] of its [`Entry`] in
+/// the last [`Tree`] built for the user interface. Widgets learn their [`Id`]
+/// while the [`Tree`] is built.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The widget with the given [`Id`] should be focused.
+    Focus(Id),
+    /// The widget with the given [`Id`] should be clicked.
+    Click(Id),
+    /// The value of the widget with the given [`Id`] should be replaced.
+    SetValue(Id, String),
+}
+
+impl Event {
+    /// Returns the [`Id`] of the target of the [`Event`].
+    pub fn target(&self) -> Id {
+        match self {
+            Self::Focus(target)
+            | Self::Click(target)
+            | Self::SetValue(target, _) => *target,
+        }
+    }
+}
+
+/// The unique identifier of a [`Node`] in a [`Tree`].
+///
+/// It is derived from the position of the [`Node`] in the [`Tree`], so it
+/// stays the same as long as the structure of the user interface does not
+/// change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(pub u64);
+
+/// A [`Node`] in a [`Tree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// The [`Id`] of the [`Entry`].
+    pub id: Id,
+    /// The [`Node`] describing the widget.
+    pub node: Node,
+    /// The layout bounds of the widget.
+    pub bounds: Rectangle,
+    /// The scroll translation applied to the widget.
+    pub translation: Vector,
+    /// The children of the [`Entry`].
+    pub children: Vec<Id>,
+}
+
+impl Entry {
+    /// Returns the bounds of the widget as visible on the screen.
+    pub fn visible_bounds(&self) -> Rectangle {
+        self.bounds - self.translation
+    }
+
+    /// Returns the [`Event`] that performs the given [`Action`] on the
+    /// widget of the [`Entry`].
+    ///
+    /// The `value` is only used by [`Action::SetValue`].
+    pub fn event(&self, action: Action, value: Option<String>) -> Event {
+        match action {
+            Action::Focus => Event::Focus(self.id),
+            Action::Click => Event::Click(self.id),
+            Action::SetValue => {
+                Event::SetValue(self.id, value.unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// The accessibility tree of a user interface.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tree {
+    entries: Vec<Entry>,
+    roots: Vec<Id>,
+}
+
+impl Tree {
+    /// Creates a [`Tree`] from its entries and the [`Id`] of its roots.
+    pub fn new(entries: Vec<Entry>, roots: Vec<Id>) -> Self {
+        Self { entries, roots }
+    }
+
+    /// Returns the [`Id`] of the top-level entries of the [`Tree`].
+    pub fn roots(&self) -> &[Id] {
+        &self.roots
+    }
+
+    /// Returns the [`Entry`] with the given [`Id`], if any.
+    pub fn get(&self, id: Id) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// Returns an iterator over all the entries of the [`Tree`].
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Returns the [`Id`] of the focused [`Entry`], if any.
+    pub fn focus(&self) -> Option<Id> {
+        self.entries
+            .iter()
+            .find(|entry| entry.node.is_focused)
+            .map(|entry| entry.id)
+    }
+
+    /// Returns true if the [`Tree`] has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
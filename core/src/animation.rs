@@ -0,0 +1,285 @@
+//! Animate values over time.
+use crate::time::{Duration, Instant};
+use crate::{
+    Background, BorderRadius, Color, Point, Rectangle, Shadow, Size, Vector,
+};
+
+/// A value that transitions smoothly towards a target over time.
+///
+/// An [`Animation`] does not keep track of time by itself; the current
+/// [`Instant`] must be provided when querying it. This makes it easy to drive
+/// with the timestamps of `window::frames` or of the
+/// [`window::Event::RedrawRequested`] events of a widget.
+///
+/// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    started_at: Option<Instant>,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone,
+{
+    /// The default duration of an [`Animation`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(150);
+
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            started_at: None,
+            duration: Self::DEFAULT_DURATION,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Duration`] of the transitions of the [`Animation`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] curve of the transitions of the [`Animation`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Starts a transition towards the given target at the given [`Instant`].
+    ///
+    /// If the [`Animation`] is already moving, the new transition starts from
+    /// its current value; this way, animations can be interrupted and
+    /// retargeted without jumps.
+    pub fn go(&mut self, target: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = target;
+        self.started_at = Some(now);
+    }
+
+    /// Sets the value of the [`Animation`] immediately, stopping any
+    /// transition.
+    pub fn snap(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.started_at = None;
+    }
+
+    /// Returns the value of the [`Animation`] at the given [`Instant`].
+    pub fn value(&self, now: Instant) -> T {
+        match self.progress(now) {
+            Some(progress) => {
+                self.from.interpolate(&self.to, self.easing.apply(progress))
+            }
+            None => self.to.clone(),
+        }
+    }
+
+    /// Returns the target value of the [`Animation`].
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns true if the [`Animation`] is still transitioning at the given
+    /// [`Instant`].
+    ///
+    /// Redraws only need to be requested while this is true.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.progress(now).is_some()
+    }
+
+    fn progress(&self, now: Instant) -> Option<f32> {
+        let started_at = self.started_at?;
+        let elapsed = now.saturating_duration_since(started_at);
+
+        if elapsed >= self.duration {
+            return None;
+        }
+
+        Some(elapsed.as_secs_f32() / self.duration.as_secs_f32())
+    }
+}
+
+/// An easing curve, mapping the linear progress of an [`Animation`] to the
+/// progress of its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    #[default]
+    EaseOut,
+    /// Starts slow, accelerates and decelerates at the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given progress, between `0.0` and
+    /// `1.0`.
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Interpolates between `self` and `other` by the given ratio, where
+    /// `0.0` produces `self` and `1.0` produces `other`.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        self + (other - self) * ratio
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, ratio),
+            g: self.g.interpolate(&other.g, ratio),
+            b: self.b.interpolate(&other.b, ratio),
+            a: self.a.interpolate(&other.a, ratio),
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, ratio),
+            self.y.interpolate(&other.y, ratio),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, ratio),
+            self.height.interpolate(&other.height, ratio),
+        )
+    }
+}
+
+impl Interpolate for Rectangle {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&other.position(), ratio),
+            self.size().interpolate(&other.size(), ratio),
+        )
+    }
+}
+
+impl Interpolate for BorderRadius {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        let a: [f32; 4] = (*self).into();
+        let b: [f32; 4] = (*other).into();
+
+        BorderRadius::from([
+            a[0].interpolate(&b[0], ratio),
+            a[1].interpolate(&b[1], ratio),
+            a[2].interpolate(&b[2], ratio),
+            a[3].interpolate(&b[3], ratio),
+        ])
+    }
+}
+
+impl Interpolate for Shadow {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Shadow {
+            color: self.color.interpolate(&other.color, ratio),
+            offset: self.offset.interpolate(&other.offset, ratio),
+            blur_radius: self
+                .blur_radius
+                .interpolate(&other.blur_radius, ratio),
+        }
+    }
+}
+
+impl Interpolate for Background {
+    /// Solid colors are blended; any other backgrounds are swapped halfway
+    /// through.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, ratio))
+            }
+            _ if ratio < 0.5 => *self,
+            _ => *other,
+        }
+    }
+}
+
+impl<T> Interpolate for Option<T>
+where
+    T: Interpolate + Clone,
+{
+    /// Values present on both sides are interpolated; otherwise, they are
+    /// swapped halfway through.
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, ratio)),
+            _ if ratio < 0.5 => self.clone(),
+            _ => other.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Animation, Easing};
+    use crate::time::{Duration, Instant};
+
+    #[test]
+    fn retargeting_starts_from_the_current_value() {
+        let start = Instant::now();
+
+        let mut animation = Animation::new(0.0)
+            .duration(Duration::from_millis(100))
+            .easing(Easing::Linear);
+
+        animation.go(1.0, start);
+
+        let halfway = start + Duration::from_millis(50);
+        assert!(animation.is_animating(halfway));
+        assert!((animation.value(halfway) - 0.5).abs() < 1e-4);
+
+        animation.go(0.0, halfway);
+        assert!((animation.value(halfway) - 0.5).abs() < 1e-4);
+
+        let end = halfway + Duration::from_millis(100);
+        assert!(!animation.is_animating(end));
+        assert_eq!(animation.value(end), 0.0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::accessibility;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
@@ -342,8 +343,9 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
             ) {
-                self.operation.focusable(state, id);
+                self.operation.focusable(state, id, bounds);
             }
 
             fn scrollable(
@@ -364,6 +366,28 @@ where
                 self.operation.text_input(state, id);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn wants_text(&self) -> bool {
+                self.operation.wants_text()
+            }
+
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &accessibility::Node,
+            ) -> Option<accessibility::Id> {
+                self.operation.accessible(id, bounds, node)
+            }
+
             fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
                 self.operation.custom(state, id);
             }
@@ -548,6 +572,7 @@ where
                     border_color: color,
                     border_width: 1.0,
                     border_radius: 0.0.into(),
+                    ..renderer::Quad::default()
                 },
                 Color::TRANSPARENT,
             );
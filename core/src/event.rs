@@ -1,4 +1,6 @@
 //! Handle events of a user interface.
+use crate::accessibility;
+use crate::input_method;
 use crate::keyboard;
 use crate::mouse;
 use crate::touch;
@@ -19,11 +21,17 @@ pub enum Event {
     Mouse(mouse::Event),
 
     /// A window event
-    Window(window::Event),
+    Window(window::Id, window::Event),
 
     /// A touch event
     Touch(touch::Event),
 
+    /// An input method event
+    InputMethod(input_method::Event),
+
+    /// An accessibility event
+    Accessibility(accessibility::Event),
+
     /// A platform specific event
     PlatformSpecific(PlatformSpecific),
 }
@@ -1,29 +1,46 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Vector};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from a center.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around a center.
+    Conic(Conic),
 }
 
 impl Gradient {
     /// Adjust the opacity of the gradient by a multiplier applied to each color stop.
     pub fn mul_alpha(mut self, alpha_multiplier: f32) -> Self {
-        match &mut self {
-            Gradient::Linear(linear) => {
-                for stop in linear.stops.iter_mut().flatten() {
-                    stop.color.a *= alpha_multiplier;
-                }
-            }
+        for stop in self.stops_mut().iter_mut().flatten() {
+            stop.color.a *= alpha_multiplier;
         }
 
         self
     }
+
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
+        }
+    }
+
+    fn stops_mut(&mut self) -> &mut [Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &mut linear.stops,
+            Gradient::Radial(radial) => &mut radial.stops,
+            Gradient::Conic(conic) => &mut conic.stops,
+        }
+    }
 }
 
 impl From<Linear> for Gradient {
@@ -32,6 +49,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -70,19 +99,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -101,3 +118,123 @@ impl Linear {
         self
     }
 }
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The center of the [`Gradient`], relative to its bounds.
+    ///
+    /// `Point::new(0.5, 0.5)` is the center of the bounds.
+    pub center: Point,
+    /// The horizontal and vertical radii of the [`Gradient`], relative to
+    /// the size of its bounds.
+    ///
+    /// `Vector::new(0.5, 0.5)` reaches the edges of the bounds from its
+    /// center.
+    pub radii: Vector,
+    /// [`ColorStop`]s from the center of the radial gradient outwards.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient with the given relative center and
+    /// radii.
+    pub fn new(center: Point, radii: Vector) -> Self {
+        Self {
+            center,
+            radii,
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The center of the [`Gradient`], relative to its bounds.
+    ///
+    /// `Point::new(0.5, 0.5)` is the center of the bounds.
+    pub center: Point,
+    /// Where the [`Gradient`] starts, clockwise from the top.
+    pub angle: Radians,
+    /// [`ColorStop`]s clockwise around the conic gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient with the given relative center,
+    /// starting at the given angle in [`Radians`].
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
@@ -0,0 +1,100 @@
+//! Listen to input method events and request input method features.
+use crate::{Point, Vector};
+
+use std::ops::Range;
+
+/// An input method event.
+///
+/// Input methods (IME) let users compose text that cannot be typed directly
+/// with a keyboard, like Japanese or Chinese characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The input method was enabled.
+    ///
+    /// After this event, [`Preedit`](Self::Preedit) and [`Commit`](Self::Commit)
+    /// events may be received.
+    Opened,
+
+    /// A new composing text should be shown at the cursor position.
+    ///
+    /// The optional range is the byte-wise position of the cursor inside of
+    /// the composing text. When it is `None`, the cursor should be hidden.
+    ///
+    /// An empty composing text means the current one was cleared.
+    Preedit(String, Option<Range<usize>>),
+
+    /// Some text should be inserted at the cursor position.
+    ///
+    /// Any composing text should be cleared before inserting it.
+    Commit(String),
+
+    /// The input method was disabled.
+    ///
+    /// Any composing text should be cleared.
+    Closed,
+}
+
+/// The input method requested by a widget.
+///
+/// A focused text widget can request an [`InputMethod`] to enable text
+/// composition and to position the candidate window of the input method
+/// next to its cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum InputMethod {
+    /// No input method is allowed.
+    #[default]
+    Disabled,
+    /// Input methods are allowed.
+    Enabled {
+        /// The position at which the candidate window of the input method
+        /// should be displayed, in logical coordinates.
+        ///
+        /// This is normally the bottom left corner of the text cursor.
+        position: Point,
+        /// The [`Purpose`] of the input method.
+        purpose: Purpose,
+    },
+}
+
+impl InputMethod {
+    /// Returns true if the [`InputMethod`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::Enabled { .. })
+    }
+
+    /// Merges two [`InputMethod`] requests.
+    ///
+    /// The first [`InputMethod::Enabled`] request wins.
+    pub fn merge(&mut self, other: &Self) {
+        if !self.is_enabled() {
+            *self = *other;
+        }
+    }
+
+    /// Translates the position of the candidate window of the
+    /// [`InputMethod`], if enabled.
+    ///
+    /// Widgets displaying their contents somewhere else than where they are
+    /// laid out should translate the [`InputMethod`] requested by them.
+    pub fn translate(self, translation: Vector) -> Self {
+        match self {
+            Self::Enabled { position, purpose } => Self::Enabled {
+                position: position + translation,
+                purpose,
+            },
+            Self::Disabled => Self::Disabled,
+        }
+    }
+}
+
+/// The purpose of an [`InputMethod`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Purpose {
+    /// No special hints for the input method.
+    #[default]
+    Normal,
+    /// The input method is used for secure input, like passwords.
+    Secure,
+    /// The input method is used for a terminal.
+    Terminal,
+}
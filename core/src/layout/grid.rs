@@ -0,0 +1,368 @@
+//! Distribute elements in a grid of rows and columns.
+use crate::layout::{Limits, Node};
+use crate::widget;
+use crate::{Alignment, Element, Length, Padding, Point, Size};
+
+use std::ops::Range;
+
+/// The placement of an item in a grid layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    /// The row of the [`Cell`].
+    pub row: usize,
+
+    /// The column of the [`Cell`].
+    pub column: usize,
+
+    /// The amount of rows spanned by the [`Cell`].
+    pub row_span: usize,
+
+    /// The amount of columns spanned by the [`Cell`].
+    pub column_span: usize,
+
+    /// The horizontal alignment of the item inside the [`Cell`].
+    pub align_x: Alignment,
+
+    /// The vertical alignment of the item inside the [`Cell`].
+    pub align_y: Alignment,
+}
+
+impl Cell {
+    /// Creates a new [`Cell`] at the given row and column, spanning a single
+    /// track in each direction.
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+        }
+    }
+
+    /// Sets the amount of rows and columns spanned by the [`Cell`].
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows.max(1);
+        self.column_span = columns.max(1);
+        self
+    }
+
+    /// Sets the horizontal alignment of the item inside the [`Cell`].
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the item inside the [`Cell`].
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.align_y = alignment;
+        self
+    }
+
+    /// Returns the range of rows spanned by the [`Cell`].
+    pub fn rows(&self) -> Range<usize> {
+        self.row..self.row + self.row_span
+    }
+
+    /// Returns the range of columns spanned by the [`Cell`].
+    pub fn columns(&self) -> Range<usize> {
+        self.column..self.column + self.column_span
+    }
+
+    /// Returns true if the [`Cell`] shares any track intersection with
+    /// another [`Cell`].
+    pub fn overlaps(&self, other: &Cell) -> bool {
+        let rows = self.rows();
+        let columns = self.columns();
+
+        rows.start < other.rows().end
+            && other.rows().start < rows.end
+            && columns.start < other.columns().end
+            && other.columns().start < columns.end
+    }
+}
+
+/// Computes the grid layout of the given items, placed in their respective
+/// [`Cell`], with the given track sizes, applying spacing and padding as
+/// needed.
+///
+/// Tracks without an explicit [`Length`] shrink to fit their contents.
+///
+/// It returns a new layout [`Node`].
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    column_spacing: f32,
+    row_spacing: f32,
+    columns: &[Length],
+    rows: &[Length],
+    cells: &[Cell],
+    items: &[Element<'_, Message, Renderer>],
+    trees: &mut [widget::Tree],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max = limits.max();
+
+    let columns = tracks(columns, cells.iter().map(|cell| cell.columns()));
+    let rows = tracks(rows, cells.iter().map(|cell| cell.rows()));
+
+    // Columns are sized first, measuring items with the height of
+    // fixed rows...
+    let widths: Vec<_> = items
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(cells)
+        .map(|((item, tree), cell)| {
+            let widget = item.as_widget();
+
+            let max_width = fixed_extent(&columns[cell.columns()])
+                .map_or(max.width, |width| {
+                    width + column_spacing * (cell.column_span - 1) as f32
+                });
+            let max_height = fixed_extent(&rows[cell.rows()])
+                .map_or(max.height, |height| {
+                    height + row_spacing * (cell.row_span - 1) as f32
+                });
+
+            let width = if widget.width().fill_factor() == 0 {
+                widget
+                    .layout(
+                        tree,
+                        renderer,
+                        &Limits::new(
+                            Size::ZERO,
+                            Size::new(max_width, max_height),
+                        ),
+                    )
+                    .size()
+                    .width
+            } else {
+                0.0
+            };
+
+            (cell.columns(), width)
+        })
+        .collect();
+
+    let column_sizes = sizes(&columns, max.width, column_spacing, &widths);
+
+    // ...then rows are sized with the final width of the columns
+    let heights: Vec<_> = items
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(cells)
+        .map(|((item, tree), cell)| {
+            let widget = item.as_widget();
+
+            let width = extent(&column_sizes[cell.columns()], column_spacing);
+            let max_height = fixed_extent(&rows[cell.rows()])
+                .map_or(max.height, |height| {
+                    height + row_spacing * (cell.row_span - 1) as f32
+                });
+
+            let height = if widget.height().fill_factor() == 0 {
+                widget
+                    .layout(
+                        tree,
+                        renderer,
+                        &Limits::new(Size::ZERO, Size::new(width, max_height)),
+                    )
+                    .size()
+                    .height
+            } else {
+                0.0
+            };
+
+            (cell.rows(), height)
+        })
+        .collect();
+
+    let row_sizes = sizes(&rows, max.height, row_spacing, &heights);
+
+    let column_offsets = offsets(&column_sizes, column_spacing);
+    let row_offsets = offsets(&row_sizes, row_spacing);
+
+    let nodes = items
+        .iter()
+        .zip(trees.iter_mut())
+        .zip(cells)
+        .map(|((item, tree), cell)| {
+            let space = Size::new(
+                extent(&column_sizes[cell.columns()], column_spacing),
+                extent(&row_sizes[cell.rows()], row_spacing),
+            );
+
+            let mut node = item.as_widget().layout(
+                tree,
+                renderer,
+                &Limits::new(Size::ZERO, space),
+            );
+
+            node.move_to(Point::new(
+                padding.left + column_offsets[cell.column],
+                padding.top + row_offsets[cell.row],
+            ));
+            node.align(cell.align_x, cell.align_y, space);
+
+            node
+        })
+        .collect();
+
+    let size = limits.resolve(Size::new(
+        extent(&column_sizes, column_spacing),
+        extent(&row_sizes, row_spacing),
+    ));
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+/// Returns the [`Length`] of every track spanned by the given ranges,
+/// shrinking the implicit ones.
+fn tracks(
+    lengths: &[Length],
+    spans: impl Iterator<Item = Range<usize>>,
+) -> Vec<Length> {
+    let count = spans
+        .map(|span| span.end)
+        .max()
+        .unwrap_or(0)
+        .max(lengths.len());
+
+    (0..count)
+        .map(|index| lengths.get(index).copied().unwrap_or(Length::Shrink))
+        .collect()
+}
+
+/// Returns the total size of the given tracks if all of them are fixed.
+fn fixed_extent(tracks: &[Length]) -> Option<f32> {
+    tracks.iter().try_fold(0.0, |total, length| match length {
+        Length::Fixed(size) => Some(total + size),
+        _ => None,
+    })
+}
+
+/// Returns the total size of the given track sizes, including spacing.
+fn extent(sizes: &[f32], spacing: f32) -> f32 {
+    sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
+}
+
+/// Returns the starting offset of every track.
+fn offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |offset, size| {
+            let start = *offset;
+            *offset += size + spacing;
+
+            Some(start)
+        })
+        .collect()
+}
+
+/// Computes the size of every track, given the size of the items spanning
+/// them and the available space.
+fn sizes(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    items: &[(Range<usize>, f32)],
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|length| match length {
+            Length::Fixed(size) => *size,
+            _ => 0.0,
+        })
+        .collect();
+
+    let is_flexible = |index: usize| !matches!(tracks[index], Length::Fixed(_));
+
+    // Tracks fit the items placed only in them...
+    for (span, size) in items.iter().filter(|(span, _)| span.len() == 1) {
+        if is_flexible(span.start) {
+            sizes[span.start] = sizes[span.start].max(*size);
+        }
+    }
+
+    // ...and grow evenly to fit the items spanning multiple tracks
+    for (span, size) in items.iter().filter(|(span, _)| span.len() > 1) {
+        let missing = size - extent(&sizes[span.clone()], spacing);
+
+        if missing <= 0.0 {
+            continue;
+        }
+
+        let shrinking: Vec<_> = span
+            .clone()
+            .filter(|index| tracks[*index] == Length::Shrink)
+            .collect();
+
+        let growing = if shrinking.is_empty() {
+            span.clone().filter(|index| is_flexible(*index)).collect()
+        } else {
+            shrinking
+        };
+
+        for index in &growing {
+            sizes[*index] += missing / growing.len() as f32;
+        }
+    }
+
+    // Filling tracks share the remaining space, if bounded
+    let fill_sum: u32 = tracks
+        .iter()
+        .map(|length| u32::from(length.fill_factor()))
+        .sum();
+
+    if fill_sum > 0 && available.is_finite() {
+        let used: f32 = tracks
+            .iter()
+            .zip(&sizes)
+            .filter(|(length, _)| length.fill_factor() == 0)
+            .map(|(_, size)| size)
+            .sum();
+
+        let remaining = (available
+            - used
+            - spacing * tracks.len().saturating_sub(1) as f32)
+            .max(0.0);
+
+        for (length, size) in tracks.iter().zip(&mut sizes) {
+            let fill_factor = length.fill_factor();
+
+            if fill_factor > 0 {
+                *size = remaining * f32::from(fill_factor) / fill_sum as f32;
+            }
+        }
+    }
+
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sizes;
+    use crate::Length;
+
+    #[test]
+    fn tracks_are_sized_by_length_and_content() {
+        let tracks = [
+            Length::Fixed(50.0),
+            Length::Shrink,
+            Length::Fill,
+            Length::FillPortion(3),
+        ];
+
+        let items = [(1..2, 30.0), (0..2, 100.0), (2..3, 500.0)];
+
+        assert_eq!(
+            sizes(&tracks, 400.0, 10.0, &items),
+            vec![50.0, 40.0, 70.0, 210.0]
+        );
+    }
+}
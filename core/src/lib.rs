@@ -16,12 +16,15 @@
     unused_results,
     rustdoc::broken_intra_doc_links
 )]
+pub mod accessibility;
 pub mod alignment;
+pub mod animation;
 pub mod clipboard;
 pub mod event;
 pub mod font;
 pub mod gradient;
 pub mod image;
+pub mod input_method;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -46,12 +49,15 @@ mod padding;
 mod pixels;
 mod point;
 mod rectangle;
+mod shadow;
 mod shell;
 mod size;
+mod transformation;
 mod vector;
 
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
+pub use animation::Animation;
 pub use background::Background;
 pub use border_radius::BorderRadius;
 pub use clipboard::Clipboard;
@@ -62,6 +68,7 @@ pub use event::Event;
 pub use font::Font;
 pub use gradient::Gradient;
 pub use hasher::Hasher;
+pub use input_method::InputMethod;
 pub use layout::Layout;
 pub use length::Length;
 pub use overlay::Overlay;
@@ -70,8 +77,10 @@ pub use pixels::Pixels;
 pub use point::Point;
 pub use rectangle::Rectangle;
 pub use renderer::Renderer;
+pub use shadow::Shadow;
 pub use shell::Shell;
 pub use size::Size;
 pub use text::Text;
+pub use transformation::Transformation;
 pub use vector::Vector;
 pub use widget::Widget;
@@ -5,7 +5,10 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
-use crate::{Background, BorderRadius, Color, Rectangle, Vector};
+use crate::{
+    Background, BorderRadius, Color, Rectangle, Shadow, Size, Transformation,
+    Vector,
+};
 
 /// A component that can be used by widgets to draw themselves on a screen.
 pub trait Renderer: Sized {
@@ -24,9 +27,54 @@ pub trait Renderer: Sized {
         f: impl FnOnce(&mut Self),
     );
 
+    /// Applies a [`Transformation`] to the primitives recorded in the given
+    /// closure.
+    ///
+    /// The [`Transformation`] is relative to the current coordinate system,
+    /// so it can be used to rotate or scale any content around a point.
+    ///
+    /// By default, only a [`Transformation`] made purely of a translation is
+    /// applied, with [`Renderer::with_translation`]. Any other
+    /// [`Transformation`] is ignored and the primitives are recorded as they
+    /// are; renderers able to transform them should override this method.
+    fn with_transformation(
+        &mut self,
+        transformation: Transformation,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let translation = transformation.translation();
+
+        if transformation
+            == Transformation::translate(translation.x, translation.y)
+        {
+            self.with_translation(translation, f);
+        } else {
+            f(self);
+        }
+    }
+
+    /// Draws the primitives recorded in the given closure as a single group
+    /// with the given opacity.
+    ///
+    /// The group is composited as a whole, so overlapping primitives inside
+    /// of it do not show through each other.
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self));
+
     /// Fills a [`Quad`] with the provided [`Background`].
     fn fill_quad(&mut self, quad: Quad, background: impl Into<Background>);
 
+    /// Blurs whatever has already been drawn inside of the given bounds with
+    /// the given blur radius.
+    ///
+    /// This can be used to draw a translucent background with a frosted-glass
+    /// look.
+    fn blur_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+    );
+
     /// Clears all of the recorded primitives in the [`Renderer`].
     fn clear(&mut self);
 }
@@ -45,6 +93,21 @@ pub struct Quad {
 
     /// The border color of the [`Quad`].
     pub border_color: Color,
+
+    /// The [`Shadow`] of the [`Quad`].
+    pub shadow: Shadow,
+}
+
+impl Default for Quad {
+    fn default() -> Self {
+        Self {
+            bounds: Rectangle::with_size(Size::ZERO),
+            border_radius: 0.0.into(),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            shadow: Shadow::default(),
+        }
+    }
 }
 
 /// The styling attributes of a [`Renderer`].
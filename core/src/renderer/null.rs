@@ -1,7 +1,11 @@
 use crate::alignment;
 use crate::renderer::{self, Renderer};
+use crate::text::editor::{self, Editor};
 use crate::text::{self, Text};
-use crate::{Background, Color, Font, Pixels, Point, Rectangle, Size, Vector};
+use crate::{
+    Background, BorderRadius, Color, Font, Pixels, Point, Rectangle, Size,
+    Transformation, Vector,
+};
 
 use std::borrow::Cow;
 
@@ -30,6 +34,15 @@ impl Renderer for Null {
     ) {
     }
 
+    fn with_transformation(
+        &mut self,
+        _transformation: Transformation,
+        _f: impl FnOnce(&mut Self),
+    ) {
+    }
+
+    fn with_opacity(&mut self, _opacity: f32, _f: impl FnOnce(&mut Self)) {}
+
     fn clear(&mut self) {}
 
     fn fill_quad(
@@ -38,11 +51,20 @@ impl Renderer for Null {
         _background: impl Into<Background>,
     ) {
     }
+
+    fn blur_backdrop(
+        &mut self,
+        _bounds: Rectangle,
+        _border_radius: BorderRadius,
+        _radius: f32,
+    ) {
+    }
 }
 
 impl text::Renderer for Null {
     type Font = Font;
     type Paragraph = ();
+    type Editor = ();
 
     const ICON_FONT: Font = Font::DEFAULT;
     const CHECKMARK_ICON: char = '0';
@@ -61,6 +83,13 @@ impl text::Renderer for Null {
     fn create_paragraph(&self, _text: Text<'_, Self::Font>) -> Self::Paragraph {
     }
 
+    fn create_rich_paragraph<Link>(
+        &self,
+        _spans: &[text::Span<'_, Link, Self::Font>],
+        _text: Text<'_, Self::Font>,
+    ) -> Self::Paragraph {
+    }
+
     fn resize_paragraph(
         &self,
         _paragraph: &mut Self::Paragraph,
@@ -76,6 +105,14 @@ impl text::Renderer for Null {
     ) {
     }
 
+    fn fill_editor(
+        &mut self,
+        _editor: &Self::Editor,
+        _position: Point,
+        _color: Color,
+    ) {
+    }
+
     fn fill_text(
         &mut self,
         _paragraph: Text<'_, Self::Font>,
@@ -120,6 +157,14 @@ impl text::Paragraph for () {
         None
     }
 
+    fn hit_span(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn span_bounds(&self, _index: usize) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     fn bounds(&self) -> Size {
         Size::ZERO
     }
@@ -132,3 +177,44 @@ impl text::Paragraph for () {
         None
     }
 }
+
+impl Editor for () {
+    type Font = Font;
+
+    fn with_text(_text: &str) -> Self {}
+
+    fn cursor(&self) -> editor::Cursor {
+        editor::Cursor::Caret(Point::ORIGIN)
+    }
+
+    fn cursor_position(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    fn selection(&self) -> Option<String> {
+        None
+    }
+
+    fn line(&self, _index: usize) -> Option<&str> {
+        None
+    }
+
+    fn line_count(&self) -> usize {
+        0
+    }
+
+    fn perform(&mut self, _action: editor::Action) {}
+
+    fn bounds(&self) -> Size {
+        Size::ZERO
+    }
+
+    fn update(
+        &mut self,
+        _new_bounds: Size,
+        _new_font: Self::Font,
+        _new_size: Pixels,
+        _new_line_height: text::LineHeight,
+    ) {
+    }
+}
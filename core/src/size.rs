@@ -94,3 +94,14 @@ impl std::ops::Sub for Size {
         }
     }
 }
+
+impl std::ops::Mul<f32> for Size {
+    type Output = Size;
+
+    fn mul(self, scale: f32) -> Self::Output {
+        Size {
+            width: self.width * scale,
+            height: self.height * scale,
+        }
+    }
+}
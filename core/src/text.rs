@@ -1,6 +1,10 @@
 //! Draw and interact with text.
+pub mod editor;
+
+pub use editor::Editor;
+
 use crate::alignment;
-use crate::{Color, Pixels, Point, Size};
+use crate::{Color, Pixels, Point, Rectangle, Size};
 
 use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
@@ -33,6 +37,111 @@ pub struct Text<'a, Font> {
     pub shaping: Shaping,
 }
 
+/// A span of text with its own attributes.
+///
+/// A list of spans can be laid out as a single paragraph of rich text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'a, Link = (), Font = crate::Font> {
+    /// The content of the [`Span`].
+    pub text: Cow<'a, str>,
+
+    /// The font of the [`Span`].
+    ///
+    /// The default, `None`, means using the font of the paragraph.
+    pub font: Option<Font>,
+
+    /// The [`Color`] of the [`Span`].
+    ///
+    /// The default, `None`, means using the color of the paragraph.
+    pub color: Option<Color>,
+
+    /// The link of the [`Span`], if any.
+    pub link: Option<Link>,
+
+    /// Whether the [`Span`] is underlined or not.
+    pub underline: bool,
+
+    /// Whether the [`Span`] is struck through or not.
+    pub strikethrough: bool,
+}
+
+impl<'a, Link, Font> Span<'a, Link, Font> {
+    /// Creates a new [`Span`] with the given text.
+    pub fn new(text: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            text: text.into(),
+            font: None,
+            color: None,
+            link: None,
+            underline: false,
+            strikethrough: false,
+        }
+    }
+
+    /// Sets the font of the [`Span`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the link of the [`Span`].
+    pub fn link(mut self, link: impl Into<Link>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined or not.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets whether the [`Span`] is struck through or not.
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.strikethrough = strikethrough;
+        self
+    }
+
+    /// Turns the [`Span`] into a static one.
+    pub fn to_static(self) -> Span<'static, Link, Font> {
+        Span {
+            text: Cow::Owned(self.text.into_owned()),
+            font: self.font,
+            color: self.color,
+            link: self.link,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+        }
+    }
+
+    /// Returns a static copy of the [`Span`] without its link.
+    pub fn without_link(&self) -> Span<'static, (), Font>
+    where
+        Font: Copy,
+    {
+        Span {
+            text: Cow::Owned(self.text.as_ref().to_owned()),
+            font: self.font,
+            color: self.color,
+            link: None,
+            underline: self.underline,
+            strikethrough: self.strikethrough,
+        }
+    }
+}
+
+impl<'a, Link, Font> From<&'a str> for Span<'a, Link, Font> {
+    fn from(text: &'a str) -> Self {
+        Self::new(text)
+    }
+}
+
 /// The shaping strategy of some text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum Shaping {
@@ -134,6 +243,9 @@ pub trait Renderer: crate::Renderer {
     /// The [`Paragraph`] of this [`Renderer`].
     type Paragraph: Paragraph<Font = Self::Font> + 'static;
 
+    /// The [`Editor`] of this [`Renderer`].
+    type Editor: Editor<Font = Self::Font> + 'static;
+
     /// The icon font of the backend.
     const ICON_FONT: Self::Font;
 
@@ -159,6 +271,18 @@ pub trait Renderer: crate::Renderer {
     /// Creates a new [`Paragraph`] laid out with the given [`Text`].
     fn create_paragraph(&self, text: Text<'_, Self::Font>) -> Self::Paragraph;
 
+    /// Creates a new [`Paragraph`] of rich text laid out with the given
+    /// spans and the attributes of the given [`Text`].
+    ///
+    /// The `content` of the [`Text`] is ignored; the spans are laid out
+    /// one after the other instead. The index of a [`Span`] identifies it
+    /// in [`Paragraph::hit_span`] and [`Paragraph::span_bounds`].
+    fn create_rich_paragraph<Link>(
+        &self,
+        spans: &[Span<'_, Link, Self::Font>],
+        text: Text<'_, Self::Font>,
+    ) -> Self::Paragraph;
+
     /// Lays out the given [`Paragraph`] with some new boundaries.
     fn resize_paragraph(
         &self,
@@ -192,6 +316,15 @@ pub trait Renderer: crate::Renderer {
         color: Color,
     );
 
+    /// Draws the given [`Editor`] at the given position and with the given
+    /// [`Color`].
+    fn fill_editor(
+        &mut self,
+        editor: &Self::Editor,
+        position: Point,
+        color: Color,
+    );
+
     /// Draws the given [`Text`] at the given position and with the given
     /// [`Color`].
     fn fill_text(
@@ -241,6 +374,17 @@ pub trait Paragraph: Default {
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Returns the index of the [`Span`] at the given point, if any.
+    ///
+    /// A [`Paragraph`] without spans only has a single one.
+    fn hit_span(&self, point: Point) -> Option<usize>;
+
+    /// Returns the bounds of the [`Span`] with the given index, relative to
+    /// the [`Paragraph`].
+    ///
+    /// There is one [`Rectangle`] per line the [`Span`] spans.
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle>;
+
     /// Returns the minimum width that can fit the contents of the [`Paragraph`].
     fn min_width(&self) -> f32 {
         self.min_bounds().width
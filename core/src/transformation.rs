@@ -0,0 +1,220 @@
+use crate::{Point, Radians, Rectangle, Size, Vector};
+
+use glam::{Mat4, Vec3, Vec4};
+use std::ops::Mul;
+
+/// A 2D transformation matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transformation(Mat4);
+
+impl Transformation {
+    /// The identity transformation.
+    pub const IDENTITY: Self = Self(Mat4::IDENTITY);
+
+    /// Get the identity transformation.
+    pub fn identity() -> Transformation {
+        Self::IDENTITY
+    }
+
+    /// Creates an orthographic projection.
+    #[rustfmt::skip]
+    pub fn orthographic(width: u32, height: u32) -> Transformation {
+        Transformation(Mat4::orthographic_rh_gl(
+            0.0, width as f32,
+            height as f32, 0.0,
+            -1.0, 1.0
+        ))
+    }
+
+    /// Creates a translate transformation.
+    pub fn translate(x: f32, y: f32) -> Transformation {
+        Transformation(Mat4::from_translation(Vec3::new(x, y, 0.0)))
+    }
+
+    /// Creates a scale transformation.
+    pub fn scale(x: f32, y: f32) -> Transformation {
+        Transformation(Mat4::from_scale(Vec3::new(x, y, 1.0)))
+    }
+
+    /// Creates a clockwise rotation transformation around the origin.
+    pub fn rotate(angle: impl Into<Radians>) -> Transformation {
+        Transformation(Mat4::from_rotation_z(angle.into().0))
+    }
+
+    /// Creates a 2D affine transformation from the coefficients of its
+    /// matrix, in the same order as the transformations of a canvas:
+    ///
+    /// ```text
+    /// | a c e |
+    /// | b d f |
+    /// | 0 0 1 |
+    /// ```
+    #[rustfmt::skip]
+    pub fn affine(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self {
+        Transformation(Mat4::from_cols(
+            Vec4::new(a, b, 0.0, 0.0),
+            Vec4::new(c, d, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(e, f, 0.0, 1.0),
+        ))
+    }
+
+    /// Returns the inverse of the [`Transformation`], if it can be inverted.
+    pub fn inverse(self) -> Option<Transformation> {
+        (self.0.determinant() != 0.0).then(|| Transformation(self.0.inverse()))
+    }
+
+    /// Returns the coefficients of the 2D affine part of the
+    /// [`Transformation`], in the same order as [`Transformation::affine`].
+    pub fn to_affine(self) -> [f32; 6] {
+        let Mat4 {
+            x_axis,
+            y_axis,
+            w_axis,
+            ..
+        } = self.0;
+
+        [x_axis.x, x_axis.y, y_axis.x, y_axis.y, w_axis.x, w_axis.y]
+    }
+
+    /// Returns the translation of the [`Transformation`].
+    pub fn translation(self) -> Vector {
+        Vector::new(self.0.w_axis.x, self.0.w_axis.y)
+    }
+
+    /// Returns the factor by which the [`Transformation`] scales horizontal
+    /// lengths.
+    pub fn scale_factor(self) -> f32 {
+        self.0.x_axis.x.hypot(self.0.x_axis.y)
+    }
+
+    /// Returns `true` if the [`Transformation`] only translates and scales
+    /// uniformly; that is, if it does not rotate, skew, flip or stretch.
+    pub fn is_translate_and_scale(self) -> bool {
+        let [a, b, c, d, _, _] = self.to_affine();
+
+        b == 0.0 && c == 0.0 && a > 0.0 && a == d
+    }
+}
+
+impl Default for Transformation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Transformation {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Transformation(self.0 * rhs.0)
+    }
+}
+
+impl Mul<Transformation> for Point {
+    type Output = Self;
+
+    fn mul(self, transformation: Transformation) -> Self {
+        let point = transformation
+            .0
+            .transform_point3(Vec3::new(self.x, self.y, 0.0));
+
+        Point::new(point.x, point.y)
+    }
+}
+
+impl Mul<Transformation> for Vector {
+    type Output = Self;
+
+    fn mul(self, transformation: Transformation) -> Self {
+        let vector = transformation
+            .0
+            .transform_vector3(Vec3::new(self.x, self.y, 0.0));
+
+        Vector::new(vector.x, vector.y)
+    }
+}
+
+impl Mul<Transformation> for Rectangle {
+    type Output = Self;
+
+    /// Transforms the [`Rectangle`], returning the smallest axis-aligned
+    /// [`Rectangle`] containing the result.
+    fn mul(self, transformation: Transformation) -> Self {
+        let corners = [
+            Point::new(self.x, self.y),
+            Point::new(self.x + self.width, self.y),
+            Point::new(self.x, self.y + self.height),
+            Point::new(self.x + self.width, self.y + self.height),
+        ]
+        .map(|corner| corner * transformation);
+
+        let (min, max) = corners.iter().fold(
+            (corners[0], corners[0]),
+            |(min, max), corner| {
+                (
+                    Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                    Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+                )
+            },
+        );
+
+        Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+    }
+}
+
+impl AsRef<[f32; 16]> for Transformation {
+    fn as_ref(&self) -> &[f32; 16] {
+        self.0.as_ref()
+    }
+}
+
+impl From<Transformation> for [f32; 16] {
+    fn from(t: Transformation) -> [f32; 16] {
+        *t.as_ref()
+    }
+}
+
+impl From<Transformation> for Mat4 {
+    fn from(transformation: Transformation) -> Self {
+        transformation.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverse_maps_transformed_points_back() {
+        let transformation = Transformation::translate(10.0, 20.0)
+            * Transformation::rotate(std::f32::consts::FRAC_PI_2)
+            * Transformation::scale(2.0, 2.0);
+
+        let point = Point::new(3.0, 4.0) * transformation;
+
+        assert!((point.x - 2.0).abs() < 1e-4);
+        assert!((point.y - 26.0).abs() < 1e-4);
+
+        let inverse = transformation.inverse().expect("Invert transformation");
+        let original = point * inverse;
+
+        assert!((original.x - 3.0).abs() < 1e-4);
+        assert!((original.y - 4.0).abs() < 1e-4);
+
+        assert_eq!(Transformation::scale(0.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn only_translations_and_uniform_scales_are_simple() {
+        assert!(Transformation::IDENTITY.is_translate_and_scale());
+        assert!((Transformation::translate(5.0, 2.0)
+            * Transformation::scale(2.0, 2.0))
+        .is_translate_and_scale());
+
+        assert!(!Transformation::scale(2.0, 1.0).is_translate_and_scale());
+        assert!(!Transformation::scale(-1.0, -1.0).is_translate_and_scale());
+        assert!(!Transformation::rotate(std::f32::consts::FRAC_PI_4)
+            .is_translate_and_scale());
+    }
+}
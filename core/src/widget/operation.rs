@@ -1,5 +1,7 @@
 //! Query or update internal widget state.
+pub mod accessibility;
 pub mod focusable;
+pub mod list;
 pub mod scrollable;
 pub mod text_input;
 
@@ -7,6 +9,7 @@ pub use focusable::Focusable;
 pub use scrollable::Scrollable;
 pub use text_input::TextInput;
 
+use crate::accessibility::Node;
 use crate::widget::Id;
 use crate::{Rectangle, Vector};
 
@@ -29,7 +32,13 @@ pub trait Operation<T> {
     );
 
     /// Operates on a widget that can be focused.
-    fn focusable(&mut self, _state: &mut dyn Focusable, _id: Option<&Id>) {}
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
 
     /// Operates on a widget that can be scrolled.
     fn scrollable(
@@ -44,6 +53,35 @@ pub trait Operation<T> {
     /// Operates on a widget that has text input.
     fn text_input(&mut self, _state: &mut dyn TextInput, _id: Option<&Id>) {}
 
+    /// Operates on a widget that displays some text.
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, _text: &str) {}
+
+    /// Returns whether the [`Operation`] uses the text of the widgets.
+    ///
+    /// Widgets whose text is expensive to produce only report it through
+    /// [`Operation::text`] when this returns `true`.
+    fn wants_text(&self) -> bool {
+        false
+    }
+
+    /// Operates on a widget that can be described to assistive technologies.
+    ///
+    /// A widget containing other widgets should call [`Operation::container`]
+    /// with the same bounds right after, so its children are nested inside of
+    /// its accessibility [`Node`].
+    ///
+    /// Returns the [`Id`](crate::accessibility::Id) assigned to the widget,
+    /// if the [`Operation`] builds an accessibility tree. The widget should
+    /// keep it to recognize the accessibility events targeting it.
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _node: &Node,
+    ) -> Option<crate::accessibility::Id> {
+        None
+    }
+
     /// Operates on a custom widget with some state.
     fn custom(&mut self, _state: &mut dyn Any, _id: Option<&Id>) {}
 
@@ -136,8 +174,9 @@ where
                     &mut self,
                     state: &mut dyn Focusable,
                     id: Option<&Id>,
+                    bounds: Rectangle,
                 ) {
-                    self.operation.focusable(state, id);
+                    self.operation.focusable(state, id, bounds);
                 }
 
                 fn text_input(
@@ -148,6 +187,28 @@ where
                     self.operation.text_input(state, id);
                 }
 
+                fn text(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    text: &str,
+                ) {
+                    self.operation.text(id, bounds, text);
+                }
+
+                fn wants_text(&self) -> bool {
+                    self.operation.wants_text()
+                }
+
+                fn accessible(
+                    &mut self,
+                    id: Option<&Id>,
+                    bounds: Rectangle,
+                    node: &Node,
+                ) -> Option<crate::accessibility::Id> {
+                    self.operation.accessible(id, bounds, node)
+                }
+
                 fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
                     self.operation.custom(state, id);
                 }
@@ -161,8 +222,13 @@ where
             .container(id, bounds, operate_on_children);
         }
 
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
-            self.operation.focusable(state, id);
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
         }
 
         fn scrollable(
@@ -179,6 +245,23 @@ where
             self.operation.text_input(state, id);
         }
 
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn wants_text(&self) -> bool {
+            self.operation.wants_text()
+        }
+
+        fn accessible(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            node: &Node,
+        ) -> Option<crate::accessibility::Id> {
+            self.operation.accessible(id, bounds, node)
+        }
+
         fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
             self.operation.custom(state, id);
         }
@@ -0,0 +1,244 @@
+//! Build the accessibility tree of a widget tree.
+use crate::accessibility::{self, Entry, Node, Role, Tree};
+use crate::widget::operation::{Operation, Scrollable};
+use crate::widget::Id;
+use crate::{Hasher, Rectangle, Vector};
+
+use std::hash::{Hash, Hasher as _};
+
+/// An [`Operation`] that builds the accessibility [`Tree`] of a widget tree.
+///
+/// Accessible widgets are nested inside the closest accessible widget
+/// containing them. Text without an accessible parent is exposed directly,
+/// and the text inside of an unlabeled accessible widget becomes its label.
+#[derive(Debug, Default)]
+pub struct Builder {
+    entries: Vec<Entry>,
+    roots: Vec<accessibility::Id>,
+    scope: Scope,
+    pending: Option<usize>,
+    pending_translation: Option<Vector>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Scope {
+    parent: Option<usize>,
+    translation: Vector,
+}
+
+impl Builder {
+    /// Creates a new empty [`Builder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes the [`Builder`] and returns the accessibility [`Tree`].
+    pub fn build(self) -> Tree {
+        Tree::new(self.entries, self.roots)
+    }
+
+    fn push(&mut self, node: Node, bounds: Rectangle) -> usize {
+        let parent = self.scope.parent;
+
+        let position = match parent {
+            Some(parent) => self.entries[parent].children.len(),
+            None => self.roots.len(),
+        };
+
+        let id = {
+            let mut hasher = Hasher::default();
+
+            parent
+                .map(|parent| self.entries[parent].id)
+                .hash(&mut hasher);
+            position.hash(&mut hasher);
+
+            accessibility::Id(hasher.finish())
+        };
+
+        match parent {
+            Some(parent) => self.entries[parent].children.push(id),
+            None => self.roots.push(id),
+        }
+
+        self.entries.push(Entry {
+            id,
+            node,
+            bounds,
+            translation: self.scope.translation,
+            children: Vec::new(),
+        });
+
+        self.entries.len() - 1
+    }
+
+    fn label_from_children(&mut self, index: usize) {
+        if self.entries[index].node.label.is_some() {
+            return;
+        }
+
+        let children = &self.entries[index].children;
+
+        let label = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.node.role == Role::Text && children.contains(&entry.id)
+            })
+            .filter_map(|entry| entry.node.label.as_deref())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if !label.is_empty() {
+            self.entries[index].node.label = Some(label);
+        }
+    }
+}
+
+impl<T> Operation<T> for Builder {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let parent = self.pending.take().filter(|index| {
+            let entry = &self.entries[*index];
+
+            entry.bounds == bounds && entry.children.is_empty()
+        });
+
+        let previous = self.scope;
+
+        if let Some(parent) = parent {
+            self.scope.parent = Some(parent);
+        }
+
+        if let Some(translation) = self.pending_translation.take() {
+            self.scope.translation = self.scope.translation + translation;
+        }
+
+        operate_on_children(self);
+
+        self.scope = previous;
+        self.pending = None;
+
+        if let Some(parent) = parent {
+            self.label_from_children(parent);
+        }
+    }
+
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        translation: Vector,
+    ) {
+        let index = self.push(Node::new(Role::ScrollView), bounds);
+
+        self.pending = Some(index);
+        self.pending_translation = Some(translation);
+    }
+
+    fn text(&mut self, _id: Option<&Id>, bounds: Rectangle, text: &str) {
+        // Accessible widgets may also report their own text
+        if self
+            .pending
+            .is_some_and(|index| self.entries[index].bounds == bounds)
+        {
+            return;
+        }
+
+        let _ = self.push(Node::new(Role::Text).label(text), bounds);
+
+        self.pending = None;
+    }
+
+    fn wants_text(&self) -> bool {
+        true
+    }
+
+    fn accessible(
+        &mut self,
+        _id: Option<&Id>,
+        bounds: Rectangle,
+        node: &Node,
+    ) -> Option<accessibility::Id> {
+        let index = self.push(node.clone(), bounds);
+
+        self.pending = Some(index);
+
+        Some(self.entries[index].id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Builder;
+    use crate::accessibility::{Node, Role};
+    use crate::widget::Operation;
+    use crate::{Point, Rectangle, Size};
+
+    #[test]
+    fn labels_accessible_widgets_with_their_text() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+        let text =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(80.0, 20.0));
+
+        let mut builder = Builder::new();
+
+        let _ = Operation::<()>::accessible(
+            &mut builder,
+            None,
+            bounds,
+            &Node::new(Role::Button),
+        );
+        Operation::<()>::container(
+            &mut builder,
+            None,
+            bounds,
+            &mut |operation| {
+                operation.text(None, text, "Submit");
+            },
+        );
+        Operation::<()>::text(&mut builder, None, text, "Orphan");
+
+        let tree = builder.build();
+        let button = tree.get(tree.roots()[0]).unwrap();
+
+        assert_eq!(tree.roots().len(), 2);
+        assert_eq!(button.node.label.as_deref(), Some("Submit"));
+        assert_eq!(button.children.len(), 1);
+    }
+
+    #[test]
+    fn accessible_widgets_are_given_the_id_of_their_entry() {
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0));
+
+        let mut builder = Builder::new();
+
+        let first = Operation::<()>::accessible(
+            &mut builder,
+            None,
+            bounds,
+            &Node::new(Role::Button),
+        );
+        let second = Operation::<()>::accessible(
+            &mut builder,
+            None,
+            bounds,
+            &Node::new(Role::CheckBox),
+        );
+
+        let tree = builder.build();
+
+        assert_ne!(first, second);
+        assert_eq!(first, Some(tree.roots()[0]));
+        assert_eq!(second, Some(tree.roots()[1]));
+        assert_eq!(
+            tree.get(second.unwrap()).unwrap().node.role,
+            Role::CheckBox
+        );
+    }
+}
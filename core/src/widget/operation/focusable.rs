@@ -32,7 +32,12 @@ pub fn focus<T>(target: Id) -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for Focus {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match id {
                 Some(id) if id == &self.target => {
                     state.focus();
@@ -71,7 +76,12 @@ where
     where
         O: Operation<T> + 'static,
     {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() {
                 self.count.focused = Some(self.count.total);
             }
@@ -109,7 +119,12 @@ pub fn focus_previous<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusPrevious {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if self.count.total == 0 {
                 return;
             }
@@ -149,7 +164,12 @@ pub fn focus_next<T>() -> impl Operation<T> {
     }
 
     impl<T> Operation<T> for FocusNext {
-        fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             match self.count.focused {
                 None if self.current == 0 => state.focus(),
                 Some(focused) if focused == self.current => state.unfocus(),
@@ -181,7 +201,12 @@ pub fn find_focused() -> impl Operation<Id> {
     }
 
     impl Operation<Id> for FindFocused {
-        fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&Id>) {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
             if state.is_focused() && id.is_some() {
                 self.focused = id.cloned();
             }
@@ -0,0 +1,153 @@
+//! Operate on lists that only build their visible rows.
+use crate::widget::operation::scrollable::{AbsoluteOffset, Scrollable};
+use crate::widget::operation::{Operation, Outcome};
+use crate::widget::Id;
+use crate::{Rectangle, Vector};
+
+use std::any::Any;
+
+/// The position of the rows of a list.
+///
+/// Lists expose it through [`Operation::custom`], so rows can be located
+/// even when they are not built.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rows {
+    /// The bounds of the list.
+    pub bounds: Rectangle,
+
+    /// The vertical offset of every row, relative to the top of the list.
+    pub offsets: Vec<f32>,
+}
+
+impl Rows {
+    /// Returns the absolute vertical position of the row with the given
+    /// index, if it exists.
+    pub fn position(&self, index: usize) -> Option<f32> {
+        self.offsets.get(index).map(|offset| self.bounds.y + offset)
+    }
+}
+
+/// Produces an [`Operation`] that scrolls the scrollable containing the list
+/// with the given [`Id`] until the row with the given index is at the top.
+pub fn scroll_to_index<T>(target: Id, index: usize) -> impl Operation<T> {
+    struct Find {
+        target: Id,
+        index: usize,
+        visited: usize,
+        entering: Option<(usize, Rectangle, Vector)>,
+        scrollables: Vec<(usize, Rectangle, Vector)>,
+        found: Option<(usize, AbsoluteOffset)>,
+    }
+
+    impl<T> Operation<T> for Find {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            // Scrollables operate on their contents right after reporting
+            // themselves
+            let entered = self.entering.take();
+
+            if let Some(scrollable) = entered {
+                self.scrollables.push(scrollable);
+            }
+
+            operate_on_children(self);
+
+            if entered.is_some() {
+                let _ = self.scrollables.pop();
+            }
+        }
+
+        fn scrollable(
+            &mut self,
+            _state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            bounds: Rectangle,
+            translation: Vector,
+        ) {
+            self.entering = Some((self.visited, bounds, translation));
+            self.visited += 1;
+        }
+
+        fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+            if self.found.is_some() || id != Some(&self.target) {
+                return;
+            }
+
+            let Some(rows) = state.downcast_ref::<Rows>() else {
+                return;
+            };
+
+            let Some(&(nth, bounds, translation)) = self.scrollables.last()
+            else {
+                return;
+            };
+
+            let index = self.index.min(rows.offsets.len().saturating_sub(1));
+
+            if let Some(position) = rows.position(index) {
+                self.found = Some((
+                    nth,
+                    AbsoluteOffset {
+                        x: translation.x,
+                        y: position - bounds.y,
+                    },
+                ));
+            }
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            match self.found {
+                Some((nth, offset)) => Outcome::Chain(Box::new(ScrollNth {
+                    nth,
+                    offset,
+                    visited: 0,
+                })),
+                None => Outcome::None,
+            }
+        }
+    }
+
+    struct ScrollNth {
+        nth: usize,
+        offset: AbsoluteOffset,
+        visited: usize,
+    }
+
+    impl<T> Operation<T> for ScrollNth {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            _translation: Vector,
+        ) {
+            if self.visited == self.nth {
+                state.scroll_to(self.offset);
+            }
+
+            self.visited += 1;
+        }
+    }
+
+    Find {
+        target,
+        index,
+        visited: 0,
+        entering: None,
+        scrollables: Vec::new(),
+        found: None,
+    }
+}
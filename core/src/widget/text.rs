@@ -5,6 +5,7 @@ use crate::mouse;
 use crate::renderer;
 use crate::text::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
+use crate::widget::Operation;
 use crate::{Color, Element, Layout, Length, Pixels, Point, Rectangle, Widget};
 
 use std::borrow::Cow;
@@ -184,6 +185,16 @@ where
             theme.appearance(self.style.clone()),
         );
     }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.text(None, layout.bounds(), &self.content);
+    }
 }
 
 /// Produces the [`layout::Node`] of a [`Text`] widget.
@@ -3,7 +3,9 @@ use crate::Widget;
 
 use std::any::{self, Any};
 use std::borrow::Borrow;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 
 /// A persistent state widget tree.
 ///
@@ -189,6 +191,68 @@ pub fn diff_children_custom_with_search<T>(
     }
 }
 
+/// Reconciliates the `current_children` with the provided list of widgets by
+/// matching their keys.
+///
+/// Every new widget reuses the state of the current child with the same key,
+/// wherever it was; this keeps the state of the children when they are
+/// inserted, removed or reordered. Children with unknown keys get a new state.
+///
+/// The `current_keys` must be the keys of the `current_children`, in order.
+pub fn diff_children_keyed<T, Key>(
+    current_children: &mut Vec<Tree>,
+    current_keys: &[Key],
+    new_children: &[T],
+    new_keys: &[Key],
+    diff: impl Fn(&mut Tree, &T),
+    new_state: impl Fn(&T) -> Tree,
+) where
+    Key: Eq + Hash,
+{
+    if current_keys == new_keys && current_children.len() == new_children.len()
+    {
+        for (child_state, new) in
+            current_children.iter_mut().zip(new_children.iter())
+        {
+            diff(child_state, new);
+        }
+
+        return;
+    }
+
+    let mut previous: Vec<Option<Tree>> =
+        current_children.drain(..).map(Some).collect();
+
+    // Index the current children by key. Children sharing a key are chained
+    // in order, so each of them is reused at most once.
+    let mut first: HashMap<&Key, usize> = HashMap::new();
+    let mut next: Vec<Option<usize>> = vec![None; current_keys.len()];
+
+    for (index, key) in current_keys.iter().enumerate().rev() {
+        next[index] = first.insert(key, index);
+    }
+
+    current_children.extend(new_children.iter().zip(new_keys).map(
+        |(new, key)| {
+            let reused = first.remove(key).and_then(|index| {
+                if let Some(next) = next[index] {
+                    let _ = first.insert(key, next);
+                }
+
+                previous[index].take()
+            });
+
+            match reused {
+                Some(mut child_state) => {
+                    diff(&mut child_state, new);
+                    child_state
+                }
+                None => new_state(new),
+            }
+        },
+    ));
+}
+
 /// The identifier of some widget state.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash)]
 pub struct Tag(any::TypeId);
@@ -267,3 +331,74 @@ impl fmt::Debug for State {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{diff_children_keyed, State, Tag, Tree};
+
+    fn tree(value: u32) -> Tree {
+        Tree {
+            tag: Tag::of::<u32>(),
+            state: State::new(value),
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn keyed_children_keep_their_state_when_reordered() {
+        let mut children = vec![tree(1), tree(2), tree(3)];
+
+        diff_children_keyed(
+            &mut children,
+            &['a', 'b', 'c'],
+            &[10, 30, 40],
+            &['a', 'c', 'd'],
+            |_, _| {},
+            |new| tree(*new),
+        );
+
+        let values: Vec<u32> = children
+            .iter()
+            .map(|child| *child.state.downcast_ref::<u32>())
+            .collect();
+
+        assert_eq!(values, vec![1, 3, 40]);
+
+        diff_children_keyed(
+            &mut children,
+            &['a', 'c', 'd'],
+            &[40, 10, 30],
+            &['d', 'a', 'c'],
+            |_, _| {},
+            |new| tree(*new),
+        );
+
+        let values: Vec<u32> = children
+            .iter()
+            .map(|child| *child.state.downcast_ref::<u32>())
+            .collect();
+
+        assert_eq!(values, vec![40, 1, 3]);
+    }
+
+    #[test]
+    fn keyed_children_with_duplicated_keys_are_reused_in_order() {
+        let mut children = vec![tree(1), tree(2), tree(3)];
+
+        diff_children_keyed(
+            &mut children,
+            &['a', 'b', 'a'],
+            &[10, 20, 30, 40],
+            &['a', 'a', 'a', 'b'],
+            |_, _| {},
+            |new| tree(*new),
+        );
+
+        let values: Vec<u32> = children
+            .iter()
+            .map(|child| *child.state.downcast_ref::<u32>())
+            .collect();
+
+        assert_eq!(values, vec![1, 3, 30, 2]);
+    }
+}
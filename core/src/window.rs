@@ -1,15 +1,21 @@
 //! Build window-based GUI applications.
 pub mod icon;
+pub mod settings;
 
 mod event;
+mod id;
 mod level;
 mod mode;
+mod position;
 mod redraw_request;
 mod user_attention;
 
 pub use event::Event;
 pub use icon::Icon;
+pub use id::Id;
 pub use level::Level;
 pub use mode::Mode;
+pub use position::Position;
 pub use redraw_request::RedrawRequest;
+pub use settings::Settings;
 pub use user_attention::UserAttention;
@@ -1,6 +1,32 @@
-use crate::window::{Icon, Level, Position};
+//! Configure the windows of an application.
+#[cfg(target_os = "windows")]
+#[path = "settings/windows.rs"]
+mod platform;
+
+#[cfg(target_os = "macos")]
+#[path = "settings/macos.rs"]
+mod platform;
+
+#[cfg(target_os = "linux")]
+#[path = "settings/linux.rs"]
+mod platform;
+
+#[cfg(target_arch = "wasm32")]
+#[path = "settings/wasm.rs"]
+mod platform;
+
+#[cfg(not(any(
+    target_os = "windows",
+    target_os = "macos",
+    target_os = "linux",
+    target_arch = "wasm32"
+)))]
+#[path = "settings/other.rs"]
+mod platform;
+
+pub use platform::PlatformSpecific;
 
-pub use iced_winit::settings::PlatformSpecific;
+use crate::window::{Icon, Level, Position};
 
 /// The window settings of an application.
 #[derive(Debug, Clone)]
@@ -37,6 +63,16 @@ pub struct Settings {
 
     /// Platform specific settings.
     pub platform_specific: PlatformSpecific,
+
+    /// Whether the window will close when the user requests it, e.g. when a
+    /// user presses the close button.
+    ///
+    /// This can be useful if you want to have some behavior that executes
+    /// before the window is actually destroyed. If you disable this, you must
+    /// manually close the window with `window::close`.
+    ///
+    /// By default, it is enabled.
+    pub exit_on_close_request: bool,
 }
 
 impl Default for Settings {
@@ -53,24 +89,7 @@ impl Default for Settings {
             level: Level::default(),
             icon: None,
             platform_specific: PlatformSpecific::default(),
-        }
-    }
-}
-
-impl From<Settings> for iced_winit::settings::Window {
-    fn from(settings: Settings) -> Self {
-        Self {
-            size: settings.size,
-            position: iced_winit::Position::from(settings.position),
-            min_size: settings.min_size,
-            max_size: settings.max_size,
-            visible: settings.visible,
-            resizable: settings.resizable,
-            decorations: settings.decorations,
-            transparent: settings.transparent,
-            level: settings.level,
-            icon: settings.icon.map(Icon::into),
-            platform_specific: settings.platform_specific,
+            exit_on_close_request: true,
         }
     }
 }
@@ -0,0 +1,58 @@
+use iced::widget::{
+    column, container, horizontal_space, row, text, text_editor,
+};
+use iced::{Element, Length, Sandbox, Settings};
+
+pub fn main() -> iced::Result {
+    Editor::run(Settings::default())
+}
+
+struct Editor {
+    content: text_editor::Content,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Edit(text_editor::Action),
+}
+
+impl Sandbox for Editor {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            content: text_editor::Content::with_text(include_str!("main.rs")),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Editor - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Edit(action) => {
+                self.content.perform(action);
+            }
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let editor = text_editor(&self.content)
+            .on_action(Message::Edit)
+            .height(Length::Fill);
+
+        let status = {
+            let (line, column) = self.content.cursor_position();
+
+            row![
+                horizontal_space(Length::Fill),
+                text(format!("{}:{}", line + 1, column + 1))
+            ]
+        };
+
+        container(column![editor, status].spacing(10))
+            .padding(10)
+            .into()
+    }
+}
@@ -8,6 +8,7 @@ use iced_wgpu::graphics::Viewport;
 use iced_wgpu::{wgpu, Backend, Renderer, Settings};
 use iced_winit::core::mouse;
 use iced_winit::core::renderer;
+use iced_winit::core::window;
 use iced_winit::core::{Color, Font, Pixels, Size};
 use iced_winit::runtime::program;
 use iced_winit::runtime::Debug;
@@ -180,6 +181,7 @@ pub fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 // Map window event to iced event
                 if let Some(event) = iced_winit::conversion::window_event(
+                    window::Id::MAIN,
                     &event,
                     window.scale_factor(),
                     modifiers,
@@ -200,7 +200,7 @@ where
 
         let state = tree.state.downcast_mut::<State>();
 
-        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+        if let Event::Window(_, window::Event::RedrawRequested(now)) = event {
             *state = state.timed_transition(self.cycle_duration, now);
 
             shell.request_redraw(RedrawRequest::At(
@@ -236,6 +236,7 @@ where
                 border_radius: 0.0.into(),
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
             },
             Background::Color(custom_style.track_color),
         );
@@ -252,6 +253,7 @@ where
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 Background::Color(custom_style.bar_color),
             ),
@@ -269,6 +271,7 @@ where
                     border_radius: 0.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..Quad::default()
                 },
                 Background::Color(custom_style.bar_color),
             ),
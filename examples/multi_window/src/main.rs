@@ -0,0 +1,140 @@
+use iced::event;
+use iced::executor;
+use iced::multi_window::{self, Application};
+use iced::widget::{button, column, container, text, text_input};
+use iced::window;
+use iced::{
+    Alignment, Command, Element, Event, Length, Settings, Subscription, Theme,
+};
+
+use std::collections::HashMap;
+
+pub fn main() -> iced::Result {
+    Example::run(Settings {
+        window: window::Settings {
+            size: (400, 300),
+            exit_on_close_request: false,
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+#[derive(Default)]
+struct Example {
+    inspectors: HashMap<window::Id, Inspector>,
+}
+
+#[derive(Debug)]
+struct Inspector {
+    title: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    OpenInspector,
+    CloseWindow(window::Id),
+    TitleChanged(window::Id, String),
+}
+
+impl multi_window::Application for Example {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (Self::default(), Command::none())
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        self.inspectors
+            .get(&window)
+            .map(|inspector| inspector.title.clone())
+            .unwrap_or_else(|| String::from("Multi window - Iced"))
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::OpenInspector => {
+                let (id, spawn) = window::spawn(window::Settings {
+                    size: (300, 200),
+                    exit_on_close_request: false,
+                    ..window::Settings::default()
+                });
+
+                let _ = self.inspectors.insert(
+                    id,
+                    Inspector {
+                        title: format!(
+                            "Inspector {}",
+                            self.inspectors.len() + 1
+                        ),
+                    },
+                );
+
+                spawn
+            }
+            Message::CloseWindow(id) => {
+                let _ = self.inspectors.remove(&id);
+
+                if id == window::Id::MAIN {
+                    Command::batch(
+                        self.inspectors
+                            .drain()
+                            .map(|(id, _)| window::close(id))
+                            .chain(std::iter::once(window::close(id))),
+                    )
+                } else {
+                    window::close(id)
+                }
+            }
+            Message::TitleChanged(id, title) => {
+                if let Some(inspector) = self.inspectors.get_mut(&id) {
+                    inspector.title = title;
+                }
+
+                Command::none()
+            }
+        }
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        event::listen_with(|event, _status| {
+            if let Event::Window(id, window::Event::CloseRequested) = event {
+                Some(Message::CloseWindow(id))
+            } else {
+                None
+            }
+        })
+    }
+
+    fn view(&self, window: window::Id) -> Element<Message> {
+        let content: Element<_> = match self.inspectors.get(&window) {
+            Some(inspector) => column![
+                text_input("Window title", &inspector.title).on_input(
+                    move |title| Message::TitleChanged(window, title)
+                ),
+                button("Close").on_press(Message::CloseWindow(window)),
+            ]
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .into(),
+            None => column![
+                text(format!("{} inspector(s) open", self.inspectors.len())),
+                button("Open inspector").on_press(Message::OpenInspector),
+            ]
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .into(),
+        };
+
+        container(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -166,7 +166,7 @@ impl Application for Todos {
                         }
                     }
                     Message::ChangeWindowMode(mode) => {
-                        window::change_mode(mode)
+                        window::change_mode(window::Id::MAIN, mode)
                     }
                     _ => Command::none(),
                 };
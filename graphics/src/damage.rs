@@ -66,10 +66,33 @@ impl<T: Damage> Damage for Primitive<T> {
 
                 bounds.expand(1.5)
             }
-            Self::Quad { bounds, .. }
-            | Self::Image { bounds, .. }
-            | Self::Svg { bounds, .. } => bounds.expand(1.0),
-            Self::Clip { bounds, .. } => bounds.expand(1.0),
+            Self::Editor {
+                editor, position, ..
+            } => {
+                let bounds = Rectangle::new(*position, editor.bounds);
+
+                bounds.expand(1.5)
+            }
+            Self::Quad { bounds, shadow, .. } => {
+                let bounds_with_shadow = Rectangle {
+                    x: bounds.x + shadow.offset.x.min(0.0) - shadow.blur_radius,
+                    y: bounds.y + shadow.offset.y.min(0.0) - shadow.blur_radius,
+                    width: bounds.width
+                        + shadow.offset.x.abs()
+                        + shadow.blur_radius * 2.0,
+                    height: bounds.height
+                        + shadow.offset.y.abs()
+                        + shadow.blur_radius * 2.0,
+                };
+
+                bounds_with_shadow.expand(1.0)
+            }
+            Self::Image { bounds, .. } | Self::Svg { bounds, .. } => {
+                bounds.expand(1.0)
+            }
+            Self::Blur { bounds, .. } | Self::Clip { bounds, .. } => {
+                bounds.expand(1.0)
+            }
             Self::Group { primitives } => primitives
                 .iter()
                 .map(Self::bounds)
@@ -80,6 +103,11 @@ impl<T: Damage> Damage for Primitive<T> {
                 translation,
                 content,
             } => content.bounds() + *translation,
+            Self::Transform {
+                transformation,
+                content,
+            } => content.bounds() * *transformation,
+            Self::Opacity { content, .. } => content.bounds(),
             Self::Cache { content } => content.bounds(),
             Self::Custom(custom) => custom.bounds(),
         }
@@ -134,6 +162,37 @@ fn regions<T: Damage>(a: &Primitive<T>, b: &Primitive<T>) -> Vec<Rectangle> {
                     .collect();
             }
         }
+        (
+            Primitive::Transform {
+                transformation: transformation_a,
+                content: content_a,
+            },
+            Primitive::Transform {
+                transformation: transformation_b,
+                content: content_b,
+            },
+        ) => {
+            if transformation_a == transformation_b {
+                return regions(content_a, content_b)
+                    .into_iter()
+                    .map(|r| r * *transformation_a)
+                    .collect();
+            }
+        }
+        (
+            Primitive::Opacity {
+                opacity: opacity_a,
+                content: content_a,
+            },
+            Primitive::Opacity {
+                opacity: opacity_b,
+                content: content_b,
+            },
+        ) => {
+            if opacity_a == opacity_b {
+                return regions(content_a, content_b);
+            }
+        }
         (
             Primitive::Cache { content: content_a },
             Primitive::Cache { content: content_b },
@@ -3,20 +3,26 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle, Vector};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
+use std::f32::consts::{FRAC_PI_2, PI};
 
 #[derive(Debug, Clone, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a center, or around a
+/// center.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from its `center` until its `radii`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +31,34 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
+        }
+    }
+
+    /// Returns the [`ColorStop`]s of the [`Gradient`].
+    pub fn stops(&self) -> &[Option<ColorStop>; 8] {
+        match self {
+            Gradient::Linear(linear) => &linear.stops,
+            Gradient::Radial(radial) => &radial.stops,
+            Gradient::Conic(conic) => &conic.stops,
         }
     }
 }
@@ -63,19 +92,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) =
-                self.stops.binary_search_by(|stop| match stop {
-                    None => Ordering::Greater,
-                    Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-                });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -96,37 +113,127 @@ impl Linear {
 
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
-        let mut colors = [[0u32; 2]; 8];
-        let mut offsets = [f16::from(0u8); 8];
+        pack_stops(
+            &self.stops,
+            Kind::Linear,
+            [self.start.x, self.start.y, self.end.x, self.end.y],
+        )
+    }
+}
 
-        for (index, stop) in self.stops.iter().enumerate() {
-            let [r, g, b, a] =
-                color::pack(stop.map_or(Color::default(), |s| s.color))
-                    .components();
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute position of the center of the gradient.
+    pub center: Point,
 
-            colors[index] = [
-                pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-            ];
+    /// The horizontal and vertical radii of the gradient.
+    pub radii: Vector,
 
-            offsets[index] =
-                stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+    /// [`ColorStop`]s from the center of the radial gradient outwards.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radii: Vector) -> Self {
+        Self {
+            center,
+            radii,
+            stops: [None; 8],
         }
+    }
 
-        let offsets = [
-            pack_f16s([offsets[0], offsets[1]]),
-            pack_f16s([offsets[2], offsets[3]]),
-            pack_f16s([offsets[4], offsets[5]]),
-            pack_f16s([offsets[6], offsets[7]]),
-        ];
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        pack_stops(
+            &self.stops,
+            Kind::Radial,
+            [self.center.x, self.center.y, self.radii.x, self.radii.y],
+        )
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute position of the center of the gradient.
+    pub center: Point,
+
+    /// Where the gradient starts, clockwise from the top.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s clockwise around the conic gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
-        Packed {
-            colors,
-            offsets,
-            direction,
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(
+        mut self,
+        stops: impl IntoIterator<Item = ColorStop>,
+    ) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
         }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        pack_stops(
+            &self.stops,
+            Kind::Conic,
+            [self.center.x, self.center.y, self.angle.0 - FRAC_PI_2, 0.0],
+        )
     }
 }
 
@@ -138,50 +245,128 @@ pub struct Packed {
     colors: [[u32; 2]; 8],
     // 8 offsets, 8x 16 bit floats packed into 4 u32s
     offsets: [u32; 4],
+    // Linear: start & end, radial: center & radii, conic: center & angle
     direction: [f32; 4],
+    kind: u32,
+}
+
+/// The kind of a [`Packed`] gradient, as understood by shader code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Linear = 0,
+    Radial = 1,
+    Conic = 2,
 }
 
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color))
-                        .components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
-
-                offsets[index] = stop
-                    .map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
-
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
-
             let (start, end) = linear.angle.to_distance(&bounds);
 
-            let direction = [start.x, start.y, end.x, end.y];
+            pack_stops(
+                &linear.stops,
+                Kind::Linear,
+                [start.x, start.y, end.x, end.y],
+            )
+        }
+        core::Gradient::Radial(radial) => {
+            let center = relative_to(bounds, radial.center);
+
+            pack_stops(
+                &radial.stops,
+                Kind::Radial,
+                [
+                    center.x,
+                    center.y,
+                    radial.radii.x * bounds.width,
+                    radial.radii.y * bounds.height,
+                ],
+            )
+        }
+        core::Gradient::Conic(conic) => {
+            let center = relative_to(bounds, conic.center);
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+            pack_stops(
+                &conic.stops,
+                Kind::Conic,
+                [center.x, center.y, conic.angle.0 - FRAC_PI_2, 0.0],
+            )
         }
     }
 }
 
+/// Returns the absolute position of a point relative to the given bounds.
+pub fn relative_to(bounds: Rectangle, point: Point) -> Point {
+    Point::new(
+        bounds.x + point.x * bounds.width,
+        bounds.y + point.y * bounds.height,
+    )
+}
+
+/// Returns the offset of a point around a conic gradient with the given
+/// center and starting angle, between `0.0` and `1.0`.
+pub fn conic_offset(center: Point, angle: Radians, point: Point) -> f32 {
+    let direction = point - center;
+    let turns =
+        (direction.y.atan2(direction.x) - (angle.0 - FRAC_PI_2)) / (2.0 * PI);
+
+    turns.rem_euclid(1.0)
+}
+
+fn pack_stops(
+    stops: &[Option<ColorStop>; 8],
+    kind: Kind,
+    direction: [f32; 4],
+) -> Packed {
+    let mut colors = [[0u32; 2]; 8];
+    let mut offsets = [f16::from(0u8); 8];
+
+    for (index, stop) in stops.iter().enumerate() {
+        let [r, g, b, a] =
+            color::pack(stop.map_or(Color::default(), |s| s.color))
+                .components();
+
+        colors[index] = [
+            pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
+            pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
+        ];
+
+        offsets[index] =
+            stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
+    }
+
+    let offsets = [
+        pack_f16s([offsets[0], offsets[1]]),
+        pack_f16s([offsets[2], offsets[3]]),
+        pack_f16s([offsets[4], offsets[5]]),
+        pack_f16s([offsets[6], offsets[7]]),
+    ];
+
+    Packed {
+        colors,
+        offsets,
+        direction,
+        kind: kind as u32,
+    }
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) =
+            stops.binary_search_by(|stop| match stop {
+                None => Ordering::Greater,
+                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+            });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
+
 /// Packs two f16s into one u32.
 fn pack_f16s(f: [f16; 2]) -> u32 {
     let one = (f[0].to_bits() as u32) << 16;
@@ -189,3 +374,29 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::conic_offset;
+    use crate::core::{Point, Radians};
+
+    #[test]
+    fn conic_offsets_go_clockwise_from_the_angle() {
+        let center = Point::new(10.0, 10.0);
+        let offset =
+            |x, y| conic_offset(center, Radians(0.0), Point::new(x, y));
+
+        assert!(offset(10.0, 0.0).abs() < 1e-5);
+        assert!((offset(20.0, 10.0) - 0.25).abs() < 1e-5);
+        assert!((offset(10.0, 20.0) - 0.5).abs() < 1e-5);
+        assert!((offset(0.0, 10.0) - 0.75).abs() < 1e-5);
+
+        let rotated = conic_offset(
+            center,
+            Radians(std::f32::consts::FRAC_PI_2),
+            Point::new(20.0, 10.0),
+        );
+
+        assert!(rotated.abs() < 1e-5);
+    }
+}
@@ -3,7 +3,11 @@ use crate::core::alignment;
 use crate::core::image;
 use crate::core::svg;
 use crate::core::text;
-use crate::core::{Background, Color, Font, Pixels, Point, Rectangle, Vector};
+use crate::core::{
+    Background, Color, Font, Pixels, Point, Rectangle, Shadow, Transformation,
+    Vector,
+};
+use crate::text::editor;
 use crate::text::paragraph;
 
 use std::sync::Arc;
@@ -41,6 +45,15 @@ pub enum Primitive<T> {
         /// The color of the paragraph.
         color: Color,
     },
+    /// An editor primitive
+    Editor {
+        /// The [`editor::Weak`] reference.
+        editor: editor::Weak,
+        /// The position of the editor.
+        position: Point,
+        /// The color of the editor.
+        color: Color,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -53,6 +66,8 @@ pub enum Primitive<T> {
         border_width: f32,
         /// The border color of the quad
         border_color: Color,
+        /// The shadow of the quad
+        shadow: Shadow,
     },
     /// An image primitive
     Image {
@@ -72,6 +87,17 @@ pub enum Primitive<T> {
         /// The bounds of the viewport
         bounds: Rectangle,
     },
+    /// A backdrop blur primitive
+    ///
+    /// It blurs whatever has been drawn before it inside of its bounds.
+    Blur {
+        /// The bounds of the blur
+        bounds: Rectangle,
+        /// The border radii of the blur
+        border_radius: [f32; 4],
+        /// The radius of the blur
+        radius: f32,
+    },
     /// A group of primitives
     Group {
         /// The primitives of the group
@@ -92,6 +118,22 @@ pub enum Primitive<T> {
         /// The primitive to translate
         content: Box<Primitive<T>>,
     },
+    /// A primitive that applies a [`Transformation`].
+    Transform {
+        /// The transformation
+        transformation: Transformation,
+
+        /// The primitive to transform
+        content: Box<Primitive<T>>,
+    },
+    /// A primitive that is composited as a whole with some opacity.
+    Opacity {
+        /// The opacity of the primitive, between `0.0` and `1.0`
+        opacity: f32,
+
+        /// The primitive to composite
+        content: Box<Primitive<T>>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -125,4 +167,20 @@ impl<T> Primitive<T> {
             content: Box::new(self),
         }
     }
+
+    /// Creates a [`Primitive::Transform`].
+    pub fn transform(self, transformation: Transformation) -> Self {
+        Self::Transform {
+            transformation,
+            content: Box::new(self),
+        }
+    }
+
+    /// Creates a [`Primitive::Opacity`].
+    pub fn opacity(self, opacity: f32) -> Self {
+        Self::Opacity {
+            opacity,
+            content: Box::new(self),
+        }
+    }
 }
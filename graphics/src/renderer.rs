@@ -6,7 +6,8 @@ use crate::core::renderer;
 use crate::core::svg;
 use crate::core::text::Text;
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, BorderRadius, Color, Font, Pixels, Point, Rectangle, Size,
+    Transformation, Vector,
 };
 use crate::text;
 use crate::Primitive;
@@ -91,6 +92,40 @@ impl<B: Backend, T> Renderer<B, T> {
         self.primitives
             .push(Primitive::group(layer).translate(translation));
     }
+
+    /// Starts recording a transformation.
+    pub fn start_transformation(&mut self) -> Vec<Primitive<B::Primitive>> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// Ends the recording of a transformation.
+    pub fn end_transformation(
+        &mut self,
+        primitives: Vec<Primitive<B::Primitive>>,
+        transformation: Transformation,
+    ) {
+        let layer = std::mem::replace(&mut self.primitives, primitives);
+
+        self.primitives
+            .push(Primitive::group(layer).transform(transformation));
+    }
+
+    /// Starts recording an opacity group.
+    pub fn start_opacity(&mut self) -> Vec<Primitive<B::Primitive>> {
+        std::mem::take(&mut self.primitives)
+    }
+
+    /// Ends the recording of an opacity group.
+    pub fn end_opacity(
+        &mut self,
+        primitives: Vec<Primitive<B::Primitive>>,
+        opacity: f32,
+    ) {
+        let layer = std::mem::replace(&mut self.primitives, primitives);
+
+        self.primitives
+            .push(Primitive::group(layer).opacity(opacity));
+    }
 }
 
 impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
@@ -116,6 +151,26 @@ impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
         self.end_translation(current, translation);
     }
 
+    fn with_transformation(
+        &mut self,
+        transformation: Transformation,
+        f: impl FnOnce(&mut Self),
+    ) {
+        let current = self.start_transformation();
+
+        f(self);
+
+        self.end_transformation(current, transformation);
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        let current = self.start_opacity();
+
+        f(self);
+
+        self.end_opacity(current, opacity);
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -127,6 +182,20 @@ impl<B: Backend, T> iced_core::Renderer for Renderer<B, T> {
             border_radius: quad.border_radius.into(),
             border_width: quad.border_width,
             border_color: quad.border_color,
+            shadow: quad.shadow,
+        });
+    }
+
+    fn blur_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+    ) {
+        self.primitives.push(Primitive::Blur {
+            bounds,
+            border_radius: border_radius.into(),
+            radius,
         });
     }
 
@@ -141,6 +210,7 @@ where
 {
     type Font = Font;
     type Paragraph = text::Paragraph;
+    type Editor = text::Editor;
 
     const ICON_FONT: Font = Font::with_name("Iced-Icons");
     const CHECKMARK_ICON: char = '\u{f00c}';
@@ -159,7 +229,15 @@ where
     }
 
     fn create_paragraph(&self, text: Text<'_, Self::Font>) -> text::Paragraph {
-        text::Paragraph::with_text(text, self.backend.font_system())
+        text::Paragraph::with_text(text)
+    }
+
+    fn create_rich_paragraph<Link>(
+        &self,
+        spans: &[core::text::Span<'_, Link, Self::Font>],
+        text: Text<'_, Self::Font>,
+    ) -> text::Paragraph {
+        text::Paragraph::with_spans(spans, text)
     }
 
     fn update_paragraph(
@@ -167,9 +245,12 @@ where
         paragraph: &mut Self::Paragraph,
         text: Text<'_, Self::Font>,
     ) {
-        let font_system = self.backend.font_system();
+        let version = text::font_system()
+            .read()
+            .expect("Read font system")
+            .version();
 
-        if paragraph.version() != font_system.version() {
+        if paragraph.version() != version {
             // The font system has changed, paragraph fonts may be outdated
             *paragraph = self.create_paragraph(text);
         } else {
@@ -190,7 +271,7 @@ where
         paragraph: &mut Self::Paragraph,
         new_bounds: Size,
     ) {
-        paragraph.resize(new_bounds, self.backend.font_system());
+        paragraph.resize(new_bounds);
     }
 
     fn fill_paragraph(
@@ -206,6 +287,19 @@ where
         });
     }
 
+    fn fill_editor(
+        &mut self,
+        editor: &Self::Editor,
+        position: Point,
+        color: Color,
+    ) {
+        self.primitives.push(Primitive::Editor {
+            editor: editor.downgrade(),
+            position,
+            color,
+        });
+    }
+
     fn fill_text(
         &mut self,
         text: Text<'_, Self::Font>,
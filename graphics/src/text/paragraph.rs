@@ -1,8 +1,8 @@
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Hit, LineHeight, Shaping, Text};
-use crate::core::{Font, Pixels, Point, Size};
-use crate::text::{self, FontSystem};
+use crate::core::text::{Hit, LineHeight, Shaping, Span, Text};
+use crate::core::{Font, Pixels, Point, Rectangle, Size};
+use crate::text;
 
 use std::fmt;
 use std::sync::{self, Arc};
@@ -13,6 +13,7 @@ pub struct Paragraph(Option<Arc<Internal>>);
 struct Internal {
     buffer: cosmic_text::Buffer,
     content: String, // TODO: Reuse from `buffer` (?)
+    spans: Vec<Span<'static, (), Font>>,
     font: Font,
     shaping: Shaping,
     horizontal_alignment: alignment::Horizontal,
@@ -27,13 +28,14 @@ impl Paragraph {
         Self::default()
     }
 
-    pub fn with_text(text: Text<'_, Font>, font_system: &FontSystem) -> Self {
+    pub fn with_text(text: Text<'_, Font>) -> Self {
         log::trace!("Allocating paragraph: {}", text.content);
 
-        let (mut font_system, version) = font_system.write();
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
 
         let mut buffer = cosmic_text::Buffer::new(
-            &mut font_system,
+            font_system.raw(),
             cosmic_text::Metrics::new(
                 text.size.into(),
                 text.line_height.to_absolute(text.size).into(),
@@ -41,13 +43,13 @@ impl Paragraph {
         );
 
         buffer.set_size(
-            &mut font_system,
+            font_system.raw(),
             text.bounds.width,
             text.bounds.height,
         );
 
         buffer.set_text(
-            &mut font_system,
+            font_system.raw(),
             text.content,
             text::to_attributes(text.font),
             text::to_shaping(text.shaping),
@@ -58,13 +60,69 @@ impl Paragraph {
         Self(Some(Arc::new(Internal {
             buffer,
             content: text.content.to_owned(),
+            spans: Vec::new(),
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
             shaping: text.shaping,
             bounds: text.bounds,
             min_bounds,
-            version,
+            version: font_system.version(),
+        })))
+    }
+
+    pub fn with_spans<Link>(
+        spans: &[Span<'_, Link, Font>],
+        text: Text<'_, Font>,
+    ) -> Self {
+        log::trace!("Allocating rich paragraph: {} spans", spans.len());
+
+        let mut font_system =
+            text::font_system().write().expect("Write font system");
+
+        let mut buffer = cosmic_text::Buffer::new(
+            font_system.raw(),
+            cosmic_text::Metrics::new(
+                text.size.into(),
+                text.line_height.to_absolute(text.size).into(),
+            ),
+        );
+
+        buffer.set_size(
+            font_system.raw(),
+            text.bounds.width,
+            text.bounds.height,
+        );
+
+        buffer.set_rich_text(
+            font_system.raw(),
+            spans.iter().enumerate().map(|(index, span)| {
+                let mut attributes =
+                    text::to_attributes(span.font.unwrap_or(text.font))
+                        .metadata(index);
+
+                if let Some(color) = span.color {
+                    attributes = attributes.color(text::to_color(color));
+                }
+
+                (span.text.as_ref(), attributes)
+            }),
+            text::to_shaping(text.shaping),
+        );
+
+        let min_bounds = text::measure(&buffer);
+
+        Self(Some(Arc::new(Internal {
+            buffer,
+            content: spans.iter().map(|span| span.text.as_ref()).collect(),
+            spans: spans.iter().map(Span::without_link).collect(),
+            font: text.font,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            shaping: text.shaping,
+            bounds: text.bounds,
+            min_bounds,
+            version: font_system.version(),
         })))
     }
 
@@ -87,7 +145,7 @@ impl Paragraph {
         }
     }
 
-    pub fn resize(&mut self, new_bounds: Size, font_system: &FontSystem) {
+    pub fn resize(&mut self, new_bounds: Size) {
         let paragraph = self
             .0
             .take()
@@ -95,10 +153,11 @@ impl Paragraph {
 
         match Arc::try_unwrap(paragraph) {
             Ok(mut internal) => {
-                let (mut font_system, _) = font_system.write();
+                let mut font_system =
+                    text::font_system().write().expect("Write font system");
 
                 internal.buffer.set_size(
-                    &mut font_system,
+                    font_system.raw(),
                     new_bounds.width,
                     new_bounds.height,
                 );
@@ -113,21 +172,24 @@ impl Paragraph {
 
                 // If there is a strong reference somewhere, we recompute the
                 // buffer from scratch
-                *self = Self::with_text(
-                    Text {
-                        content: &internal.content,
-                        bounds: internal.bounds,
-                        size: Pixels(metrics.font_size),
-                        line_height: LineHeight::Absolute(Pixels(
-                            metrics.line_height,
-                        )),
-                        font: internal.font,
-                        horizontal_alignment: internal.horizontal_alignment,
-                        vertical_alignment: internal.vertical_alignment,
-                        shaping: internal.shaping,
-                    },
-                    font_system,
-                );
+                let text = Text {
+                    content: &internal.content,
+                    bounds: internal.bounds,
+                    size: Pixels(metrics.font_size),
+                    line_height: LineHeight::Absolute(Pixels(
+                        metrics.line_height,
+                    )),
+                    font: internal.font,
+                    horizontal_alignment: internal.horizontal_alignment,
+                    vertical_alignment: internal.vertical_alignment,
+                    shaping: internal.shaping,
+                };
+
+                *self = if internal.spans.is_empty() {
+                    Self::with_text(text)
+                } else {
+                    Self::with_spans(&internal.spans, text)
+                };
             }
         }
     }
@@ -222,6 +284,47 @@ impl core::text::Paragraph for Paragraph {
             glyph.y - glyph.y_offset * glyph.font_size,
         ))
     }
+
+    fn hit_span(&self, point: Point) -> Option<usize> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+
+        let run = internal.buffer.layout_runs().find(|run| {
+            point.y >= run.line_top && point.y < run.line_top + line_height
+        })?;
+
+        run.glyphs
+            .iter()
+            .find(|glyph| point.x >= glyph.x && point.x < glyph.x + glyph.w)
+            .map(|glyph| glyph.metadata)
+    }
+
+    fn span_bounds(&self, index: usize) -> Vec<Rectangle> {
+        let internal = self.internal();
+        let line_height = internal.buffer.metrics().line_height;
+
+        internal
+            .buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (start, end) = run
+                    .glyphs
+                    .iter()
+                    .filter(|glyph| glyph.metadata == index)
+                    .fold(None, |range, glyph| {
+                        let (start, end) =
+                            range.unwrap_or((glyph.x, glyph.x + glyph.w));
+
+                        Some((start.min(glyph.x), end.max(glyph.x + glyph.w)))
+                    })?;
+
+                Some(Rectangle::new(
+                    Point::new(start, run.line_top),
+                    Size::new(end - start, line_height),
+                ))
+            })
+            .collect()
+    }
 }
 
 impl Default for Paragraph {
@@ -249,6 +352,7 @@ impl fmt::Debug for Paragraph {
 impl PartialEq for Internal {
     fn eq(&self, other: &Self) -> bool {
         self.content == other.content
+            && self.spans == other.spans
             && self.font == other.font
             && self.shaping == other.shaping
             && self.horizontal_alignment == other.horizontal_alignment
@@ -267,6 +371,7 @@ impl Default for Internal {
                 line_height: 1.0,
             }),
             content: String::new(),
+            spans: Vec::new(),
             font: Font::default(),
             shaping: Shaping::default(),
             horizontal_alignment: alignment::Horizontal::Left,
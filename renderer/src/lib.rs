@@ -3,6 +3,8 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 pub mod compositor;
 
+pub mod offscreen;
+
 #[cfg(feature = "geometry")]
 pub mod geometry;
 
@@ -11,7 +13,11 @@ mod settings;
 pub use iced_graphics as graphics;
 pub use iced_graphics::core;
 
+#[cfg(feature = "wgpu")]
+pub use iced_wgpu as wgpu;
+
 pub use compositor::Compositor;
+pub use offscreen::Offscreen;
 pub use settings::Settings;
 
 #[cfg(feature = "geometry")]
@@ -20,9 +26,10 @@ pub use geometry::Geometry;
 use crate::core::renderer;
 use crate::core::text::{self, Text};
 use crate::core::{
-    Background, Color, Font, Pixels, Point, Rectangle, Size, Vector,
+    Background, BorderRadius, Color, Font, Pixels, Point, Rectangle, Size,
+    Transformation, Vector,
 };
-use crate::graphics::text::Paragraph;
+use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::Mesh;
 
 use std::borrow::Cow;
@@ -133,6 +140,74 @@ impl<T> core::Renderer for Renderer<T> {
         }
     }
 
+    fn with_transformation(
+        &mut self,
+        transformation: Transformation,
+        f: impl FnOnce(&mut Self),
+    ) {
+        match self {
+            Self::TinySkia(renderer) => {
+                let primitives = renderer.start_transformation();
+
+                f(self);
+
+                match self {
+                    Self::TinySkia(renderer) => {
+                        renderer.end_transformation(primitives, transformation);
+                    }
+                    #[cfg(feature = "wgpu")]
+                    _ => unreachable!(),
+                }
+            }
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(renderer) => {
+                let primitives = renderer.start_transformation();
+
+                f(self);
+
+                match self {
+                    #[cfg(feature = "wgpu")]
+                    Self::Wgpu(renderer) => {
+                        renderer.end_transformation(primitives, transformation);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
+    fn with_opacity(&mut self, opacity: f32, f: impl FnOnce(&mut Self)) {
+        match self {
+            Self::TinySkia(renderer) => {
+                let primitives = renderer.start_opacity();
+
+                f(self);
+
+                match self {
+                    Self::TinySkia(renderer) => {
+                        renderer.end_opacity(primitives, opacity);
+                    }
+                    #[cfg(feature = "wgpu")]
+                    _ => unreachable!(),
+                }
+            }
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(renderer) => {
+                let primitives = renderer.start_opacity();
+
+                f(self);
+
+                match self {
+                    #[cfg(feature = "wgpu")]
+                    Self::Wgpu(renderer) => {
+                        renderer.end_opacity(primitives, opacity);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+
     fn fill_quad(
         &mut self,
         quad: renderer::Quad,
@@ -141,6 +216,19 @@ impl<T> core::Renderer for Renderer<T> {
         delegate!(self, renderer, renderer.fill_quad(quad, background));
     }
 
+    fn blur_backdrop(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: BorderRadius,
+        radius: f32,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.blur_backdrop(bounds, border_radius, radius)
+        );
+    }
+
     fn clear(&mut self) {
         delegate!(self, renderer, renderer.clear());
     }
@@ -149,6 +237,7 @@ impl<T> core::Renderer for Renderer<T> {
 impl<T> text::Renderer for Renderer<T> {
     type Font = Font;
     type Paragraph = Paragraph;
+    type Editor = Editor;
 
     const ICON_FONT: Font = iced_tiny_skia::Renderer::<T>::ICON_FONT;
     const CHECKMARK_ICON: char = iced_tiny_skia::Renderer::<T>::CHECKMARK_ICON;
@@ -167,6 +256,14 @@ impl<T> text::Renderer for Renderer<T> {
         delegate!(self, renderer, renderer.create_paragraph(text))
     }
 
+    fn create_rich_paragraph<Link>(
+        &self,
+        spans: &[text::Span<'_, Link, Self::Font>],
+        text: Text<'_, Self::Font>,
+    ) -> Self::Paragraph {
+        delegate!(self, renderer, renderer.create_rich_paragraph(spans, text))
+    }
+
     fn resize_paragraph(
         &self,
         paragraph: &mut Self::Paragraph,
@@ -196,6 +293,19 @@ impl<T> text::Renderer for Renderer<T> {
         );
     }
 
+    fn fill_editor(
+        &mut self,
+        editor: &Self::Editor,
+        position: Point,
+        color: Color,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.fill_editor(editor, position, color)
+        );
+    }
+
     fn fill_text(
         &mut self,
         text: Text<'_, Self::Font>,
@@ -265,3 +375,30 @@ impl<T> crate::graphics::geometry::Renderer for Renderer<T> {
         }
     }
 }
+
+#[cfg(feature = "wgpu")]
+impl<T> iced_wgpu::primitive::pipeline::Renderer for Renderer<T> {
+    fn draw_pipeline_primitive(
+        &mut self,
+        id: iced_wgpu::primitive::pipeline::Id,
+        bounds: Rectangle,
+        primitive: impl iced_wgpu::primitive::pipeline::Primitive,
+    ) {
+        match self {
+            Self::TinySkia(_) => {
+                log::warn!(
+                    "Custom pipeline primitives are unsupported: {primitive:?}"
+                );
+            }
+            Self::Wgpu(renderer) => {
+                renderer.draw_primitive(iced_wgpu::Primitive::Custom(
+                    iced_wgpu::primitive::Custom::Pipeline(
+                        iced_wgpu::primitive::Pipeline::new(
+                            id, bounds, primitive,
+                        ),
+                    ),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! Render primitives to RGBA buffers, without a window.
+use crate::compositor::Compositor;
+use crate::core::Color;
+use crate::graphics::Viewport;
+use crate::{Renderer, Settings};
+
+#[cfg(feature = "wgpu")]
+use crate::graphics::Error;
+
+/// A compositor that renders into memory instead of a window.
+///
+/// An [`Offscreen`] compositor needs no display server, which makes it
+/// suitable for headless processes—like servers generating images of a user
+/// interface.
+#[allow(missing_debug_implementations)]
+pub struct Offscreen<Theme> {
+    compositor: Compositor<Theme>,
+    renderer: Renderer<Theme>,
+}
+
+impl<Theme> Offscreen<Theme> {
+    /// Creates a new [`Offscreen`] compositor backed by the software
+    /// renderer.
+    pub fn new(settings: Settings) -> Self {
+        let (compositor, backend) = iced_tiny_skia::window::compositor::new();
+
+        Self {
+            compositor: Compositor::TinySkia(compositor),
+            renderer: Renderer::TinySkia(iced_tiny_skia::Renderer::new(
+                backend,
+                settings.default_font,
+                settings.default_text_size,
+            )),
+        }
+    }
+
+    /// Creates a new [`Offscreen`] compositor backed by `wgpu`.
+    ///
+    /// Returns an error if no graphics adapter could be found.
+    #[cfg(feature = "wgpu")]
+    pub fn wgpu(settings: Settings) -> Result<Self, Error> {
+        let (compositor, backend) =
+            iced_wgpu::window::compositor::headless(iced_wgpu::Settings {
+                default_font: settings.default_font,
+                default_text_size: settings.default_text_size,
+                antialiasing: settings.antialiasing,
+                ..iced_wgpu::Settings::from_env()
+            })?;
+
+        Ok(Self {
+            compositor: Compositor::Wgpu(compositor),
+            renderer: Renderer::Wgpu(iced_wgpu::Renderer::new(
+                backend,
+                settings.default_font,
+                settings.default_text_size,
+            )),
+        })
+    }
+
+    /// Returns the [`Renderer`] of the [`Offscreen`] compositor.
+    pub fn renderer(&mut self) -> &mut Renderer<Theme> {
+        &mut self.renderer
+    }
+
+    /// Renders whatever has been drawn with the [`Renderer`] of the
+    /// [`Offscreen`] compositor in the given [`Viewport`], and returns the
+    /// resulting RGBA pixels.
+    pub fn render(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+    ) -> Vec<u8> {
+        match (&self.compositor, &mut self.renderer) {
+            (
+                Compositor::TinySkia(_compositor),
+                Renderer::TinySkia(renderer),
+            ) => renderer.with_primitives(|backend, primitives| {
+                iced_tiny_skia::window::compositor::offscreen(
+                    backend,
+                    primitives,
+                    viewport,
+                    background_color,
+                    &[] as &[&str],
+                )
+            }),
+            #[cfg(feature = "wgpu")]
+            (Compositor::Wgpu(compositor), Renderer::Wgpu(renderer)) => {
+                renderer.with_primitives(|backend, primitives| {
+                    iced_wgpu::window::compositor::screenshot(
+                        compositor,
+                        backend,
+                        primitives,
+                        viewport,
+                        background_color,
+                        &[] as &[&str],
+                    )
+                })
+            }
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(
+                "The renderer of an offscreen compositor always matches \
+                its backend."
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::renderer;
+    use crate::core::Size;
+
+    struct Theme;
+
+    #[test]
+    fn render_fills_the_viewport_with_the_background_color() {
+        let mut offscreen = Offscreen::<Theme>::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(8, 4), 1.0);
+
+        let bytes = offscreen.render(&viewport, Color::from_rgb8(255, 0, 0));
+
+        assert_eq!(bytes.len(), 8 * 4 * 4);
+        assert!(bytes.chunks(4).all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+
+    #[test]
+    fn opacity_groups_are_composited_as_a_whole() {
+        use crate::core::Renderer as _;
+        use crate::core::{Point, Rectangle};
+
+        let mut offscreen = Offscreen::<Theme>::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(8, 4), 1.0);
+
+        offscreen.renderer().with_opacity(0.5, |renderer| {
+            for x in [0.0, 2.0] {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(x, 0.0),
+                            Size::new(4.0, 4.0),
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    Color::WHITE,
+                );
+            }
+        });
+
+        let bytes = offscreen.render(&viewport, Color::BLACK);
+        let pixel = |x: usize| &bytes[x * 4..x * 4 + 4];
+
+        // The overlapping area is as opaque as the rest of the group
+        assert_eq!(pixel(1), pixel(3));
+        assert!((120..=136).contains(&pixel(1)[0]));
+        assert_eq!(pixel(7), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn backdrop_blur_softens_what_is_behind() {
+        use crate::core::Renderer as _;
+        use crate::core::{Point, Rectangle};
+
+        let mut offscreen = Offscreen::<Theme>::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(8, 4), 1.0);
+
+        let renderer = offscreen.renderer();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle::new(Point::ORIGIN, Size::new(4.0, 4.0)),
+                ..renderer::Quad::default()
+            },
+            Color::WHITE,
+        );
+
+        renderer.blur_backdrop(
+            Rectangle::new(Point::new(2.0, 0.0), Size::new(4.0, 4.0)),
+            0.0.into(),
+            2.0,
+        );
+
+        let bytes = offscreen.render(&viewport, Color::BLACK);
+        let pixel = |x: usize| &bytes[x * 4..x * 4 + 4];
+
+        // Only the edge inside of the blurred bounds is softened
+        assert_eq!(pixel(0), [255, 255, 255, 255]);
+        assert!((1..255).contains(&pixel(3)[0]));
+        assert!((1..255).contains(&pixel(4)[0]));
+        assert_eq!(pixel(7), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn rotated_quads_are_drawn_rotated() {
+        use crate::core::Renderer as _;
+        use crate::core::{Point, Radians, Rectangle, Transformation};
+
+        let mut offscreen = Offscreen::<Theme>::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(16, 16), 1.0);
+
+        let rotation = Transformation::translate(8.0, 8.0)
+            * Transformation::rotate(Radians(std::f32::consts::FRAC_PI_4))
+            * Transformation::translate(-8.0, -8.0);
+
+        offscreen
+            .renderer()
+            .with_transformation(rotation, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(4.0, 4.0),
+                            Size::new(8.0, 8.0),
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    Color::WHITE,
+                );
+            });
+
+        let bytes = offscreen.render(&viewport, Color::BLACK);
+        let pixel = |x: usize, y: usize| {
+            let i = (y * 16 + x) * 4;
+
+            &bytes[i..i + 4]
+        };
+
+        // The quad is a diamond, reaching beyond its unrotated bounds
+        assert_eq!(pixel(7, 7), [255, 255, 255, 255]);
+        assert!(pixel(7, 3)[0] > 200);
+        assert!(pixel(4, 4)[0] < 50);
+        assert_eq!(pixel(0, 0), [0, 0, 0, 255]);
+    }
+}
@@ -20,6 +20,8 @@ pub mod clipboard;
 pub mod command;
 pub mod font;
 pub mod keyboard;
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
 pub mod overlay;
 pub mod program;
 pub mod system;
@@ -0,0 +1,4 @@
+//! A multi-window application.
+pub mod program;
+
+pub use program::Program;
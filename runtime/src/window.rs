@@ -8,7 +8,9 @@ pub use screenshot::Screenshot;
 
 use crate::command::{self, Command};
 use crate::core::time::Instant;
-use crate::core::window::{Event, Icon, Level, Mode, UserAttention};
+use crate::core::window::{
+    Event, Icon, Id, Level, Mode, Settings, UserAttention,
+};
 use crate::core::Size;
 use crate::futures::event;
 use crate::futures::Subscription;
@@ -23,68 +25,84 @@ use crate::futures::Subscription;
 /// animations without missing any frames.
 pub fn frames() -> Subscription<Instant> {
     event::listen_raw(|event, _status| match event {
-        iced_core::Event::Window(Event::RedrawRequested(at)) => Some(at),
+        iced_core::Event::Window(_, Event::RedrawRequested(at)) => Some(at),
         _ => None,
     })
 }
 
-/// Closes the current window and exits the application.
-pub fn close<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::Close))
+/// Spawns a new window with the given [`Settings`].
+///
+/// Returns the new window [`Id`] alongside the [`Command`].
+pub fn spawn<Message>(settings: Settings) -> (Id, Command<Message>) {
+    let id = Id::unique();
+
+    (
+        id,
+        Command::single(command::Action::Window(Action::Spawn(id, settings))),
+    )
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Closing the last window exits the application.
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Close(id)))
 }
 
 /// Begins dragging the window while the left mouse button is held.
-pub fn drag<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::Drag))
+pub fn drag<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Drag(id)))
 }
 
 /// Resizes the window to the given logical dimensions.
-pub fn resize<Message>(new_size: Size<u32>) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Resize(new_size)))
+pub fn resize<Message>(id: Id, new_size: Size<u32>) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Resize(id, new_size)))
 }
 
 /// Fetches the current window size in logical dimensions.
 pub fn fetch_size<Message>(
+    id: Id,
     f: impl FnOnce(Size<u32>) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::FetchSize(Box::new(f))))
+    Command::single(command::Action::Window(Action::FetchSize(id, Box::new(f))))
 }
 
 /// Maximizes the window.
-pub fn maximize<Message>(maximized: bool) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Maximize(maximized)))
+pub fn maximize<Message>(id: Id, maximized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Maximize(id, maximized)))
 }
 
 /// Minimes the window.
-pub fn minimize<Message>(minimized: bool) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Minimize(minimized)))
+pub fn minimize<Message>(id: Id, minimized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Minimize(id, minimized)))
 }
 
 /// Moves a window to the given logical coordinates.
-pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Move { x, y }))
+pub fn move_to<Message>(id: Id, x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Move { id, x, y }))
 }
 
 /// Changes the [`Mode`] of the window.
-pub fn change_mode<Message>(mode: Mode) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeMode(mode)))
+pub fn change_mode<Message>(id: Id, mode: Mode) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeMode(id, mode)))
 }
 
 /// Fetches the current [`Mode`] of the window.
 pub fn fetch_mode<Message>(
+    id: Id,
     f: impl FnOnce(Mode) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::FetchMode(Box::new(f))))
+    Command::single(command::Action::Window(Action::FetchMode(id, Box::new(f))))
 }
 
 /// Toggles the window to maximized or back.
-pub fn toggle_maximize<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::ToggleMaximize))
+pub fn toggle_maximize<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ToggleMaximize(id)))
 }
 
 /// Toggles the window decorations.
-pub fn toggle_decorations<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::ToggleDecorations))
+pub fn toggle_decorations<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ToggleDecorations(id)))
 }
 
 /// Request user attention to the window, this has no effect if the application
@@ -94,9 +112,11 @@ pub fn toggle_decorations<Message>() -> Command<Message> {
 /// Providing `None` will unset the request for user attention. Unsetting the request for
 /// user attention might not be done automatically by the WM when the window receives input.
 pub fn request_user_attention<Message>(
+    id: Id,
     user_attention: Option<UserAttention>,
 ) -> Command<Message> {
     Command::single(command::Action::Window(Action::RequestUserAttention(
+        id,
         user_attention,
     )))
 }
@@ -107,30 +127,36 @@ pub fn request_user_attention<Message>(
 /// This [`Command`] steals input focus from other applications. Do not use this method unless
 /// you are certain that's what the user wants. Focus stealing can cause an extremely disruptive
 /// user experience.
-pub fn gain_focus<Message>() -> Command<Message> {
-    Command::single(command::Action::Window(Action::GainFocus))
+pub fn gain_focus<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GainFocus(id)))
 }
 
 /// Changes the window [`Level`].
-pub fn change_level<Message>(level: Level) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeLevel(level)))
+pub fn change_level<Message>(id: Id, level: Level) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeLevel(id, level)))
 }
 
-/// Fetches an identifier unique to the window.
+/// Fetches an identifier unique to the window, provided by the underlying windowing system. This is
+/// not to be confused with [`Id`].
 pub fn fetch_id<Message>(
+    id: Id,
     f: impl FnOnce(u64) -> Message + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::FetchId(Box::new(f))))
+    Command::single(command::Action::Window(Action::FetchId(id, Box::new(f))))
 }
 
 /// Changes the [`Icon`] of the window.
-pub fn change_icon<Message>(icon: Icon) -> Command<Message> {
-    Command::single(command::Action::Window(Action::ChangeIcon(icon)))
+pub fn change_icon<Message>(id: Id, icon: Icon) -> Command<Message> {
+    Command::single(command::Action::Window(Action::ChangeIcon(id, icon)))
 }
 
 /// Captures a [`Screenshot`] from the window.
 pub fn screenshot<Message>(
+    id: Id,
     f: impl FnOnce(Screenshot) -> Message + Send + 'static,
 ) -> Command<Message> {
-    Command::single(command::Action::Window(Action::Screenshot(Box::new(f))))
+    Command::single(command::Action::Window(Action::Screenshot(
+        id,
+        Box::new(f),
+    )))
 }
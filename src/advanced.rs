@@ -9,6 +9,7 @@ pub use crate::core::text::{self, Text};
 pub use crate::core::widget::{self, Widget};
 pub use crate::core::{Clipboard, Hasher, Shell};
 pub use crate::renderer::graphics;
+pub use crate::renderer::offscreen;
 
 pub mod subscription {
     //! Write your own subscriptions.
@@ -41,14 +41,6 @@ pub struct Settings<Flags> {
     ///
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
-
-    /// Whether the [`Application`] should exit when the user requests the
-    /// window to close (e.g. the user presses the close button).
-    ///
-    /// By default, it is enabled.
-    ///
-    /// [`Application`]: crate::Application
-    pub exit_on_close_request: bool,
 }
 
 impl<Flags> Settings<Flags> {
@@ -65,7 +57,6 @@ impl<Flags> Settings<Flags> {
             default_font: default_settings.default_font,
             default_text_size: default_settings.default_text_size,
             antialiasing: default_settings.antialiasing,
-            exit_on_close_request: default_settings.exit_on_close_request,
         }
     }
 }
@@ -82,7 +73,6 @@ where
             default_font: Font::default(),
             default_text_size: Pixels(16.0),
             antialiasing: false,
-            exit_on_close_request: true,
         }
     }
 }
@@ -91,9 +81,8 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
     fn from(settings: Settings<Flags>) -> iced_winit::Settings<Flags> {
         iced_winit::Settings {
             id: settings.id,
-            window: settings.window.into(),
+            window: settings.window,
             flags: settings.flags,
-            exit_on_close_request: settings.exit_on_close_request,
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Change the apperance of a button.
-use iced_core::{Background, BorderRadius, Color, Vector};
+use iced_core::animation::Interpolate;
+use iced_core::{Background, BorderRadius, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug, Clone, Copy)]
@@ -16,6 +17,8 @@ pub struct Appearance {
     pub border_color: Color,
     /// The text [`Color`] of the button.
     pub text_color: Color,
+    /// The [`Shadow`] of the button.
+    pub shadow: Shadow,
 }
 
 impl std::default::Default for Appearance {
@@ -27,6 +30,29 @@ impl std::default::Default for Appearance {
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
             text_color: Color::BLACK,
+            shadow: Shadow::default(),
+        }
+    }
+}
+
+impl Interpolate for Appearance {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, ratio),
+            background: self.background.interpolate(&other.background, ratio),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, ratio),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, ratio),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, ratio),
+            text_color: self.text_color.interpolate(&other.text_color, ratio),
+            shadow: self.shadow.interpolate(&other.shadow, ratio),
         }
     }
 }
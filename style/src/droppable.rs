@@ -0,0 +1,29 @@
+//! Change the appearance of a drop target.
+use iced_core::{Background, BorderRadius, Color};
+
+/// The appearance of a drop target.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the drop target, if any.
+    pub background: Option<Background>,
+    /// The border radius of the drop target.
+    pub border_radius: BorderRadius,
+    /// The border width of the drop target.
+    pub border_width: f32,
+    /// The border [`Color`] of the drop target.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a drop target.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a drop target while something is being
+    /// dragged.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Appearance`] of a drop target while something is being
+    /// dragged over it.
+    fn hovered(&self, style: &Self::Style) -> Appearance;
+}
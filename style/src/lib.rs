@@ -20,7 +20,9 @@ pub mod application;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod droppable;
 pub mod menu;
+pub mod modal;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -29,8 +31,12 @@ pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
+pub mod text_editor;
 pub mod text_input;
 pub mod theme;
+pub mod toast;
 pub mod toggler;
+pub mod tree_view;
 
 pub use theme::Theme;
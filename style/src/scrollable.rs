@@ -1,4 +1,5 @@
 //! Change the appearance of a scrollable.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, BorderRadius, Color};
 
 /// The appearance of a scrollable.
@@ -16,6 +17,24 @@ pub struct Scrollbar {
     pub scroller: Scroller,
 }
 
+impl Interpolate for Scrollbar {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            background: self.background.interpolate(&other.background, ratio),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, ratio),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, ratio),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, ratio),
+            scroller: self.scroller.interpolate(&other.scroller, ratio),
+        }
+    }
+}
+
 /// The appearance of the scroller of a scrollable.
 #[derive(Debug, Clone, Copy)]
 pub struct Scroller {
@@ -29,6 +48,23 @@ pub struct Scroller {
     pub border_color: Color,
 }
 
+impl Interpolate for Scroller {
+    fn interpolate(&self, other: &Self, ratio: f32) -> Self {
+        Self {
+            color: self.color.interpolate(&other.color, ratio),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, ratio),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, ratio),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, ratio),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a scrollable.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
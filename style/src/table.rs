@@ -0,0 +1,32 @@
+//! Change the appearance of a table.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the header.
+    pub header_background: Background,
+    /// The text [`Color`] of the header.
+    pub header_text_color: Color,
+    /// The [`Background`] of the rows, if any.
+    pub row_background: Option<Background>,
+    /// The [`Background`] of every other row, if any.
+    pub alternate_row_background: Option<Background>,
+    /// The [`Background`] of the selected rows.
+    pub selected_row_background: Background,
+    /// The text [`Color`] of the selected rows.
+    pub selected_text_color: Color,
+    /// The [`Color`] of the dividers between the columns.
+    pub divider_color: Color,
+    /// The width of the dividers between the columns.
+    pub divider_width: f32,
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a table.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
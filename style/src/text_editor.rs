@@ -0,0 +1,47 @@
+//! Change the appearance of a text editor.
+use crate::core::{Background, BorderRadius, Color};
+
+/// The appearance of a text editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the text editor.
+    pub background: Background,
+    /// The border radius of the text editor.
+    pub border_radius: BorderRadius,
+    /// The border width of the text editor.
+    pub border_width: f32,
+    /// The border [`Color`] of the text editor.
+    pub border_color: Color,
+}
+
+/// A set of rules that dictate the style of a text editor.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the style of an active text editor.
+    fn active(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the style of a focused text editor.
+    fn focused(&self, style: &Self::Style) -> Appearance;
+
+    /// Produces the [`Color`] of the placeholder of a text editor.
+    fn placeholder_color(&self, style: &Self::Style) -> Color;
+
+    /// Produces the [`Color`] of the value of a text editor.
+    fn value_color(&self, style: &Self::Style) -> Color;
+
+    /// Produces the [`Color`] of the value of a disabled text editor.
+    fn disabled_color(&self, style: &Self::Style) -> Color;
+
+    /// Produces the [`Color`] of the selection of a text editor.
+    fn selection_color(&self, style: &Self::Style) -> Color;
+
+    /// Produces the style of an hovered text editor.
+    fn hovered(&self, style: &Self::Style) -> Appearance {
+        self.focused(style)
+    }
+
+    /// Produces the style of a disabled text editor.
+    fn disabled(&self, style: &Self::Style) -> Appearance;
+}
@@ -8,7 +8,9 @@ use crate::button;
 use crate::checkbox;
 use crate::container;
 use crate::core::widget::text;
+use crate::droppable;
 use crate::menu;
+use crate::modal;
 use crate::pane_grid;
 use crate::pick_list;
 use crate::progress_bar;
@@ -17,10 +19,14 @@ use crate::rule;
 use crate::scrollable;
 use crate::slider;
 use crate::svg;
+use crate::table;
+use crate::text_editor;
 use crate::text_input;
+use crate::toast;
 use crate::toggler;
+use crate::tree_view;
 
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, Color, Shadow, Vector};
 
 use std::rc::Rc;
 
@@ -403,6 +409,8 @@ impl container::StyleSheet for Theme {
                     border_radius: 2.0.into(),
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    shadow: Shadow::default(),
+                    backdrop_blur: 0.0,
                 }
             }
             Container::Custom(custom) => custom.appearance(self),
@@ -880,6 +888,249 @@ impl<T: Fn(&Theme) -> rule::Appearance> rule::StyleSheet for T {
     }
 }
 
+/// The style of a drop target.
+#[derive(Default)]
+pub enum Droppable {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn droppable::StyleSheet<Style = Theme>>),
+}
+
+impl droppable::StyleSheet for Theme {
+    type Style = Droppable;
+
+    fn active(&self, style: &Self::Style) -> droppable::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Droppable::Default => droppable::Appearance {
+                background: None,
+                border_radius: 2.0.into(),
+                border_width: 1.0,
+                border_color: palette.primary.weak.color,
+            },
+            Droppable::Custom(custom) => custom.active(self),
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> droppable::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Droppable::Default => droppable::Appearance {
+                background: Some(
+                    Color {
+                        a: 0.2,
+                        ..palette.primary.base.color
+                    }
+                    .into(),
+                ),
+                border_color: palette.primary.strong.color,
+                ..self.active(style)
+            },
+            Droppable::Custom(custom) => custom.hovered(self),
+        }
+    }
+}
+
+/// The style of a modal.
+#[derive(Default)]
+pub enum Modal {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn modal::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> modal::Appearance + 'static> From<T> for Modal {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl modal::StyleSheet for Theme {
+    type Style = Modal;
+
+    fn appearance(&self, style: &Self::Style) -> modal::Appearance {
+        match style {
+            Modal::Default => modal::Appearance {
+                backdrop: Color {
+                    a: 0.8,
+                    ..Color::BLACK
+                }
+                .into(),
+            },
+            Modal::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> modal::Appearance> modal::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> modal::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a toast.
+#[derive(Default)]
+pub enum Toast {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn toast::StyleSheet<Style = Theme>>),
+}
+
+impl toast::StyleSheet for Theme {
+    type Style = Toast;
+
+    fn appearance(
+        &self,
+        style: &Self::Style,
+        status: toast::Status,
+    ) -> toast::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Toast::Default => {
+                let (pair, border) = match status {
+                    toast::Status::Primary => {
+                        (palette.primary.weak, palette.primary.strong)
+                    }
+                    toast::Status::Secondary => {
+                        (palette.secondary.weak, palette.secondary.strong)
+                    }
+                    toast::Status::Success => {
+                        (palette.success.weak, palette.success.strong)
+                    }
+                    toast::Status::Danger => {
+                        (palette.danger.weak, palette.danger.strong)
+                    }
+                };
+
+                toast::Appearance {
+                    text_color: pair.text,
+                    background: pair.color.into(),
+                    border_radius: 4.0.into(),
+                    border_width: 1.0,
+                    border_color: border.color,
+                    shadow: Shadow {
+                        color: Color {
+                            a: 0.2,
+                            ..Color::BLACK
+                        },
+                        offset: Vector::new(0.0, 2.0),
+                        blur_radius: 6.0,
+                    },
+                }
+            }
+            Toast::Custom(custom) => custom.appearance(self, status),
+        }
+    }
+}
+
+/// The style of a tree view.
+#[derive(Default)]
+pub enum TreeView {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn tree_view::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> tree_view::Appearance + 'static> From<T> for TreeView {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl tree_view::StyleSheet for Theme {
+    type Style = TreeView;
+
+    fn appearance(&self, style: &Self::Style) -> tree_view::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            TreeView::Default => tree_view::Appearance {
+                hovered_background: Some(palette.background.weak.color.into()),
+                selected_background: palette.primary.weak.color.into(),
+                selected_text_color: palette.primary.weak.text,
+                focused_border_color: palette.primary.strong.color,
+                guide_color: palette.background.strong.color,
+                arrow_color: palette.background.base.text,
+                drop_indicator_color: palette.primary.strong.color,
+            },
+            TreeView::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> tree_view::Appearance> tree_view::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> tree_view::Appearance {
+        (self)(style)
+    }
+}
+
+/// The style of a table.
+#[derive(Default)]
+pub enum Table {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn table::StyleSheet<Style = Theme>>),
+}
+
+impl<T: Fn(&Theme) -> table::Appearance + 'static> From<T> for Table {
+    fn from(f: T) -> Self {
+        Self::Custom(Box::new(f))
+    }
+}
+
+impl table::StyleSheet for Theme {
+    type Style = Table;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        let palette = self.extended_palette();
+
+        match style {
+            Table::Default => table::Appearance {
+                header_background: palette.background.weak.color.into(),
+                header_text_color: palette.background.weak.text,
+                row_background: None,
+                alternate_row_background: Some(
+                    Color {
+                        a: 0.3,
+                        ..palette.background.weak.color
+                    }
+                    .into(),
+                ),
+                selected_row_background: palette.primary.weak.color.into(),
+                selected_text_color: palette.primary.weak.text,
+                divider_color: palette.background.strong.color,
+                divider_width: 1.0,
+            },
+            Table::Custom(custom) => custom.appearance(self),
+        }
+    }
+}
+
+impl<T: Fn(&Theme) -> table::Appearance> table::StyleSheet for T {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> table::Appearance {
+        (self)(style)
+    }
+}
+
 /**
  * Svg
  */
@@ -1174,3 +1425,115 @@ impl text_input::StyleSheet for Theme {
         self.placeholder_color(style)
     }
 }
+
+/// The style of a text editor.
+#[derive(Default)]
+pub enum TextEditor {
+    /// The default style.
+    #[default]
+    Default,
+    /// A custom style.
+    Custom(Box<dyn text_editor::StyleSheet<Style = Theme>>),
+}
+
+impl text_editor::StyleSheet for Theme {
+    type Style = TextEditor;
+
+    fn active(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.active(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.hovered(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.base.text,
+        }
+    }
+
+    fn focused(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.focused(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.base.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.primary.strong.color,
+        }
+    }
+
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.placeholder_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.background.strong.color
+    }
+
+    fn value_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.value_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.background.base.text
+    }
+
+    fn selection_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.selection_color(self);
+        }
+
+        let palette = self.extended_palette();
+
+        palette.primary.weak.color
+    }
+
+    fn disabled(&self, style: &Self::Style) -> text_editor::Appearance {
+        if let TextEditor::Custom(custom) = style {
+            return custom.disabled(self);
+        }
+
+        let palette = self.extended_palette();
+
+        text_editor::Appearance {
+            background: palette.background.weak.color.into(),
+            border_radius: 2.0.into(),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+        }
+    }
+
+    fn disabled_color(&self, style: &Self::Style) -> Color {
+        if let TextEditor::Custom(custom) = style {
+            return custom.disabled_color(self);
+        }
+
+        self.placeholder_color(style)
+    }
+}
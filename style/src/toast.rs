@@ -0,0 +1,62 @@
+//! Change the appearance of a toast.
+use iced_core::{Background, BorderRadius, Color, Shadow};
+
+use std::fmt;
+
+/// The status of a toast, which usually dictates its colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Status {
+    /// A toast with some primary information.
+    #[default]
+    Primary,
+    /// A toast with some secondary information.
+    Secondary,
+    /// A toast reporting the success of an action.
+    Success,
+    /// A toast reporting an error or a dangerous situation.
+    Danger,
+}
+
+impl Status {
+    /// All the variants of a [`Status`].
+    pub const ALL: &'static [Self] =
+        &[Self::Primary, Self::Secondary, Self::Success, Self::Danger];
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Primary => "Primary",
+            Status::Secondary => "Secondary",
+            Status::Success => "Success",
+            Status::Danger => "Danger",
+        }
+        .fmt(f)
+    }
+}
+
+/// The appearance of a toast.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The text [`Color`] of the toast.
+    pub text_color: Color,
+    /// The [`Background`] of the toast.
+    pub background: Background,
+    /// The border radius of the toast.
+    pub border_radius: BorderRadius,
+    /// The border width of the toast.
+    pub border_width: f32,
+    /// The border [`Color`] of the toast.
+    pub border_color: Color,
+    /// The [`Shadow`] of the toast.
+    pub shadow: Shadow,
+}
+
+/// A set of rules that dictate the style of a toast.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a toast with the given [`Status`].
+    fn appearance(&self, style: &Self::Style, status: Status) -> Appearance;
+}
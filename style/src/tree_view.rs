@@ -0,0 +1,32 @@
+//! Change the appearance of a tree view.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Appearance {
+    /// The [`Background`] of the hovered node, if any.
+    pub hovered_background: Option<Background>,
+    /// The [`Background`] of the selected nodes.
+    pub selected_background: Background,
+    /// The text [`Color`] of the selected nodes.
+    pub selected_text_color: Color,
+    /// The border [`Color`] of the node under the keyboard cursor, when the
+    /// tree view is focused.
+    pub focused_border_color: Color,
+    /// The [`Color`] of the indentation guides.
+    pub guide_color: Color,
+    /// The [`Color`] of the expand and collapse arrows.
+    pub arrow_color: Color,
+    /// The [`Color`] of the indicator of where a dragged node will be
+    /// dropped.
+    pub drop_indicator_color: Color,
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// The supported style of the [`StyleSheet`].
+    type Style: Default;
+
+    /// Produces the [`Appearance`] of a tree view.
+    fn appearance(&self, style: &Self::Style) -> Appearance;
+}
@@ -0,0 +1,447 @@
+//! A headless simulator to test `iced` user interfaces.
+//!
+//! `iced_test` builds a [`UserInterface`] on top of a software renderer, so
+//! the interaction logic of a view can be exercised without a display server.
+//!
+//! # Example
+//! ```no_run
+//! use iced_test::Simulator;
+//! use iced_widget::button;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Message {
+//!     Increment,
+//! }
+//!
+//! let mut simulator =
+//!     Simulator::new(button("Increment").on_press(Message::Increment));
+//!
+//! let _ = simulator.click("Increment").expect("Find button");
+//!
+//! assert_eq!(
+//!     simulator.into_messages().collect::<Vec<_>>(),
+//!     vec![Message::Increment]
+//! );
+//! ```
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/iced-rs/iced/9ab6923e943f784985e9ef9ca28b10278297225d/docs/logo.svg"
+)]
+#![forbid(unsafe_code, rust_2018_idioms)]
+#![deny(
+    missing_debug_implementations,
+    missing_docs,
+    unused_results,
+    rustdoc::broken_intra_doc_links
+)]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+pub mod offscreen;
+pub mod selector;
+pub mod snapshot;
+
+pub use iced_renderer as renderer;
+pub use iced_runtime as runtime;
+pub use iced_runtime::core;
+pub use iced_style as style;
+
+pub use selector::{Selector, Target};
+pub use snapshot::Snapshot;
+
+use crate::core::clipboard;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::mouse;
+use crate::core::renderer::Style;
+use crate::core::{Element, Font, Pixels, Point, Size};
+use crate::renderer::graphics::Viewport;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::style::application;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// The default [`Renderer`] of a [`Simulator`].
+///
+/// [`Renderer`]: crate::core::Renderer
+pub type Renderer<Theme = style::Theme> = renderer::Renderer<Theme>;
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// No widget matches the given [`Selector`].
+    #[error("no widget matches the selector: {0:?}")]
+    NotFound(Selector),
+
+    /// The renderer of the [`Simulator`] cannot take snapshots.
+    #[error("the renderer does not support snapshots")]
+    SnapshotUnsupported,
+
+    /// The sizes of two [`Snapshot`]s do not match.
+    #[error("expected a snapshot of size {expected:?}, found {actual:?}")]
+    SizeMismatch {
+        /// The size of the reference [`Snapshot`].
+        expected: Size<u32>,
+        /// The size of the compared [`Snapshot`].
+        actual: Size<u32>,
+    },
+
+    /// The reference image of a [`Snapshot`] does not exist.
+    ///
+    /// Set the [`snapshot::UPDATE_VARIABLE`] environment variable to save
+    /// the missing reference images.
+    #[error("the reference image {0:?} does not exist")]
+    ReferenceMissing(PathBuf),
+
+    /// An I/O operation failed.
+    #[error("input/output operation failed: {0}")]
+    Io(Arc<std::io::Error>),
+
+    /// An image could not be encoded or decoded.
+    #[error("image operation failed: {0}")]
+    Image(Arc<image::ImageError>),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(Arc::new(error))
+    }
+}
+
+impl From<image::ImageError> for Error {
+    fn from(error: image::ImageError) -> Self {
+        Self::Image(Arc::new(error))
+    }
+}
+
+/// A user interface that can be interacted with programmatically.
+///
+/// Every [`Message`] produced by the widgets is queued and can be drained
+/// with [`Simulator::messages`] or [`Simulator::into_messages`].
+///
+/// [`Message`]: crate::core::Element
+#[allow(missing_debug_implementations)]
+pub struct Simulator<'a, Message, Renderer = crate::Renderer> {
+    raw: UserInterface<'a, Message, Renderer>,
+    renderer: Renderer,
+    size: Size,
+    cursor: mouse::Cursor,
+    messages: Vec<Message>,
+}
+
+impl<'a, Message> Simulator<'a, Message> {
+    /// Creates a new [`Simulator`] of the given [`Element`] with a default
+    /// size.
+    pub fn new(
+        element: impl Into<Element<'a, Message, crate::Renderer>>,
+    ) -> Self {
+        Self::with_size(Size::new(1024.0, 768.0), element)
+    }
+
+    /// Creates a new [`Simulator`] of the given [`Element`] with the given
+    /// size.
+    pub fn with_size(
+        size: impl Into<Size>,
+        element: impl Into<Element<'a, Message, crate::Renderer>>,
+    ) -> Self {
+        let renderer =
+            renderer::Renderer::TinySkia(iced_tiny_skia::Renderer::new(
+                iced_tiny_skia::Backend::new(),
+                Font::default(),
+                Pixels(16.0),
+            ));
+
+        Self::with_renderer(renderer, size, element)
+    }
+
+    /// Renders the current state of the user interface offscreen with the
+    /// given [`Theme`] and returns its [`Snapshot`].
+    ///
+    /// [`Theme`]: crate::style::Theme
+    pub fn snapshot(
+        &mut self,
+        theme: &style::Theme,
+    ) -> Result<Snapshot, Error> {
+        use application::StyleSheet as _;
+
+        let appearance = theme.appearance(&Default::default());
+
+        let _ = self.raw.draw(
+            &mut self.renderer,
+            theme,
+            &Style {
+                text_color: appearance.text_color,
+            },
+            self.cursor,
+        );
+
+        let size = Size::new(
+            self.size.width.ceil() as u32,
+            self.size.height.ceil() as u32,
+        );
+        let viewport = Viewport::with_physical_size(size, 1.0);
+
+        match &mut self.renderer {
+            renderer::Renderer::TinySkia(renderer) => {
+                let rgba = renderer.with_primitives(|backend, primitives| {
+                    iced_tiny_skia::window::compositor::offscreen(
+                        backend,
+                        primitives,
+                        &viewport,
+                        appearance.background_color,
+                        &[] as &[&str],
+                    )
+                });
+
+                Ok(Snapshot::new(rgba, size))
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::SnapshotUnsupported),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Simulator<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Simulator`] of the given [`Element`] using a custom
+    /// renderer.
+    pub fn with_renderer(
+        mut renderer: Renderer,
+        size: impl Into<Size>,
+        element: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        let size = size.into();
+
+        let raw = UserInterface::build(
+            element,
+            size,
+            user_interface::Cache::default(),
+            &mut renderer,
+        );
+
+        Self {
+            raw,
+            renderer,
+            size,
+            cursor: mouse::Cursor::Unavailable,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Finds the first widget matching the given [`Selector`].
+    pub fn find(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let selector = selector.into();
+        let mut find = selector.find();
+
+        self.raw.operate(&self.renderer, &mut find);
+
+        find.target().ok_or(Error::NotFound(selector))
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn point_at(&mut self, position: impl Into<Point>) -> event::Status {
+        let position = position.into();
+
+        self.cursor = mouse::Cursor::Available(position);
+
+        self.simulate_one(Event::Mouse(mouse::Event::CursorMoved { position }))
+    }
+
+    /// Clicks the center of the first widget matching the given [`Selector`]
+    /// with the left mouse button.
+    pub fn click(
+        &mut self,
+        selector: impl Into<Selector>,
+    ) -> Result<Target, Error> {
+        let target = self.find(selector)?;
+
+        let _ = self.point_at(target.bounds.center());
+        let _ = self.simulate([
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ]);
+
+        Ok(target)
+    }
+
+    /// Presses and releases the given key.
+    pub fn tap_key(&mut self, key_code: keyboard::KeyCode) -> event::Status {
+        let modifiers = keyboard::Modifiers::default();
+
+        self.simulate([
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Types the given text, character by character.
+    pub fn typewrite(&mut self, text: &str) -> event::Status {
+        self.simulate(
+            text.chars()
+                .map(keyboard::Event::CharacterReceived)
+                .map(Event::Keyboard),
+        )
+        .into_iter()
+        .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    /// Processes the given events in order, returning the [`event::Status`]
+    /// of each one.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Vec<event::Status> {
+        let events: Vec<Event> = events.into_iter().collect();
+
+        let (_state, statuses) = self.raw.update(
+            &events,
+            self.cursor,
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut self.messages,
+        );
+
+        statuses
+    }
+
+    /// Drains the messages produced by the user interface so far.
+    pub fn messages(&mut self) -> impl Iterator<Item = Message> + '_ {
+        self.messages.drain(..)
+    }
+
+    /// Consumes the [`Simulator`] and returns all the messages produced by
+    /// the user interface.
+    pub fn into_messages(self) -> impl Iterator<Item = Message> {
+        self.messages.into_iter()
+    }
+
+    fn simulate_one(&mut self, event: Event) -> event::Status {
+        self.simulate([event])
+            .into_iter()
+            .next()
+            .unwrap_or(event::Status::Ignored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_widget::{button, column, text_input};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Increment,
+        NameChanged(String),
+    }
+
+    #[test]
+    fn click_finds_widgets_by_text() {
+        let mut simulator =
+            Simulator::new(button("Increment").on_press(Message::Increment));
+
+        let _ = simulator.click("Increment").expect("Find button");
+
+        assert_eq!(
+            simulator.into_messages().collect::<Vec<_>>(),
+            vec![Message::Increment]
+        );
+    }
+
+    #[test]
+    fn click_finds_widgets_by_id() {
+        let mut simulator = Simulator::new(column![
+            button("Increment").on_press(Message::Increment),
+            text_input("Name", "")
+                .id(text_input::Id::new("name"))
+                .on_input(Message::NameChanged),
+        ]);
+
+        let _ = simulator
+            .click(Selector::id(text_input::Id::new("name")))
+            .expect("Find text input");
+
+        let _ = simulator.typewrite("hi");
+
+        assert_eq!(
+            simulator.messages().collect::<Vec<_>>(),
+            vec![
+                Message::NameChanged(String::from("h")),
+                Message::NameChanged(String::from("hi")),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_fails_for_missing_widgets() {
+        let mut simulator = Simulator::<Message>::new(button("Increment"));
+
+        assert!(matches!(
+            simulator.find("Decrement"),
+            Err(Error::NotFound(Selector::Text(text))) if text == "Decrement"
+        ));
+    }
+
+    #[test]
+    fn text_inputs_are_found_by_their_value_only() {
+        let mut simulator = Simulator::<Message>::new(column![
+            text_input("Name", ""),
+            text_input("Surname", "Lovelace"),
+        ]);
+
+        assert!(matches!(simulator.find("Name"), Err(Error::NotFound(_))));
+        assert!(simulator.find("Lovelace").is_ok());
+    }
+
+    #[test]
+    fn snapshots_are_deterministic() {
+        let view = || button("Increment").on_press(Message::Increment);
+
+        let a = Simulator::with_size([200.0, 100.0], view())
+            .snapshot(&style::Theme::Light)
+            .expect("Take snapshot");
+
+        let b = Simulator::with_size([200.0, 100.0], view())
+            .snapshot(&style::Theme::Light)
+            .expect("Take snapshot");
+
+        assert_eq!(a.size(), Size::new(200, 100));
+        assert!(a.diff(&b, 0).expect("Compare snapshots").is_match());
+    }
+
+    #[test]
+    fn diff_respects_tolerance() {
+        let a =
+            Snapshot::new(vec![0, 0, 0, 255, 10, 10, 10, 255], Size::new(2, 1));
+        let b =
+            Snapshot::new(vec![0, 0, 0, 255, 13, 10, 10, 255], Size::new(2, 1));
+
+        let diff = a.diff(&b, 2).expect("Compare snapshots");
+
+        assert_eq!(diff.mismatched_pixels, 1);
+        assert_eq!(diff.max_difference, 3);
+        assert!(a.diff(&b, 3).expect("Compare snapshots").is_match());
+    }
+
+    #[test]
+    fn empty_user_interfaces_produce_empty_snapshots() {
+        let snapshot =
+            Simulator::<Message>::with_size([0.0, 0.0], button("Increment"))
+                .snapshot(&style::Theme::Light)
+                .expect("Take snapshot");
+
+        assert_eq!(snapshot.size(), Size::new(0, 0));
+        assert!(snapshot.rgba().is_empty());
+    }
+}
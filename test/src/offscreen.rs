@@ -0,0 +1,84 @@
+//! Render user interfaces to screenshots, without a window.
+use crate::core::renderer;
+use crate::core::{mouse, Color, Element};
+use crate::renderer::graphics::Viewport;
+use crate::renderer::{Offscreen, Renderer};
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::window::Screenshot;
+
+/// Lays out and draws the given [`Element`] with an [`Offscreen`]
+/// compositor in the given [`Viewport`], and returns the resulting
+/// [`Screenshot`].
+pub fn render<'a, Message, Theme>(
+    offscreen: &mut Offscreen<Theme>,
+    element: impl Into<Element<'a, Message, Renderer<Theme>>>,
+    viewport: &Viewport,
+    theme: &Theme,
+    style: &renderer::Style,
+    background_color: Color,
+) -> Screenshot
+where
+    Theme: 'a,
+{
+    let mut user_interface = UserInterface::build(
+        element,
+        viewport.logical_size(),
+        user_interface::Cache::default(),
+        offscreen.renderer(),
+    );
+
+    let _ = user_interface.draw(
+        offscreen.renderer(),
+        theme,
+        style,
+        mouse::Cursor::Unavailable,
+    );
+
+    screenshot(offscreen, viewport, background_color)
+}
+
+/// Renders whatever has been drawn with the [`Renderer`] of the given
+/// [`Offscreen`] compositor in the given [`Viewport`], and returns the
+/// resulting [`Screenshot`].
+pub fn screenshot<Theme>(
+    offscreen: &mut Offscreen<Theme>,
+    viewport: &Viewport,
+    background_color: Color,
+) -> Screenshot {
+    Screenshot::new(
+        offscreen.render(viewport, background_color),
+        viewport.physical_size(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+    use crate::renderer::Settings;
+    use crate::style::Theme;
+
+    #[test]
+    fn render_fills_the_viewport_with_the_background_color() {
+        let mut offscreen = Offscreen::<Theme>::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(8, 4), 1.0);
+
+        let screenshot = render::<(), _>(
+            &mut offscreen,
+            iced_widget::text(""),
+            &viewport,
+            &Theme::Light,
+            &renderer::Style {
+                text_color: Color::BLACK,
+            },
+            Color::from_rgb8(255, 0, 0),
+        );
+
+        assert_eq!(screenshot.size, Size::new(8, 4));
+        assert_eq!(screenshot.bytes.len(), 8 * 4 * 4);
+        assert!(screenshot
+            .bytes
+            .chunks(4)
+            .all(|pixel| pixel == [255, 0, 0, 255]));
+    }
+}
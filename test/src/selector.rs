@@ -0,0 +1,170 @@
+//! Select widgets of a user interface.
+use crate::core::widget::{self, Operation};
+use crate::core::Rectangle;
+
+use std::borrow::Cow;
+
+/// A query that selects a widget of a user interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selector {
+    /// Select the widget with the given [`widget::Id`].
+    Id(widget::Id),
+
+    /// Select the widget displaying the given text.
+    Text(Cow<'static, str>),
+}
+
+impl Selector {
+    /// Creates a [`Selector`] that matches the widget with the given
+    /// [`widget::Id`].
+    pub fn id(id: impl Into<widget::Id>) -> Self {
+        Self::Id(id.into())
+    }
+
+    /// Creates a [`Selector`] that matches the widget displaying the given
+    /// text.
+    pub fn text(text: impl Into<Cow<'static, str>>) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub(crate) fn find(&self) -> Find<'_> {
+        Find {
+            selector: self,
+            target: None,
+        }
+    }
+}
+
+impl From<widget::Id> for Selector {
+    fn from(id: widget::Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl From<&'static str> for Selector {
+    fn from(text: &'static str) -> Self {
+        Self::Text(Cow::Borrowed(text))
+    }
+}
+
+impl From<String> for Selector {
+    fn from(text: String) -> Self {
+        Self::Text(Cow::Owned(text))
+    }
+}
+
+/// A widget found by a [`Selector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Target {
+    /// The bounds of the widget.
+    pub bounds: Rectangle,
+}
+
+pub(crate) struct Find<'a> {
+    selector: &'a Selector,
+    target: Option<Target>,
+}
+
+impl<'a> Find<'a> {
+    pub fn target(self) -> Option<Target> {
+        self.target
+    }
+}
+
+impl<'a, T> Operation<T> for Find<'a> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.target.is_some() {
+            return;
+        }
+
+        match (self.selector, id) {
+            (Selector::Id(target), Some(id)) if target == id => {
+                self.target = Some(Target { bounds });
+            }
+            _ => {
+                operate_on_children(self);
+            }
+        }
+    }
+
+    fn focusable(
+        &mut self,
+        _state: &mut dyn widget::operation::Focusable,
+        id: Option<&widget::Id>,
+        bounds: Rectangle,
+    ) {
+        if self.target.is_some() {
+            return;
+        }
+
+        if let (Selector::Id(target), Some(id)) = (self.selector, id) {
+            if target == id {
+                self.target = Some(Target { bounds });
+            }
+        }
+    }
+
+    fn text(&mut self, id: Option<&widget::Id>, bounds: Rectangle, text: &str) {
+        if self.target.is_some() {
+            return;
+        }
+
+        let is_match = match (self.selector, id) {
+            (Selector::Id(target), Some(id)) => target == id,
+            (Selector::Text(target), _) => target == text,
+            _ => false,
+        };
+
+        if is_match {
+            self.target = Some(Target { bounds });
+        }
+    }
+
+    fn wants_text(&self) -> bool {
+        matches!(self.selector, Selector::Text(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::widget::operation::Focusable;
+    use crate::core::Size;
+
+    struct State;
+
+    impl Focusable for State {
+        fn is_focused(&self) -> bool {
+            false
+        }
+
+        fn focus(&mut self) {}
+
+        fn unfocus(&mut self) {}
+    }
+
+    #[test]
+    fn focusable_widgets_are_found_by_id() {
+        let id = widget::Id::new("tree");
+        let bounds = Rectangle::with_size(Size::new(100.0, 50.0));
+
+        let selector = Selector::id(id.clone());
+        let mut find = selector.find();
+
+        Operation::<()>::focusable(
+            &mut find,
+            &mut State,
+            Some(&widget::Id::new("other")),
+            Rectangle::with_size(Size::new(10.0, 10.0)),
+        );
+        Operation::<()>::focusable(&mut find, &mut State, Some(&id), bounds);
+
+        assert_eq!(find.target(), Some(Target { bounds }));
+        assert!(!Operation::<()>::wants_text(&selector.find()));
+    }
+}
@@ -0,0 +1,212 @@
+//! Compare the rendered output of a user interface against reference images.
+use crate::core::Size;
+use crate::Error;
+
+use std::path::Path;
+
+/// The environment variable that makes [`Snapshot::matches_image`] save the
+/// compared [`Snapshot`] as the new reference image.
+pub const UPDATE_VARIABLE: &str = "ICED_TEST_UPDATE_SNAPSHOTS";
+
+/// The rendered pixels of a user interface.
+///
+/// A [`Snapshot`] can be produced with [`Simulator::snapshot`].
+///
+/// [`Simulator::snapshot`]: crate::Simulator::snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    rgba: Vec<u8>,
+    size: Size<u32>,
+}
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] from the given RGBA pixels and physical
+    /// size.
+    ///
+    /// # Panics
+    /// The amount of pixels must match the given size.
+    pub fn new(rgba: Vec<u8>, size: Size<u32>) -> Self {
+        assert_eq!(
+            rgba.len(),
+            size.width as usize * size.height as usize * 4,
+            "the amount of pixels must match the size of the snapshot"
+        );
+
+        Self { rgba, size }
+    }
+
+    /// Loads a [`Snapshot`] from the PNG image at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let image = image::open(path)?.to_rgba8();
+        let size = Size::new(image.width(), image.height());
+
+        Ok(Self::new(image.into_raw(), size))
+    }
+
+    /// Saves the [`Snapshot`] as a PNG image at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        image::save_buffer(
+            path,
+            &self.rgba,
+            self.size.width,
+            self.size.height,
+            image::ColorType::Rgba8,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the RGBA pixels of the [`Snapshot`].
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    /// Returns the physical size of the [`Snapshot`].
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Computes the [`Diff`] between the [`Snapshot`] and the given
+    /// reference.
+    ///
+    /// A pixel is considered different when any of its channels differs by
+    /// more than the given `tolerance`.
+    pub fn diff(&self, reference: &Self, tolerance: u8) -> Result<Diff, Error> {
+        if self.size != reference.size {
+            return Err(Error::SizeMismatch {
+                expected: reference.size,
+                actual: self.size,
+            });
+        }
+
+        let (mismatched_pixels, max_difference) = self
+            .rgba
+            .chunks_exact(4)
+            .zip(reference.rgba.chunks_exact(4))
+            .map(|(a, b)| {
+                a.iter()
+                    .zip(b)
+                    .map(|(a, b)| a.abs_diff(*b))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .fold((0, 0), |(mismatched, max), difference| {
+                (
+                    mismatched + usize::from(difference > tolerance),
+                    max.max(difference),
+                )
+            });
+
+        Ok(Diff {
+            mismatched_pixels,
+            total_pixels: self.rgba.len() / 4,
+            max_difference,
+        })
+    }
+
+    /// Compares the [`Snapshot`] against the reference PNG image at the
+    /// given path and returns whether they match within the given
+    /// `tolerance`.
+    ///
+    /// If the reference image does not exist, an
+    /// [`Error::ReferenceMissing`] is returned. When the [`UPDATE_VARIABLE`]
+    /// environment variable is set, the [`Snapshot`] is saved as the
+    /// reference image instead and considered a match.
+    pub fn matches_image(
+        &self,
+        path: impl AsRef<Path>,
+        tolerance: u8,
+    ) -> Result<bool, Error> {
+        let update = std::env::var_os(UPDATE_VARIABLE).is_some();
+
+        self.compare_image(path.as_ref(), tolerance, update)
+    }
+
+    fn compare_image(
+        &self,
+        path: &Path,
+        tolerance: u8,
+        update: bool,
+    ) -> Result<bool, Error> {
+        if update {
+            self.save(path)?;
+
+            return Ok(true);
+        }
+
+        if !path.exists() {
+            return Err(Error::ReferenceMissing(path.to_path_buf()));
+        }
+
+        let reference = Self::load(path)?;
+
+        match self.diff(&reference, tolerance) {
+            Ok(diff) => Ok(diff.is_match()),
+            Err(Error::SizeMismatch { .. }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// The per-pixel difference between two [`Snapshot`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diff {
+    /// The amount of pixels that differ beyond the tolerance.
+    pub mismatched_pixels: usize,
+
+    /// The total amount of pixels compared.
+    pub total_pixels: usize,
+
+    /// The largest channel difference found between two pixels.
+    pub max_difference: u8,
+}
+
+impl Diff {
+    /// Returns true if no pixels differ beyond the tolerance.
+    pub fn is_match(&self) -> bool {
+        self.mismatched_pixels == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_images_are_saved_loaded_and_compared() {
+        let directory = std::env::temp_dir()
+            .join(format!("iced_test-snapshot-{}", std::process::id()));
+        let path = directory.join("reference.png");
+
+        let snapshot = Snapshot::new(
+            vec![255, 0, 0, 255, 0, 0, 255, 255],
+            Size::new(2, 1),
+        );
+        let other = Snapshot::new(
+            vec![255, 0, 0, 255, 0, 255, 0, 255],
+            Size::new(2, 1),
+        );
+
+        assert!(matches!(
+            snapshot.compare_image(&path, 0, false),
+            Err(Error::ReferenceMissing(missing)) if missing == path
+        ));
+        assert!(!path.exists());
+
+        assert!(snapshot
+            .compare_image(&path, 0, true)
+            .expect("Save reference"));
+        assert_eq!(Snapshot::load(&path).expect("Load reference"), snapshot);
+
+        assert!(snapshot.compare_image(&path, 0, false).expect("Compare"));
+        assert!(!other.compare_image(&path, 0, false).expect("Compare"));
+
+        std::fs::remove_dir_all(directory).expect("Remove reference");
+    }
+}
@@ -1,6 +1,7 @@
-use crate::core::{Background, Color, Gradient, Rectangle, Vector};
+use crate::core::{
+    Background, Color, Gradient, Point, Rectangle, Size, Transformation, Vector,
+};
 use crate::graphics::backend;
-use crate::graphics::text;
 use crate::graphics::{Damage, Viewport};
 use crate::primitive::{self, Primitive};
 
@@ -104,7 +105,7 @@ impl Backend {
                     clip_mask,
                     region,
                     scale_factor,
-                    Vector::ZERO,
+                    Transformation::IDENTITY,
                 );
             }
 
@@ -144,7 +145,7 @@ impl Backend {
         clip_mask: &mut tiny_skia::Mask,
         clip_bounds: Rectangle,
         scale_factor: f32,
-        translation: Vector,
+        transformation: Transformation,
     ) {
         match primitive {
             Primitive::Quad {
@@ -153,21 +154,41 @@ impl Backend {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
-
-                if !clip_bounds.intersects(&physical_bounds) {
+                let physical_bounds = (*bounds * transformation) * scale_factor;
+
+                let shadow_bounds = (shadow.color.a > 0.0).then(|| {
+                    (Rectangle {
+                        x: bounds.x + shadow.offset.x.min(0.0)
+                            - shadow.blur_radius,
+                        y: bounds.y + shadow.offset.y.min(0.0)
+                            - shadow.blur_radius,
+                        width: bounds.width
+                            + shadow.offset.x.abs()
+                            + shadow.blur_radius * 2.0,
+                        height: bounds.height
+                            + shadow.offset.y.abs()
+                            + shadow.blur_radius * 2.0,
+                    } * transformation)
+                        * scale_factor
+                });
+
+                if !clip_bounds.intersects(&physical_bounds)
+                    && !shadow_bounds.is_some_and(|shadow_bounds| {
+                        clip_bounds.intersects(&shadow_bounds)
+                    })
+                {
                     return;
                 }
 
-                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
-                    .then_some(clip_mask as &_);
+                let clip_mask = (!shadow_bounds
+                    .unwrap_or(physical_bounds)
+                    .is_within(&clip_bounds))
+                .then_some(clip_mask as &_);
 
-                let transform = tiny_skia::Transform::from_translate(
-                    translation.x,
-                    translation.y,
-                )
-                .post_scale(scale_factor, scale_factor);
+                let transform = into_transform(transformation)
+                    .post_scale(scale_factor, scale_factor);
 
                 // Make sure the border radius is not larger than the bounds
                 let border_width = border_width
@@ -182,6 +203,42 @@ impl Backend {
                 }
                 let path = rounded_rectangle(*bounds, fill_border_radius);
 
+                if let Some(shadow_bounds) = shadow_bounds {
+                    draw_shadow(
+                        pixels,
+                        clip_mask,
+                        shadow_bounds,
+                        ((*bounds + shadow.offset) * transformation)
+                            * scale_factor,
+                        fill_border_radius.map(|radius| {
+                            radius
+                                * transformation.scale_factor()
+                                * scale_factor
+                        }),
+                        shadow.color,
+                        shadow.blur_radius
+                            * transformation.scale_factor()
+                            * scale_factor,
+                    );
+                }
+
+                // Conic gradients are rasterized into a pattern
+                let conic = match background {
+                    Background::Gradient(Gradient::Conic(conic)) => {
+                        crate::gradient::conic(
+                            crate::graphics::gradient::relative_to(
+                                *bounds,
+                                conic.center,
+                            ),
+                            conic.angle,
+                            &conic.stops,
+                            *bounds,
+                            scale_factor,
+                        )
+                    }
+                    _ => None,
+                };
+
                 pixels.fill_path(
                     &path,
                     &tiny_skia::Paint {
@@ -195,44 +252,41 @@ impl Backend {
                                 let (start, end) =
                                     linear.angle.to_distance(bounds);
 
-                                let stops: Vec<tiny_skia::GradientStop> =
-                                    linear
-                                        .stops
-                                        .into_iter()
-                                        .flatten()
-                                        .map(|stop| {
-                                            tiny_skia::GradientStop::new(
-                                                stop.offset,
-                                                tiny_skia::Color::from_rgba(
-                                                    stop.color.b,
-                                                    stop.color.g,
-                                                    stop.color.r,
-                                                    stop.color.a,
-                                                )
-                                                .expect("Create color"),
-                                            )
-                                        })
-                                        .collect();
-
                                 tiny_skia::LinearGradient::new(
                                     tiny_skia::Point {
                                         x: start.x,
                                         y: start.y,
                                     },
                                     tiny_skia::Point { x: end.x, y: end.y },
-                                    if stops.is_empty() {
-                                        vec![tiny_skia::GradientStop::new(
-                                            0.0,
-                                            tiny_skia::Color::BLACK,
-                                        )]
-                                    } else {
-                                        stops
-                                    },
+                                    crate::gradient::stops(&linear.stops),
                                     tiny_skia::SpreadMode::Pad,
                                     tiny_skia::Transform::identity(),
                                 )
                                 .expect("Create linear gradient")
                             }
+                            Background::Gradient(Gradient::Radial(radial)) => {
+                                crate::gradient::radial(
+                                    crate::graphics::gradient::relative_to(
+                                        *bounds,
+                                        radial.center,
+                                    ),
+                                    Vector::new(
+                                        radial.radii.x * bounds.width,
+                                        radial.radii.y * bounds.height,
+                                    ),
+                                    &radial.stops,
+                                )
+                            }
+                            Background::Gradient(Gradient::Conic(_)) => {
+                                match &conic {
+                                    Some(pixmap) => crate::gradient::pattern(
+                                        pixmap,
+                                        *bounds,
+                                        scale_factor,
+                                    ),
+                                    None => return,
+                                }
+                            }
                         },
                         anti_alias: true,
                         ..tiny_skia::Paint::default()
@@ -365,7 +419,7 @@ impl Backend {
             } => {
                 let physical_bounds =
                     (Rectangle::new(*position, paragraph.min_bounds)
-                        + translation)
+                        * transformation)
                         * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
@@ -377,8 +431,35 @@ impl Backend {
 
                 self.text_pipeline.draw_paragraph(
                     paragraph,
-                    *position + translation,
+                    *position,
+                    *color,
+                    transformation,
+                    scale_factor,
+                    pixels,
+                    clip_mask,
+                );
+            }
+            Primitive::Editor {
+                editor,
+                position,
+                color,
+            } => {
+                let physical_bounds =
+                    (Rectangle::new(*position, editor.bounds) * transformation)
+                        * scale_factor;
+
+                if !clip_bounds.intersects(&physical_bounds) {
+                    return;
+                }
+
+                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
+                    .then_some(clip_mask as &_);
+
+                self.text_pipeline.draw_editor(
+                    editor,
+                    *position,
                     *color,
+                    transformation,
                     scale_factor,
                     pixels,
                     clip_mask,
@@ -396,7 +477,7 @@ impl Backend {
                 shaping,
             } => {
                 let physical_bounds =
-                    (primitive.bounds() + translation) * scale_factor;
+                    (primitive.bounds() * transformation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -407,7 +488,7 @@ impl Backend {
 
                 self.text_pipeline.draw_cached(
                     content,
-                    *bounds + translation,
+                    *bounds,
                     *color,
                     *size,
                     *line_height,
@@ -415,6 +496,7 @@ impl Backend {
                     *horizontal_alignment,
                     *vertical_alignment,
                     *shaping,
+                    transformation,
                     scale_factor,
                     pixels,
                     clip_mask,
@@ -422,7 +504,7 @@ impl Backend {
             }
             #[cfg(feature = "image")]
             Primitive::Image { handle, bounds } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds = (*bounds * transformation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -431,11 +513,8 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
-                let transform = tiny_skia::Transform::from_translate(
-                    translation.x,
-                    translation.y,
-                )
-                .post_scale(scale_factor, scale_factor);
+                let transform = into_transform(transformation)
+                    .post_scale(scale_factor, scale_factor);
 
                 self.raster_pipeline
                     .draw(handle, *bounds, pixels, transform, clip_mask);
@@ -452,7 +531,7 @@ impl Backend {
                 bounds,
                 color,
             } => {
-                let physical_bounds = (*bounds + translation) * scale_factor;
+                let physical_bounds = (*bounds * transformation) * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
                     return;
@@ -464,7 +543,7 @@ impl Backend {
                 self.vector_pipeline.draw(
                     handle,
                     *color,
-                    (*bounds + translation) * scale_factor,
+                    physical_bounds,
                     pixels,
                     clip_mask,
                 );
@@ -475,6 +554,30 @@ impl Backend {
                     "Unsupported primitive in `iced_tiny_skia`: {primitive:?}",
                 );
             }
+            Primitive::Blur {
+                bounds,
+                border_radius,
+                radius,
+            } => {
+                let physical_bounds = (*bounds * transformation) * scale_factor;
+
+                if *radius <= 0.0 || !clip_bounds.intersects(&physical_bounds) {
+                    return;
+                }
+
+                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
+                    .then_some(clip_mask as &_);
+
+                let scale = transformation.scale_factor() * scale_factor;
+
+                blur_backdrop(
+                    pixels,
+                    clip_mask,
+                    physical_bounds,
+                    border_radius.map(|radius| radius * scale),
+                    radius * scale,
+                );
+            }
             Primitive::Custom(primitive::Custom::Fill {
                 path,
                 paint,
@@ -488,7 +591,7 @@ impl Backend {
                     y: bounds.y(),
                     width: bounds.width(),
                     height: bounds.height(),
-                } + translation)
+                } * transformation)
                     * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
@@ -498,15 +601,17 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
-                pixels.fill_path(
-                    path,
-                    paint,
-                    *rule,
-                    transform
-                        .post_translate(translation.x, translation.y)
-                        .post_scale(scale_factor, scale_factor),
-                    clip_mask,
-                );
+                with_paint(paint, bounds, scale_factor, |paint| {
+                    pixels.fill_path(
+                        path,
+                        paint,
+                        *rule,
+                        transform
+                            .post_concat(into_transform(transformation))
+                            .post_scale(scale_factor, scale_factor),
+                        clip_mask,
+                    );
+                });
             }
             Primitive::Custom(primitive::Custom::Stroke {
                 path,
@@ -521,7 +626,7 @@ impl Backend {
                     y: bounds.y(),
                     width: bounds.width(),
                     height: bounds.height(),
-                } + translation)
+                } * transformation)
                     * scale_factor;
 
                 if !clip_bounds.intersects(&physical_bounds) {
@@ -531,14 +636,23 @@ impl Backend {
                 let clip_mask = (!physical_bounds.is_within(&clip_bounds))
                     .then_some(clip_mask as &_);
 
-                pixels.stroke_path(
-                    path,
+                with_paint(
                     paint,
-                    stroke,
-                    transform
-                        .post_translate(translation.x, translation.y)
-                        .post_scale(scale_factor, scale_factor),
-                    clip_mask,
+                    bounds
+                        .outset(stroke.width / 2.0, stroke.width / 2.0)
+                        .unwrap_or(bounds),
+                    scale_factor,
+                    |paint| {
+                        pixels.stroke_path(
+                            path,
+                            paint,
+                            stroke,
+                            transform
+                                .post_concat(into_transform(transformation))
+                                .post_scale(scale_factor, scale_factor),
+                            clip_mask,
+                        );
+                    },
                 );
             }
             Primitive::Group { primitives } => {
@@ -549,12 +663,12 @@ impl Backend {
                         clip_mask,
                         clip_bounds,
                         scale_factor,
-                        translation,
+                        transformation,
                     );
                 }
             }
             Primitive::Translate {
-                translation: offset,
+                translation,
                 content,
             } => {
                 self.draw_primitive(
@@ -563,11 +677,174 @@ impl Backend {
                     clip_mask,
                     clip_bounds,
                     scale_factor,
-                    translation + *offset,
+                    transformation
+                        * Transformation::translate(
+                            translation.x,
+                            translation.y,
+                        ),
+                );
+            }
+            Primitive::Transform {
+                transformation: new_transformation,
+                content,
+            } => {
+                let transformation = transformation * *new_transformation;
+
+                if transformation.is_translate_and_scale() {
+                    self.draw_primitive(
+                        content,
+                        pixels,
+                        clip_mask,
+                        clip_bounds,
+                        scale_factor,
+                        transformation,
+                    );
+
+                    return;
+                }
+
+                // Rotations and skews are applied to the content as a whole;
+                // it is drawn untransformed in its own layer first
+                let Some(inverse) = transformation.inverse() else {
+                    return;
+                };
+
+                let Some(region) = ((clip_bounds * (1.0 / scale_factor))
+                    * inverse)
+                    .intersection(&content.bounds())
+                else {
+                    return;
+                };
+
+                // The layer has the resolution the content is displayed at
+                let [a, b, c, d, _, _] = transformation.to_affine();
+                let density = scale_factor * a.hypot(b).max(c.hypot(d));
+
+                let x = (region.x * density).floor();
+                let y = (region.y * density).floor();
+                let width =
+                    ((region.x + region.width) * density - x).ceil() as u32;
+                let height =
+                    ((region.y + region.height) * density - y).ceil() as u32;
+
+                let (Some(mut layer), Some(mut layer_mask)) = (
+                    tiny_skia::Pixmap::new(width, height),
+                    tiny_skia::Mask::new(width, height),
+                ) else {
+                    return;
+                };
+
+                let layer_bounds = Rectangle::new(
+                    Point::ORIGIN,
+                    Size::new(width as f32, height as f32),
+                );
+
+                adjust_clip_mask(&mut layer_mask, layer_bounds);
+
+                self.draw_primitive(
+                    content,
+                    &mut layer.as_mut(),
+                    &mut layer_mask,
+                    layer_bounds,
+                    density,
+                    Transformation::translate(-x / density, -y / density),
+                );
+
+                let physical_bounds = (Rectangle::new(
+                    Point::new(x, y),
+                    Size::new(width as f32, height as f32),
+                ) * (1.0 / density)
+                    * transformation)
+                    * scale_factor;
+
+                let clip_mask = (!physical_bounds.is_within(&clip_bounds))
+                    .then_some(clip_mask as &_);
+
+                pixels.draw_pixmap(
+                    0,
+                    0,
+                    layer.as_ref(),
+                    &tiny_skia::PixmapPaint {
+                        quality: tiny_skia::FilterQuality::Bilinear,
+                        ..tiny_skia::PixmapPaint::default()
+                    },
+                    into_transform(transformation)
+                        .pre_scale(1.0 / density, 1.0 / density)
+                        .pre_translate(x, y)
+                        .post_scale(scale_factor, scale_factor),
+                    clip_mask,
+                );
+            }
+            Primitive::Opacity { opacity, content } => {
+                if *opacity <= 0.0 {
+                    return;
+                }
+
+                if *opacity >= 1.0 {
+                    self.draw_primitive(
+                        content,
+                        pixels,
+                        clip_mask,
+                        clip_bounds,
+                        scale_factor,
+                        transformation,
+                    );
+
+                    return;
+                }
+
+                let physical_bounds =
+                    (content.bounds() * transformation) * scale_factor;
+
+                let Some(bounds) = clip_bounds.intersection(&physical_bounds)
+                else {
+                    return;
+                };
+
+                // Draw the content in its own layer, so it can be composited
+                // as a whole
+                let x = bounds.x.floor();
+                let y = bounds.y.floor();
+                let width = (bounds.x + bounds.width - x).ceil() as u32;
+                let height = (bounds.y + bounds.height - y).ceil() as u32;
+
+                let (Some(mut layer), Some(mut layer_mask)) = (
+                    tiny_skia::Pixmap::new(width, height),
+                    tiny_skia::Mask::new(width, height),
+                ) else {
+                    return;
+                };
+
+                let layer_bounds = bounds - Vector::new(x, y);
+
+                adjust_clip_mask(&mut layer_mask, layer_bounds);
+
+                self.draw_primitive(
+                    content,
+                    &mut layer.as_mut(),
+                    &mut layer_mask,
+                    layer_bounds,
+                    scale_factor,
+                    Transformation::translate(
+                        -x / scale_factor,
+                        -y / scale_factor,
+                    ) * transformation,
+                );
+
+                pixels.draw_pixmap(
+                    x as i32,
+                    y as i32,
+                    layer.as_ref(),
+                    &tiny_skia::PixmapPaint {
+                        opacity: *opacity,
+                        ..tiny_skia::PixmapPaint::default()
+                    },
+                    tiny_skia::Transform::identity(),
+                    None,
                 );
             }
             Primitive::Clip { bounds, content } => {
-                let bounds = (*bounds + translation) * scale_factor;
+                let bounds = (*bounds * transformation) * scale_factor;
 
                 if bounds == clip_bounds {
                     self.draw_primitive(
@@ -576,7 +853,7 @@ impl Backend {
                         clip_mask,
                         bounds,
                         scale_factor,
-                        translation,
+                        transformation,
                     );
                 } else if let Some(bounds) = clip_bounds.intersection(&bounds) {
                     if bounds.x + bounds.width <= 0.0
@@ -597,7 +874,7 @@ impl Backend {
                         clip_mask,
                         bounds,
                         scale_factor,
-                        translation,
+                        transformation,
                     );
 
                     adjust_clip_mask(clip_mask, clip_bounds);
@@ -610,7 +887,7 @@ impl Backend {
                     clip_mask,
                     clip_bounds,
                     scale_factor,
-                    translation,
+                    transformation,
                 );
             }
         }
@@ -623,11 +900,244 @@ impl Default for Backend {
     }
 }
 
+/// Calls the given function with the raw `tiny_skia` paint of a
+/// [`primitive::Paint`], rasterizing it for the given path bounds if needed.
+fn with_paint(
+    paint: &primitive::Paint,
+    bounds: tiny_skia::Rect,
+    scale_factor: f32,
+    draw: impl FnOnce(&tiny_skia::Paint<'_>),
+) {
+    match paint {
+        primitive::Paint::Raw(paint) => draw(paint),
+        primitive::Paint::Conic {
+            gradient,
+            anti_alias,
+        } => {
+            let bounds = Rectangle {
+                x: bounds.x(),
+                y: bounds.y(),
+                width: bounds.width(),
+                height: bounds.height(),
+            };
+
+            let Some(pixmap) = crate::gradient::conic(
+                gradient.center,
+                gradient.angle,
+                &gradient.stops,
+                bounds,
+                scale_factor,
+            ) else {
+                return;
+            };
+
+            draw(&tiny_skia::Paint {
+                shader: crate::gradient::pattern(&pixmap, bounds, scale_factor),
+                anti_alias: *anti_alias,
+                ..tiny_skia::Paint::default()
+            });
+        }
+    }
+}
+
+fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
+    let [a, b, c, d, e, f] = transformation.to_affine();
+
+    tiny_skia::Transform::from_row(a, b, c, d, e, f)
+}
+
 fn into_color(color: Color) -> tiny_skia::Color {
     tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
         .expect("Convert color from iced to tiny_skia")
 }
 
+fn draw_shadow(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+    bounds: Rectangle,
+    shadow: Rectangle,
+    border_radius: [f32; 4],
+    color: Color,
+    blur_radius: f32,
+) {
+    let x = bounds.x.floor();
+    let y = bounds.y.floor();
+    let width = (bounds.x + bounds.width - x).ceil() as u32;
+    let height = (bounds.y + bounds.height - y).ceil() as u32;
+
+    let Some(mut pixmap) = tiny_skia::Pixmap::new(width, height) else {
+        return;
+    };
+
+    let half_size = Vector::new(shadow.width / 2.0, shadow.height / 2.0);
+    let center = Vector::new(shadow.x + half_size.x, shadow.y + half_size.y);
+    let blur = blur_radius.max(0.5);
+
+    let [top_left, top_right, bottom_right, bottom_left] = border_radius;
+
+    for (i, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let px = x + (i as u32 % width) as f32 + 0.5 - center.x;
+        let py = y + (i as u32 / width) as f32 + 0.5 - center.y;
+
+        let radius = match (px > 0.0, py > 0.0) {
+            (false, false) => top_left,
+            (true, false) => top_right,
+            (true, true) => bottom_right,
+            (false, true) => bottom_left,
+        };
+
+        let distance = rounded_box_sdf(px, py, half_size, radius);
+        let alpha = color.a * (1.0 - smoothstep(-blur, blur, distance));
+
+        *pixel = tiny_skia::ColorU8::from_rgba(
+            (color.b * 255.0) as u8,
+            (color.g * 255.0) as u8,
+            (color.r * 255.0) as u8,
+            (alpha * 255.0) as u8,
+        )
+        .premultiply();
+    }
+
+    pixels.draw_pixmap(
+        x as i32,
+        y as i32,
+        pixmap.as_ref(),
+        &tiny_skia::PixmapPaint::default(),
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+}
+
+/// Blurs the pixels inside of the given physical bounds with a Gaussian
+/// blur of the given radius.
+fn blur_backdrop(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: Option<&tiny_skia::Mask>,
+    bounds: Rectangle,
+    border_radius: [f32; 4],
+    radius: f32,
+) {
+    let kernel = gaussian_kernel(radius);
+    let extent = (kernel.len() / 2) as i32;
+
+    let width = pixels.width() as i32;
+    let height = pixels.height() as i32;
+
+    let left = (bounds.x.floor() as i32).max(0);
+    let top = (bounds.y.floor() as i32).max(0);
+    let right = ((bounds.x + bounds.width).ceil() as i32).min(width);
+    let bottom = ((bounds.y + bounds.height).ceil() as i32).min(height);
+
+    if left >= right || top >= bottom {
+        return;
+    }
+
+    let Some(mut blurred) =
+        tiny_skia::Pixmap::new((right - left) as u32, (bottom - top) as u32)
+    else {
+        return;
+    };
+
+    // The vertical pass needs the rows around the bounds blurred horizontally
+    let first_row = (top - extent).max(0);
+    let last_row = (bottom + extent).min(height);
+    let columns = (right - left) as usize;
+
+    let source = pixels.pixels_mut();
+    let mut horizontal =
+        Vec::with_capacity(columns * (last_row - first_row) as usize);
+
+    for y in first_row..last_row {
+        for x in left..right {
+            let mut sum = [0.0; 4];
+
+            for (i, weight) in kernel.iter().enumerate() {
+                let sample_x = (x + i as i32 - extent).clamp(0, width - 1);
+                let pixel = source[(y * width + sample_x) as usize];
+
+                sum[0] += f32::from(pixel.red()) * weight;
+                sum[1] += f32::from(pixel.green()) * weight;
+                sum[2] += f32::from(pixel.blue()) * weight;
+                sum[3] += f32::from(pixel.alpha()) * weight;
+            }
+
+            horizontal.push(sum);
+        }
+    }
+
+    for (i, pixel) in blurred.pixels_mut().iter_mut().enumerate() {
+        let x = i % columns;
+        let y = top + (i / columns) as i32;
+
+        let mut sum = [0.0f32; 4];
+
+        for (j, weight) in kernel.iter().enumerate() {
+            let row = (y + j as i32 - extent).clamp(first_row, last_row - 1);
+            let sample = horizontal[(row - first_row) as usize * columns + x];
+
+            for (channel, value) in sum.iter_mut().zip(sample) {
+                *channel += value * weight;
+            }
+        }
+
+        let alpha = sum[3].round().clamp(0.0, 255.0) as u8;
+        let [r, g, b] =
+            [sum[0], sum[1], sum[2]].map(|c| (c.round() as u8).min(alpha));
+
+        if let Some(color) =
+            tiny_skia::PremultipliedColorU8::from_rgba(r, g, b, alpha)
+        {
+            *pixel = color;
+        }
+    }
+
+    pixels.fill_path(
+        &rounded_rectangle(bounds, border_radius),
+        &tiny_skia::Paint {
+            shader: tiny_skia::Pattern::new(
+                blurred.as_ref(),
+                tiny_skia::SpreadMode::Pad,
+                tiny_skia::FilterQuality::Nearest,
+                1.0,
+                tiny_skia::Transform::from_translate(left as f32, top as f32),
+            ),
+            anti_alias: true,
+            ..tiny_skia::Paint::default()
+        },
+        tiny_skia::FillRule::EvenOdd,
+        tiny_skia::Transform::identity(),
+        clip_mask,
+    );
+}
+
+/// Returns the normalized weights of a Gaussian kernel covering the given
+/// radius, which spans three standard deviations.
+fn gaussian_kernel(radius: f32) -> Vec<f32> {
+    let sigma = (radius / 3.0).max(0.5);
+    let extent = radius.ceil().max(1.0) as i32;
+
+    let weights: Vec<f32> = (-extent..=extent)
+        .map(|i| (-((i * i) as f32) / (2.0 * sigma * sigma)).exp())
+        .collect();
+
+    let total: f32 = weights.iter().sum();
+
+    weights.into_iter().map(|weight| weight / total).collect()
+}
+
+fn rounded_box_sdf(x: f32, y: f32, half_size: Vector, radius: f32) -> f32 {
+    let x = (x.abs() - half_size.x + radius).max(0.0);
+    let y = (y.abs() - half_size.y + radius).max(0.0);
+
+    (x * x + y * y).sqrt() - radius
+}
+
+fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
+    let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
+
+    x * x * (3.0 - 2.0 * x)
+}
+
 fn rounded_rectangle(
     bounds: Rectangle,
     border_radius: [f32; 4],
@@ -803,10 +1313,6 @@ impl iced_graphics::Backend for Backend {
 }
 
 impl backend::Text for Backend {
-    fn font_system(&self) -> &text::FontSystem {
-        self.text_pipeline.font_system()
-    }
-
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         self.text_pipeline.load_font(font);
     }
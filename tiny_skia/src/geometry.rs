@@ -1,4 +1,5 @@
 use crate::core::{Point, Rectangle, Size, Vector};
+use crate::gradient;
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{Path, Style, Text};
@@ -67,10 +68,7 @@ impl Frame {
         self.primitives
             .push(Primitive::Custom(primitive::Custom::Fill {
                 path,
-                paint: tiny_skia::Paint {
-                    anti_alias: false,
-                    ..into_paint(fill.style)
-                },
+                paint: into_paint(fill.style).anti_alias(false),
                 rule: into_fill_rule(fill.rule),
                 transform: self.transform,
             }));
@@ -240,60 +238,42 @@ fn convert_path(path: &Path) -> Option<tiny_skia::Path> {
     result
 }
 
-pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
-    tiny_skia::Paint {
+pub fn into_paint(style: Style) -> primitive::Paint {
+    primitive::Paint::Raw(tiny_skia::Paint {
         shader: match style {
             Style::Solid(color) => tiny_skia::Shader::SolidColor(
                 tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
                     .expect("Create color"),
             ),
             Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(
-                                0.0,
-                                tiny_skia::Color::BLACK,
-                            )]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point {
+                        x: linear.start.x,
+                        y: linear.start.y,
+                    },
+                    tiny_skia::Point {
+                        x: linear.end.x,
+                        y: linear.end.y,
+                    },
+                    gradient::stops(&linear.stops),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )
+                .expect("Create linear gradient"),
+                Gradient::Radial(radial) => {
+                    gradient::radial(radial.center, radial.radii, &radial.stops)
+                }
+                Gradient::Conic(conic) => {
+                    return primitive::Paint::Conic {
+                        gradient: conic,
+                        anti_alias: true,
+                    };
                 }
             },
         },
         anti_alias: true,
         ..Default::default()
-    }
+    })
 }
 
 pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
@@ -0,0 +1,150 @@
+//! Convert gradients into `tiny_skia` shaders.
+use crate::core::gradient::ColorStop;
+use crate::core::{Color, Point, Radians, Rectangle, Vector};
+use crate::graphics::gradient;
+
+/// Converts the given [`ColorStop`]s into `tiny_skia` gradient stops.
+pub fn stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<_> = stops
+        .iter()
+        .flatten()
+        .map(|stop| {
+            tiny_skia::GradientStop::new(stop.offset, into_color(stop.color))
+        })
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+/// Creates a radial gradient shader with the given absolute center and
+/// radii.
+pub fn radial(
+    center: Point,
+    radii: Vector,
+    color_stops: &[Option<ColorStop>; 8],
+) -> tiny_skia::Shader<'static> {
+    // An ellipse is a scaled unit circle
+    tiny_skia::RadialGradient::new(
+        tiny_skia::Point::zero(),
+        tiny_skia::Point::zero(),
+        1.0,
+        stops(color_stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::from_translate(center.x, center.y)
+            .pre_scale(radii.x, radii.y),
+    )
+    .unwrap_or_else(|| {
+        // Degenerate radii only show the last stop
+        tiny_skia::Shader::SolidColor(into_color(
+            color_stops
+                .iter()
+                .flatten()
+                .last()
+                .map_or(Color::BLACK, |stop| stop.color),
+        ))
+    })
+}
+
+/// Rasterizes a conic gradient with the given absolute center and starting
+/// angle, covering the given bounds.
+///
+/// `tiny_skia` does not support conic gradients, so they are drawn as a
+/// pattern instead; see [`pattern`].
+pub fn conic(
+    center: Point,
+    angle: Radians,
+    stops: &[Option<ColorStop>; 8],
+    bounds: Rectangle,
+    scale_factor: f32,
+) -> Option<tiny_skia::Pixmap> {
+    let width = (bounds.width * scale_factor).ceil().max(1.0) as u32;
+    let height = (bounds.height * scale_factor).ceil().max(1.0) as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+    for (index, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let x = (index as u32 % width) as f32 + 0.5;
+        let y = (index as u32 / width) as f32 + 0.5;
+
+        let offset = gradient::conic_offset(
+            center,
+            angle,
+            Point::new(
+                bounds.x + x / scale_factor,
+                bounds.y + y / scale_factor,
+            ),
+        );
+
+        *pixel = into_color(sample(stops, offset))
+            .premultiply()
+            .to_color_u8();
+    }
+
+    Some(pixmap)
+}
+
+/// Creates a shader that draws a [`conic`] gradient rasterized for the given
+/// bounds.
+pub fn pattern(
+    pixmap: &tiny_skia::Pixmap,
+    bounds: Rectangle,
+    scale_factor: f32,
+) -> tiny_skia::Shader<'_> {
+    tiny_skia::Pattern::new(
+        pixmap.as_ref(),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::FilterQuality::Bilinear,
+        1.0,
+        tiny_skia::Transform::from_translate(bounds.x, bounds.y)
+            .pre_scale(1.0 / scale_factor, 1.0 / scale_factor),
+    )
+}
+
+/// Returns the color of the gradient defined by the given [`ColorStop`]s at
+/// the given offset.
+fn sample(stops: &[Option<ColorStop>; 8], offset: f32) -> Color {
+    let mut stops = stops.iter().flatten().peekable();
+
+    let Some(first) = stops.peek().copied() else {
+        return Color::BLACK;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    let mut previous = first;
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let range = stop.offset - previous.offset;
+
+            if range <= 0.0 {
+                return stop.color;
+            }
+
+            let ratio = (offset - previous.offset) / range;
+            let mix = |a: f32, b: f32| a + (b - a) * ratio;
+
+            return Color {
+                r: mix(previous.color.r, stop.color.r),
+                g: mix(previous.color.g, stop.color.g),
+                b: mix(previous.color.b, stop.color.b),
+                a: mix(previous.color.a, stop.color.a),
+            };
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
+fn into_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba(color.b, color.g, color.r, color.a)
+        .expect("Create color")
+}
@@ -4,6 +4,7 @@
 pub mod window;
 
 mod backend;
+mod gradient;
 mod primitive;
 mod settings;
 mod text;
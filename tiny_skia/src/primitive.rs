@@ -1,4 +1,5 @@
 use crate::core::Rectangle;
+use crate::graphics::gradient;
 use crate::graphics::Damage;
 
 pub type Primitive = crate::graphics::Primitive<Custom>;
@@ -10,7 +11,7 @@ pub enum Custom {
         /// The path to fill.
         path: tiny_skia::Path,
         /// The paint to use.
-        paint: tiny_skia::Paint<'static>,
+        paint: Paint,
         /// The fill rule to follow.
         rule: tiny_skia::FillRule,
         /// The transform to apply to the path.
@@ -21,7 +22,7 @@ pub enum Custom {
         /// The path to stroke.
         path: tiny_skia::Path,
         /// The paint to use.
-        paint: tiny_skia::Paint<'static>,
+        paint: Paint,
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
         /// The transform to apply to the path.
@@ -29,6 +30,36 @@ pub enum Custom {
     },
 }
 
+/// The paint of a [`Custom`] primitive.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Paint {
+    /// A paint supported natively by `tiny_skia`.
+    Raw(tiny_skia::Paint<'static>),
+    /// A conic gradient, which is rasterized into a pattern when drawn.
+    Conic {
+        /// The conic gradient.
+        gradient: gradient::Conic,
+        /// Whether the path is anti-aliased.
+        anti_alias: bool,
+    },
+}
+
+impl Paint {
+    /// Sets whether the [`Paint`] is anti-aliased.
+    pub fn anti_alias(self, anti_alias: bool) -> Self {
+        match self {
+            Self::Raw(paint) => Self::Raw(tiny_skia::Paint {
+                anti_alias,
+                ..paint
+            }),
+            Self::Conic { gradient, .. } => Self::Conic {
+                gradient,
+                anti_alias,
+            },
+        }
+    }
+}
+
 impl Damage for Custom {
     fn bounds(&self) -> Rectangle {
         match self {
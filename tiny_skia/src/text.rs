@@ -1,9 +1,10 @@
 use crate::core::alignment;
 use crate::core::text::{LineHeight, Shaping};
-use crate::core::{Color, Font, Pixels, Point, Rectangle};
+use crate::core::{Color, Font, Pixels, Point, Rectangle, Transformation};
 use crate::graphics::text::cache::{self, Cache};
+use crate::graphics::text::editor;
+use crate::graphics::text::font_system;
 use crate::graphics::text::paragraph;
-use crate::graphics::text::FontSystem;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
@@ -12,7 +13,6 @@ use std::collections::hash_map;
 
 #[allow(missing_debug_implementations)]
 pub struct Pipeline {
-    font_system: FontSystem,
     glyph_cache: GlyphCache,
     cache: RefCell<Cache>,
 }
@@ -20,18 +20,16 @@ pub struct Pipeline {
 impl Pipeline {
     pub fn new() -> Self {
         Pipeline {
-            font_system: FontSystem::new(),
             glyph_cache: GlyphCache::new(),
             cache: RefCell::new(Cache::new()),
         }
     }
 
-    pub fn font_system(&self) -> &FontSystem {
-        &self.font_system
-    }
-
     pub fn load_font(&mut self, bytes: Cow<'static, [u8]>) {
-        self.font_system.load_font(bytes);
+        font_system()
+            .write()
+            .expect("Write font system")
+            .load_font(bytes);
 
         self.cache = RefCell::new(Cache::new());
     }
@@ -41,6 +39,7 @@ impl Pipeline {
         paragraph: &paragraph::Weak,
         position: Point,
         color: Color,
+        transformation: Transformation,
         scale_factor: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
@@ -51,14 +50,50 @@ impl Pipeline {
             return;
         };
 
+        let mut font_system = font_system().write().expect("Write font system");
+
         draw(
-            self.font_system.get_mut(),
+            font_system.raw(),
             &mut self.glyph_cache,
             paragraph.buffer(),
             Rectangle::new(position, paragraph.min_bounds()),
             color,
             paragraph.horizontal_alignment(),
             paragraph.vertical_alignment(),
+            transformation,
+            scale_factor,
+            pixels,
+            clip_mask,
+        );
+    }
+
+    pub fn draw_editor(
+        &mut self,
+        editor: &editor::Weak,
+        position: Point,
+        color: Color,
+        transformation: Transformation,
+        scale_factor: f32,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        clip_mask: Option<&tiny_skia::Mask>,
+    ) {
+        use crate::core::text::Editor as _;
+
+        let Some(editor) = editor.upgrade() else {
+            return;
+        };
+
+        let mut font_system = font_system().write().expect("Write font system");
+
+        draw(
+            font_system.raw(),
+            &mut self.glyph_cache,
+            editor.buffer(),
+            Rectangle::new(position, editor.bounds()),
+            color,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+            transformation,
             scale_factor,
             pixels,
             clip_mask,
@@ -76,13 +111,14 @@ impl Pipeline {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
         shaping: Shaping,
+        transformation: Transformation,
         scale_factor: f32,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
     ) {
         let line_height = f32::from(line_height.to_absolute(size));
 
-        let font_system = self.font_system.get_mut();
+        let mut font_system = font_system().write().expect("Write font system");
         let key = cache::Key {
             bounds: bounds.size(),
             content,
@@ -92,13 +128,13 @@ impl Pipeline {
             shaping,
         };
 
-        let (_, entry) = self.cache.get_mut().allocate(font_system, key);
+        let (_, entry) = self.cache.get_mut().allocate(font_system.raw(), key);
 
         let width = entry.min_bounds.width;
         let height = entry.min_bounds.height;
 
         draw(
-            font_system,
+            font_system.raw(),
             &mut self.glyph_cache,
             &entry.buffer,
             Rectangle {
@@ -109,6 +145,7 @@ impl Pipeline {
             color,
             horizontal_alignment,
             vertical_alignment,
+            transformation,
             scale_factor,
             pixels,
             clip_mask,
@@ -129,11 +166,21 @@ fn draw(
     color: Color,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    transformation: Transformation,
     scale_factor: f32,
     pixels: &mut tiny_skia::PixmapMut<'_>,
     clip_mask: Option<&tiny_skia::Mask>,
 ) {
-    let bounds = bounds * scale_factor;
+    // Rotations and skews are applied by the backend to the text as a whole,
+    // so only translations and uniform scales reach this point
+    let scale = transformation.scale_factor();
+
+    let bounds = Rectangle::new(
+        bounds.position() * transformation,
+        bounds.size() * scale,
+    ) * scale_factor;
+
+    let scale_factor = scale_factor * scale;
 
     let x = match horizontal_alignment {
         alignment::Horizontal::Left => bounds.x,
@@ -153,6 +200,18 @@ fn draw(
         for glyph in run.glyphs {
             let physical_glyph = glyph.physical((x, y), scale_factor);
 
+            let color = glyph
+                .color_opt
+                .map(|color| {
+                    Color::from_rgba8(
+                        color.r(),
+                        color.g(),
+                        color.b(),
+                        f32::from(color.a()) / 255.0,
+                    )
+                })
+                .unwrap_or(color);
+
             if let Some((buffer, placement)) = glyph_cache.allocate(
                 physical_glyph.cache_key,
                 color,
@@ -192,6 +192,55 @@ pub fn screenshot<T: AsRef<str>>(
     viewport: &Viewport,
     background_color: Color,
     overlay: &[T],
+) -> Vec<u8> {
+    draw_offscreen(
+        backend,
+        &mut surface.clip_mask,
+        primitives,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+/// Renders the given primitives into an offscreen buffer and returns its
+/// pixels in RGBA format.
+///
+/// Unlike [`screenshot`], no [`Surface`] is needed. Therefore, this can be
+/// used in environments without a display server.
+///
+/// An empty viewport produces no pixels.
+pub fn offscreen<T: AsRef<str>>(
+    backend: &mut Backend,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
+) -> Vec<u8> {
+    let size = viewport.physical_size();
+
+    let Some(mut clip_mask) = tiny_skia::Mask::new(size.width, size.height)
+    else {
+        return Vec::new();
+    };
+
+    draw_offscreen(
+        backend,
+        &mut clip_mask,
+        primitives,
+        viewport,
+        background_color,
+        overlay,
+    )
+}
+
+fn draw_offscreen<T: AsRef<str>>(
+    backend: &mut Backend,
+    clip_mask: &mut tiny_skia::Mask,
+    primitives: &[Primitive],
+    viewport: &Viewport,
+    background_color: Color,
+    overlay: &[T],
 ) -> Vec<u8> {
     let size = viewport.physical_size();
 
@@ -205,7 +254,7 @@ pub fn screenshot<T: AsRef<str>>(
             size.height,
         )
         .expect("Create offscreen pixel map"),
-        &mut surface.clip_mask,
+        clip_mask,
         primitives,
         viewport,
         &[Rectangle::with_size(Size::new(
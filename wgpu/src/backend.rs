@@ -1,8 +1,10 @@
-use crate::core::{Color, Size};
-use crate::graphics;
+use crate::blur;
+use crate::core::{Color, Rectangle, Size};
 use crate::graphics::backend;
 use crate::graphics::color;
 use crate::graphics::{Transformation, Viewport};
+use crate::opacity;
+use crate::primitive::pipeline;
 use crate::primitive::{self, Primitive};
 use crate::quad;
 use crate::text;
@@ -16,6 +18,7 @@ use tracing::info_span;
 use crate::image;
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 /// A [`wgpu`] graphics backend for [`iced`].
 ///
@@ -26,9 +29,14 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    opacity_pipeline: opacity::Pipeline,
+    blur_pipeline: blur::Pipeline,
 
     #[cfg(any(feature = "image", feature = "svg"))]
     image_pipeline: image::Pipeline,
+
+    format: wgpu::TextureFormat,
+    pipeline_storages: HashMap<pipeline::Id, pipeline::Storage>,
 }
 
 impl Backend {
@@ -43,6 +51,8 @@ impl Backend {
         let quad_pipeline = quad::Pipeline::new(device, format);
         let triangle_pipeline =
             triangle::Pipeline::new(device, format, settings.antialiasing);
+        let opacity_pipeline = opacity::Pipeline::new(device, format);
+        let blur_pipeline = blur::Pipeline::new(device, format);
 
         #[cfg(any(feature = "image", feature = "svg"))]
         let image_pipeline = image::Pipeline::new(device, format);
@@ -51,9 +61,14 @@ impl Backend {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            opacity_pipeline,
+            blur_pipeline,
 
             #[cfg(any(feature = "image", feature = "svg"))]
             image_pipeline,
+
+            format,
+            pipeline_storages: HashMap::new(),
         }
     }
 
@@ -112,6 +127,13 @@ impl Backend {
 
         #[cfg(any(feature = "image", feature = "svg"))]
         self.image_pipeline.end_frame();
+
+        // The storage of primitives that are no longer drawn is dropped
+        self.pipeline_storages.retain(|id, _| {
+            layers.iter().any(|layer| {
+                layer.pipelines.iter().any(|pipeline| pipeline.id == *id)
+            })
+        });
     }
 
     fn prepare(
@@ -170,6 +192,18 @@ impl Backend {
                 }
             }
 
+            for pipeline in &layer.pipelines {
+                pipeline.primitive.prepare(
+                    self.format,
+                    device,
+                    queue,
+                    pipeline.bounds,
+                    target_size,
+                    scale_factor,
+                    self.pipeline_storages.entry(pipeline.id).or_default(),
+                );
+            }
+
             if !layer.text.is_empty() {
                 self.text_pipeline.prepare(
                     device,
@@ -193,58 +227,167 @@ impl Backend {
         target_size: Size<u32>,
         layers: &[Layer<'_>],
     ) {
-        use std::mem::ManuallyDrop;
+        if !layers.iter().any(|layer| layer.blur.is_some()) {
+            self.render_layers(
+                device,
+                encoder,
+                target,
+                load_op(clear_color),
+                scale_factor,
+                target_size,
+                layers,
+                0,
+                &mut Offsets::default(),
+            );
+
+            return;
+        }
 
-        let mut quad_layer = 0;
-        let mut triangle_layer = 0;
-        #[cfg(any(feature = "image", feature = "svg"))]
-        let mut image_layer = 0;
-        let mut text_layer = 0;
-
-        let mut render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("iced_wgpu::quad render pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: match clear_color {
-                            Some(background_color) => wgpu::LoadOp::Clear({
-                                let [r, g, b, a] =
-                                    color::pack(background_color).components();
-
-                                wgpu::Color {
-                                    r: f64::from(r),
-                                    g: f64::from(g),
-                                    b: f64::from(b),
-                                    a: f64::from(a),
-                                }
-                            }),
-                            None => wgpu::LoadOp::Load,
-                        },
-                        store: true,
-                    },
-                })],
-                depth_stencil_attachment: None,
+        // Blurs need to sample what has been drawn, so the layers are drawn
+        // offscreen first
+        let frame = self.opacity_pipeline.target(device, 0, target_size);
+
+        self.render_layers(
+            device,
+            encoder,
+            &frame,
+            load_op(Some(Color::TRANSPARENT)),
+            scale_factor,
+            target_size,
+            layers,
+            1,
+            &mut Offsets::default(),
+        );
+
+        self.opacity_pipeline.render(
+            device,
+            encoder,
+            target,
+            load_op(clear_color),
+            &frame,
+            1.0,
+            Transformation::IDENTITY,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: target_size.width,
+                height: target_size.height,
             },
-        ));
+        );
+    }
 
-        for layer in layers {
+    fn render_layers(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        load: wgpu::LoadOp<wgpu::Color>,
+        scale_factor: f32,
+        target_size: Size<u32>,
+        layers: &[Layer<'_>],
+        depth: usize,
+        offsets: &mut Offsets,
+    ) {
+        use std::mem::ManuallyDrop;
+
+        let mut render_pass =
+            ManuallyDrop::new(begin_render_pass(encoder, target, load));
+
+        let mut i = 0;
+
+        while i < layers.len() {
+            let layer = &layers[i];
             let bounds = (layer.bounds * scale_factor).snap();
 
+            i += 1;
+
+            if let Some(group) = layer.group {
+                let group_layers = &layers[i..i + group.layers];
+
+                i += group.layers;
+
+                if bounds.width < 1 || bounds.height < 1 {
+                    offsets.skip(group_layers, scale_factor);
+                    continue;
+                }
+
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                let offscreen =
+                    self.opacity_pipeline.target(device, depth, target_size);
+
+                self.render_layers(
+                    device,
+                    encoder,
+                    &offscreen,
+                    load_op(Some(Color::TRANSPARENT)),
+                    scale_factor,
+                    target_size,
+                    group_layers,
+                    depth + 1,
+                    offsets,
+                );
+
+                self.opacity_pipeline.render(
+                    device,
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                    &offscreen,
+                    group.opacity,
+                    Transformation::scale(scale_factor, scale_factor)
+                        * group.transformation
+                        * Transformation::scale(
+                            1.0 / scale_factor,
+                            1.0 / scale_factor,
+                        ),
+                    bounds,
+                );
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                ));
+
+                continue;
+            }
+
             if bounds.width < 1 || bounds.height < 1 {
                 continue;
             }
 
+            if let Some(blur) = &layer.blur {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.blur_pipeline.render(
+                    device,
+                    encoder,
+                    target,
+                    target_size,
+                    blur,
+                    bounds,
+                    scale_factor,
+                );
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
+                ));
+
+                continue;
+            }
+
             if !layer.quads.is_empty() {
                 self.quad_pipeline.render(
-                    quad_layer,
+                    offsets.quad,
                     bounds,
                     &layer.quads,
                     &mut render_pass,
                 );
 
-                quad_layer += 1;
+                offsets.quad += 1;
             }
 
             if !layer.meshes.is_empty() {
@@ -254,29 +397,18 @@ impl Backend {
                     device,
                     encoder,
                     target,
-                    triangle_layer,
+                    offsets.triangle,
                     target_size,
                     &layer.meshes,
                     scale_factor,
                 );
 
-                triangle_layer += 1;
-
-                render_pass = ManuallyDrop::new(encoder.begin_render_pass(
-                    &wgpu::RenderPassDescriptor {
-                        label: Some("iced_wgpu::quad render pass"),
-                        color_attachments: &[Some(
-                            wgpu::RenderPassColorAttachment {
-                                view: target,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Load,
-                                    store: true,
-                                },
-                            },
-                        )],
-                        depth_stencil_attachment: None,
-                    },
+                offsets.triangle += 1;
+
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    target,
+                    wgpu::LoadOp::Load,
                 ));
             }
 
@@ -284,20 +416,64 @@ impl Backend {
             {
                 if !layer.images.is_empty() {
                     self.image_pipeline.render(
-                        image_layer,
+                        offsets.image,
                         bounds,
                         &mut render_pass,
                     );
 
-                    image_layer += 1;
+                    offsets.image += 1;
                 }
             }
 
+            if !layer.pipelines.is_empty() {
+                for pipeline in &layer.pipelines {
+                    let clip_bounds =
+                        (pipeline.clip_bounds * scale_factor).snap();
+
+                    if clip_bounds.width < 1 || clip_bounds.height < 1 {
+                        continue;
+                    }
+
+                    let Some(storage) =
+                        self.pipeline_storages.get(&pipeline.id)
+                    else {
+                        continue;
+                    };
+
+                    render_pass.set_scissor_rect(
+                        clip_bounds.x,
+                        clip_bounds.y,
+                        clip_bounds.width,
+                        clip_bounds.height,
+                    );
+
+                    pipeline.primitive.render(
+                        storage,
+                        pipeline.bounds * scale_factor,
+                        clip_bounds,
+                        &mut render_pass,
+                    );
+                }
+
+                // Custom pipelines may change the viewport of the render pass
+                render_pass.set_viewport(
+                    0.0,
+                    0.0,
+                    target_size.width as f32,
+                    target_size.height as f32,
+                    0.0,
+                    1.0,
+                );
+            }
+
             if !layer.text.is_empty() {
-                self.text_pipeline
-                    .render(text_layer, bounds, &mut render_pass);
+                self.text_pipeline.render(
+                    offsets.text,
+                    bounds,
+                    &mut render_pass,
+                );
 
-                text_layer += 1;
+                offsets.text += 1;
             }
         }
 
@@ -305,15 +481,74 @@ impl Backend {
     }
 }
 
+/// The amount of prepared layers already rendered by each pipeline.
+#[derive(Debug, Default)]
+struct Offsets {
+    quad: usize,
+    triangle: usize,
+    #[cfg(any(feature = "image", feature = "svg"))]
+    image: usize,
+    text: usize,
+}
+
+impl Offsets {
+    /// Skips the prepared layers of the given layers, without rendering them.
+    fn skip(&mut self, layers: &[Layer<'_>], scale_factor: f32) {
+        for layer in layers {
+            let bounds = (layer.bounds * scale_factor).snap();
+
+            if bounds.width < 1 || bounds.height < 1 {
+                continue;
+            }
+
+            self.quad += usize::from(!layer.quads.is_empty());
+            self.triangle += usize::from(!layer.meshes.is_empty());
+            #[cfg(any(feature = "image", feature = "svg"))]
+            {
+                self.image += usize::from(!layer.images.is_empty());
+            }
+            self.text += usize::from(!layer.text.is_empty());
+        }
+    }
+}
+
+fn load_op(clear_color: Option<Color>) -> wgpu::LoadOp<wgpu::Color> {
+    match clear_color {
+        Some(background_color) => wgpu::LoadOp::Clear({
+            let [r, g, b, a] = color::pack(background_color).components();
+
+            wgpu::Color {
+                r: f64::from(r),
+                g: f64::from(g),
+                b: f64::from(b),
+                a: f64::from(a),
+            }
+        }),
+        None => wgpu::LoadOp::Load,
+    }
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    target: &'a wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::quad render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            resolve_target: None,
+            ops: wgpu::Operations { load, store: true },
+        })],
+        depth_stencil_attachment: None,
+    })
+}
+
 impl crate::graphics::Backend for Backend {
     type Primitive = primitive::Custom;
 }
 
 impl backend::Text for Backend {
-    fn font_system(&self) -> &graphics::text::FontSystem {
-        self.text_pipeline.font_system()
-    }
-
     fn load_font(&mut self, font: Cow<'static, [u8]>) {
         self.text_pipeline.load_font(font);
     }
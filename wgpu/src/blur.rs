@@ -0,0 +1,289 @@
+//! Blur whatever has already been drawn inside some bounds.
+use crate::core::{Rectangle, Size};
+use crate::layer;
+
+use bytemuck::{Pod, Zeroable};
+use wgpu::util::DeviceExt;
+
+use std::borrow::Cow;
+use std::mem;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    blur: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    format: wgpu::TextureFormat,
+    intermediate: Option<wgpu::Texture>,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::blur layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>()
+                                    as wgpu::BufferAddress,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: false,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::blur pipeline layout"),
+                bind_group_layouts: &[&layout],
+                push_constant_ranges: &[],
+            });
+
+        let shader =
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::blur shader"),
+                source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(include_str!(
+                    "shader/blur.wgsl"
+                ))),
+            });
+
+        let pipeline = |label, entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..wgpu::PrimitiveState::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+
+        let blur = pipeline(
+            "iced_wgpu::blur horizontal pipeline",
+            "fs_blur",
+            Some(wgpu::BlendState::REPLACE),
+        );
+
+        let composite = pipeline(
+            "iced_wgpu::blur composite pipeline",
+            "fs_composite",
+            Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+        );
+
+        Self {
+            blur,
+            composite,
+            layout,
+            format,
+            intermediate: None,
+        }
+    }
+
+    /// Blurs the contents of the target inside of the bounds of the given
+    /// [`layer::Blur`], clipped by the given physical bounds.
+    ///
+    /// The target must be a texture that can be bound for sampling.
+    pub fn render(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        target_size: Size<u32>,
+        blur: &layer::Blur,
+        clip_bounds: Rectangle<u32>,
+        scale_factor: f32,
+    ) {
+        let bounds = blur.bounds * scale_factor;
+        let radius = blur.radius * scale_factor;
+
+        let Some(region) = Rectangle::<f32>::from(clip_bounds)
+            .intersection(&bounds)
+            .map(Rectangle::snap)
+        else {
+            return;
+        };
+
+        if region.width < 1 || region.height < 1 {
+            return;
+        }
+
+        // The vertical pass samples the rows around the region
+        let extent = radius.max(1.0).ceil() as u32;
+        let top = region.y.saturating_sub(extent);
+        let bottom =
+            (region.y + region.height + extent).min(target_size.height);
+
+        let horizontal_region = Rectangle {
+            y: top,
+            height: bottom - top,
+            ..region
+        };
+
+        let intermediate = self.intermediate(device, target_size);
+
+        let uniforms = |direction| Uniforms {
+            bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+            border_radius: blur
+                .border_radius
+                .map(|radius| radius * scale_factor),
+            direction,
+            radius,
+            _padding: 0.0,
+        };
+
+        self.pass(
+            device,
+            encoder,
+            &self.blur,
+            uniforms([1.0, 0.0]),
+            target,
+            &intermediate,
+            horizontal_region,
+        );
+
+        self.pass(
+            device,
+            encoder,
+            &self.composite,
+            uniforms([0.0, 1.0]),
+            &intermediate,
+            target,
+            region,
+        );
+    }
+
+    fn intermediate(
+        &mut self,
+        device: &wgpu::Device,
+        size: Size<u32>,
+    ) -> wgpu::TextureView {
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = match &mut self.intermediate {
+            Some(texture) if texture.size() == extent => texture,
+            intermediate => intermediate.insert(device.create_texture(
+                &wgpu::TextureDescriptor {
+                    label: Some("iced_wgpu::blur intermediate texture"),
+                    size: extent,
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: self.format,
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING,
+                    view_formats: &[],
+                },
+            )),
+        };
+
+        texture.create_view(&wgpu::TextureViewDescriptor::default())
+    }
+
+    fn pass(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        pipeline: &wgpu::RenderPipeline,
+        uniforms: Uniforms,
+        source: &wgpu::TextureView,
+        target: &wgpu::TextureView,
+        bounds: Rectangle<u32>,
+    ) {
+        let uniforms =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::blur uniforms buffer"),
+                contents: bytemuck::bytes_of(&uniforms),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::blur bind group"),
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniforms.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+            ],
+        });
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::blur render pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+struct Uniforms {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    direction: [f32; 2],
+    radius: f32,
+    _padding: f32,
+}
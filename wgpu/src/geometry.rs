@@ -1,5 +1,5 @@
 //! Build and draw geometry.
-use crate::core::{Point, Rectangle, Size, Vector};
+use crate::core::{Point, Radians, Rectangle, Size, Vector};
 use crate::graphics::color;
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::{
@@ -12,6 +12,7 @@ use crate::primitive::{self, Primitive};
 use lyon::geom::euclid;
 use lyon::tessellation;
 use std::borrow::Cow;
+use std::f32::consts::FRAC_PI_2;
 
 /// A frame for drawing some geometry.
 #[allow(missing_debug_implementations)]
@@ -145,6 +146,27 @@ impl Transform {
                 self.transform_point(&mut linear.start);
                 self.transform_point(&mut linear.end);
             }
+            Gradient::Radial(radial) => {
+                self.transform_point(&mut radial.center);
+
+                let radii = self.raw.transform_vector(euclid::Vector2D::new(
+                    radial.radii.x,
+                    radial.radii.y,
+                ));
+
+                radial.radii = Vector::new(radii.x.abs(), radii.y.abs());
+            }
+            Gradient::Conic(conic) => {
+                self.transform_point(&mut conic.center);
+
+                let angle = conic.angle.0 - FRAC_PI_2;
+                let direction = self.raw.transform_vector(
+                    euclid::Vector2D::new(angle.cos(), angle.sin()),
+                );
+
+                conic.angle =
+                    Radians(direction.y.atan2(direction.x) + FRAC_PI_2);
+            }
         }
 
         gradient
@@ -3,16 +3,21 @@ mod image;
 mod text;
 
 pub mod mesh;
+pub mod pipeline;
 
 pub use image::Image;
 pub use mesh::Mesh;
+pub use pipeline::Pipeline;
 pub use text::Text;
 
 use crate::core;
 use crate::core::alignment;
-use crate::core::{Color, Font, Pixels, Point, Rectangle, Size, Vector};
+use crate::core::{
+    Color, Font, Pixels, Point, Rectangle, Size, Transformation, Vector,
+};
 use crate::graphics;
 use crate::graphics::color;
+use crate::graphics::Damage;
 use crate::graphics::Viewport;
 use crate::primitive::{self, Primitive};
 use crate::quad::{self, Quad};
@@ -34,6 +39,49 @@ pub struct Layer<'a> {
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
+
+    /// The custom pipeline primitives of the [`Layer`].
+    pub pipelines: Vec<Pipeline>,
+
+    /// The [`Group`] started by the [`Layer`], if any.
+    pub group: Option<Group>,
+
+    /// The [`Blur`] applied by the [`Layer`] to the layers before it, if any.
+    pub blur: Option<Blur>,
+}
+
+/// A group of layers that are drawn offscreen and then composited together
+/// with some opacity and [`Transformation`].
+///
+/// The [`Layer`] starting a [`Group`] is always empty; its contents are
+/// the layers that follow it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Group {
+    /// The opacity of the [`Group`].
+    pub opacity: f32,
+
+    /// The [`Transformation`] from the coordinates of the layers of the
+    /// [`Group`] to the coordinates of the [`Layer`] starting it.
+    pub transformation: Transformation,
+
+    /// The amount of layers following the starting [`Layer`] that belong
+    /// to the [`Group`].
+    pub layers: usize,
+}
+
+/// A blur applied to whatever has been drawn before, inside some bounds.
+///
+/// A [`Layer`] applying a [`Blur`] is always empty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Blur {
+    /// The bounds of the [`Blur`].
+    pub bounds: Rectangle,
+
+    /// The border radii of the [`Blur`].
+    pub border_radius: [f32; 4],
+
+    /// The radius of the [`Blur`].
+    pub radius: f32,
 }
 
 impl<'a> Layer<'a> {
@@ -45,6 +93,9 @@ impl<'a> Layer<'a> {
             meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            pipelines: Vec::new(),
+            group: None,
+            blur: None,
         }
     }
 
@@ -93,25 +144,28 @@ impl<'a> Layer<'a> {
             Layer::new(Rectangle::with_size(viewport.logical_size()));
 
         let mut layers = vec![first_layer];
+        let mut current_layer = 0;
 
         for primitive in primitives {
-            Self::process_primitive(
+            current_layer = Self::process_primitive(
                 &mut layers,
-                Vector::new(0.0, 0.0),
+                Transformation::IDENTITY,
                 primitive,
-                0,
+                current_layer,
             );
         }
 
         layers
     }
 
+    /// Distributes the given [`Primitive`] in the given layers and returns
+    /// the index of the [`Layer`] where the primitives following it belong.
     fn process_primitive(
         layers: &mut Vec<Self>,
-        translation: Vector,
+        transformation: Transformation,
         primitive: &'a Primitive,
         current_layer: usize,
-    ) {
+    ) -> usize {
         match primitive {
             Primitive::Paragraph {
                 paragraph,
@@ -122,8 +176,23 @@ impl<'a> Layer<'a> {
 
                 layer.text.push(Text::Managed {
                     paragraph: paragraph.clone(),
-                    position: *position + translation,
+                    position: *position * transformation,
+                    color: *color,
+                    scale: transformation.scale_factor(),
+                });
+            }
+            Primitive::Editor {
+                editor,
+                position,
+                color,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                layer.text.push(Text::Editor {
+                    editor: editor.clone(),
+                    position: *position * transformation,
                     color: *color,
+                    scale: transformation.scale_factor(),
                 });
             }
             Primitive::Text {
@@ -138,12 +207,16 @@ impl<'a> Layer<'a> {
                 shaping,
             } => {
                 let layer = &mut layers[current_layer];
+                let scale = transformation.scale_factor();
 
                 layer.text.push(Text::Cached(text::Cached {
                     content,
-                    bounds: *bounds + translation,
-                    size: *size,
-                    line_height: *line_height,
+                    bounds: Rectangle::new(
+                        bounds.position() * transformation,
+                        bounds.size() * scale,
+                    ),
+                    size: Pixels(size.0 * scale),
+                    line_height: scale_line_height(*line_height, scale),
                     color: *color,
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
@@ -157,18 +230,21 @@ impl<'a> Layer<'a> {
                 border_radius,
                 border_width,
                 border_color,
+                shadow,
             } => {
                 let layer = &mut layers[current_layer];
+                let position = bounds.position() * transformation;
+                let scale = transformation.scale_factor();
 
                 let quad = Quad {
-                    position: [
-                        bounds.x + translation.x,
-                        bounds.y + translation.y,
-                    ],
-                    size: [bounds.width, bounds.height],
+                    position: [position.x, position.y],
+                    size: [bounds.width * scale, bounds.height * scale],
                     border_color: color::pack(*border_color),
-                    border_radius: *border_radius,
-                    border_width: *border_width,
+                    border_radius: border_radius.map(|radius| radius * scale),
+                    border_width: *border_width * scale,
+                    shadow_color: color::pack(shadow.color),
+                    shadow_offset: (shadow.offset * scale).into(),
+                    shadow_blur_radius: shadow.blur_radius * scale,
                 };
 
                 layer.quads.add(quad, background);
@@ -178,7 +254,7 @@ impl<'a> Layer<'a> {
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
-                    bounds: *bounds + translation,
+                    bounds: *bounds * transformation,
                 });
             }
             Primitive::Svg {
@@ -191,34 +267,66 @@ impl<'a> Layer<'a> {
                 layer.images.push(Image::Vector {
                     handle: handle.clone(),
                     color: *color,
-                    bounds: *bounds + translation,
+                    bounds: *bounds * transformation,
+                });
+            }
+            Primitive::Blur {
+                bounds,
+                border_radius,
+                radius,
+            } => {
+                let layer_bounds = layers[current_layer].bounds;
+                let bounds = *bounds * transformation;
+
+                if *radius <= 0.0 || !layer_bounds.intersects(&bounds) {
+                    return current_layer;
+                }
+
+                let scale = transformation.scale_factor();
+
+                // Everything drawn after the blur needs to be on top of it
+                layers.push(Layer {
+                    blur: Some(Blur {
+                        bounds,
+                        border_radius: border_radius
+                            .map(|radius| radius * scale),
+                        radius: radius * scale,
+                    }),
+                    ..Layer::new(layer_bounds)
                 });
+                layers.push(Layer::new(layer_bounds));
+
+                return layers.len() - 1;
             }
             Primitive::Group { primitives } => {
                 // TODO: Inspect a bit and regroup (?)
+                let mut current_layer = current_layer;
+
                 for primitive in primitives {
-                    Self::process_primitive(
+                    current_layer = Self::process_primitive(
                         layers,
-                        translation,
+                        transformation,
                         primitive,
                         current_layer,
                     );
                 }
+
+                return current_layer;
             }
             Primitive::Clip { bounds, content } => {
                 let layer = &mut layers[current_layer];
-                let translated_bounds = *bounds + translation;
+                let transformed_bounds = *bounds * transformation;
 
                 // Only draw visible content
                 if let Some(clip_bounds) =
-                    layer.bounds.intersection(&translated_bounds)
+                    layer.bounds.intersection(&transformed_bounds)
                 {
                     let clip_layer = Layer::new(clip_bounds);
                     layers.push(clip_layer);
 
-                    Self::process_primitive(
+                    let _ = Self::process_primitive(
                         layers,
-                        translation,
+                        transformation,
                         content,
                         layers.len() - 1,
                     );
@@ -228,17 +336,123 @@ impl<'a> Layer<'a> {
                 translation: new_translation,
                 content,
             } => {
-                Self::process_primitive(
+                return Self::process_primitive(
                     layers,
-                    translation + *new_translation,
+                    transformation
+                        * Transformation::translate(
+                            new_translation.x,
+                            new_translation.y,
+                        ),
                     content,
                     current_layer,
                 );
             }
+            Primitive::Transform {
+                transformation: new_transformation,
+                content,
+            } => {
+                let transformation = transformation * *new_transformation;
+
+                if transformation.is_translate_and_scale() {
+                    return Self::process_primitive(
+                        layers,
+                        transformation,
+                        content,
+                        current_layer,
+                    );
+                }
+
+                // Rotations and skews are applied to the content as a whole;
+                // it is drawn untransformed in a group of layers first
+                let Some(inverse) = transformation.inverse() else {
+                    return current_layer;
+                };
+
+                let bounds = layers[current_layer].bounds;
+
+                let Some(region) =
+                    (bounds * inverse).intersection(&content.bounds())
+                else {
+                    return current_layer;
+                };
+
+                // The group has the resolution the content is displayed at,
+                // as long as it fits in the viewport
+                let viewport = layers[0].bounds.size();
+                let [a, b, c, d, _, _] = transformation.to_affine();
+                let scale = a
+                    .hypot(b)
+                    .max(c.hypot(d))
+                    .min(viewport.width / region.width)
+                    .min(viewport.height / region.height);
+
+                let group_bounds = Rectangle::with_size(region.size() * scale);
+                let composition = transformation
+                    * Transformation::translate(region.x, region.y)
+                    * Transformation::scale(1.0 / scale, 1.0 / scale);
+
+                let Some(bounds) =
+                    bounds.intersection(&(group_bounds * composition))
+                else {
+                    return current_layer;
+                };
+
+                let start = layers.len();
+
+                layers.push(Layer::new(bounds));
+                layers.push(Layer::new(group_bounds));
+
+                let _ = Self::process_primitive(
+                    layers,
+                    Transformation::scale(scale, scale)
+                        * Transformation::translate(-region.x, -region.y),
+                    content,
+                    start + 1,
+                );
+
+                layers[start].group = Some(Group {
+                    opacity: 1.0,
+                    transformation: composition,
+                    layers: layers.len() - start - 1,
+                });
+            }
+            Primitive::Opacity { opacity, content } => {
+                if *opacity <= 0.0 {
+                    return current_layer;
+                }
+
+                if *opacity >= 1.0 {
+                    return Self::process_primitive(
+                        layers,
+                        transformation,
+                        content,
+                        current_layer,
+                    );
+                }
+
+                let bounds = layers[current_layer].bounds;
+                let start = layers.len();
+
+                layers.push(Layer::new(bounds));
+                layers.push(Layer::new(bounds));
+
+                let _ = Self::process_primitive(
+                    layers,
+                    transformation,
+                    content,
+                    start + 1,
+                );
+
+                layers[start].group = Some(Group {
+                    opacity: *opacity,
+                    transformation: Transformation::IDENTITY,
+                    layers: layers.len() - start - 1,
+                });
+            }
             Primitive::Cache { content } => {
-                Self::process_primitive(
+                return Self::process_primitive(
                     layers,
-                    translation,
+                    transformation,
                     content,
                     current_layer,
                 );
@@ -248,20 +462,15 @@ impl<'a> Layer<'a> {
                     graphics::Mesh::Solid { buffers, size } => {
                         let layer = &mut layers[current_layer];
 
-                        let bounds = Rectangle::new(
-                            Point::new(translation.x, translation.y),
-                            *size,
-                        );
+                        let bounds =
+                            Rectangle::with_size(*size) * transformation;
 
                         // Only draw visible content
                         if let Some(clip_bounds) =
                             layer.bounds.intersection(&bounds)
                         {
                             layer.meshes.push(Mesh::Solid {
-                                origin: Point::new(
-                                    translation.x,
-                                    translation.y,
-                                ),
+                                transformation,
                                 buffers,
                                 clip_bounds,
                             });
@@ -270,27 +479,55 @@ impl<'a> Layer<'a> {
                     graphics::Mesh::Gradient { buffers, size } => {
                         let layer = &mut layers[current_layer];
 
-                        let bounds = Rectangle::new(
-                            Point::new(translation.x, translation.y),
-                            *size,
-                        );
+                        let bounds =
+                            Rectangle::with_size(*size) * transformation;
 
                         // Only draw visible content
                         if let Some(clip_bounds) =
                             layer.bounds.intersection(&bounds)
                         {
                             layer.meshes.push(Mesh::Gradient {
-                                origin: Point::new(
-                                    translation.x,
-                                    translation.y,
-                                ),
+                                transformation,
                                 buffers,
                                 clip_bounds,
                             });
                         }
                     }
                 },
+                primitive::Custom::Pipeline(pipeline) => {
+                    let layer = &mut layers[current_layer];
+
+                    let bounds = pipeline.bounds * transformation;
+
+                    // Only draw visible content
+                    if let Some(clip_bounds) =
+                        layer.bounds.intersection(&bounds)
+                    {
+                        layer.pipelines.push(Pipeline {
+                            id: pipeline.id,
+                            bounds,
+                            clip_bounds,
+                            primitive: pipeline.primitive.clone(),
+                        });
+                    }
+                }
             },
         }
+
+        current_layer
+    }
+}
+
+fn scale_line_height(
+    line_height: core::text::LineHeight,
+    scale: f32,
+) -> core::text::LineHeight {
+    match line_height {
+        core::text::LineHeight::Relative(factor) => {
+            core::text::LineHeight::Relative(factor)
+        }
+        core::text::LineHeight::Absolute(height) => {
+            core::text::LineHeight::Absolute(Pixels(height.0 * scale))
+        }
     }
 }
@@ -0,0 +1,21 @@
+//! A collection of custom pipeline primitives.
+use crate::core::Rectangle;
+use crate::primitive::pipeline::{Id, Primitive};
+
+use std::sync::Arc;
+
+/// A primitive drawn by a custom pipeline.
+#[derive(Clone, Debug)]
+pub struct Pipeline {
+    /// The [`Id`] of the storage of the [`Pipeline`].
+    pub id: Id,
+
+    /// The bounds of the [`Pipeline`].
+    pub bounds: Rectangle,
+
+    /// The clipping bounds of the [`Pipeline`].
+    pub clip_bounds: Rectangle,
+
+    /// The [`Primitive`] to draw.
+    pub primitive: Arc<dyn Primitive>,
+}
@@ -1,6 +1,7 @@
 use crate::core::alignment;
 use crate::core::text;
 use crate::core::{Color, Font, Pixels, Point, Rectangle};
+use crate::graphics::text::editor;
 use crate::graphics::text::paragraph;
 
 /// A paragraph of text.
@@ -10,6 +11,13 @@ pub enum Text<'a> {
         paragraph: paragraph::Weak,
         position: Point,
         color: Color,
+        scale: f32,
+    },
+    Editor {
+        editor: editor::Weak,
+        position: Point,
+        color: Color,
+        scale: f32,
     },
     Cached(Cached<'a>),
 }
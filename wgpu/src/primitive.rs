@@ -1,4 +1,8 @@
 //! Draw using different graphical primitives.
+pub mod pipeline;
+
+pub use pipeline::Pipeline;
+
 use crate::core::Rectangle;
 use crate::graphics::{Damage, Mesh};
 
@@ -10,12 +14,16 @@ pub type Primitive = crate::graphics::Primitive<Custom>;
 pub enum Custom {
     /// A mesh primitive.
     Mesh(Mesh),
+
+    /// A primitive drawn by a custom pipeline.
+    Pipeline(Pipeline),
 }
 
 impl Damage for Custom {
     fn bounds(&self) -> Rectangle {
         match self {
             Self::Mesh(mesh) => mesh.bounds(),
+            Self::Pipeline(pipeline) => pipeline.bounds,
         }
     }
 }
@@ -0,0 +1,164 @@
+//! Draw primitives using custom pipelines.
+use crate::core::{self, Rectangle, Size};
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Arc;
+
+/// A primitive drawn by a custom pipeline, along with its bounds.
+#[derive(Clone, Debug)]
+pub struct Pipeline {
+    /// The [`Id`] of the [`Storage`] of the [`Pipeline`].
+    pub id: Id,
+
+    /// The bounds of the [`Pipeline`].
+    pub bounds: Rectangle,
+
+    /// The [`Primitive`] drawn by the [`Pipeline`].
+    pub primitive: Arc<dyn Primitive>,
+}
+
+impl Pipeline {
+    /// Creates a new [`Pipeline`] that draws the given [`Primitive`] inside
+    /// the given bounds, using the [`Storage`] with the given [`Id`].
+    pub fn new(id: Id, bounds: Rectangle, primitive: impl Primitive) -> Self {
+        Self {
+            id,
+            bounds,
+            primitive: Arc::new(primitive),
+        }
+    }
+}
+
+impl PartialEq for Pipeline {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.bounds == other.bounds
+            && Arc::ptr_eq(&self.primitive, &other.primitive)
+    }
+}
+
+/// A set of methods which allows a [`Primitive`] to be rendered.
+pub trait Primitive: Debug + Send + Sync + 'static {
+    /// Processes the [`Primitive`], allowing for GPU buffer allocation.
+    ///
+    /// The given [`Storage`] belongs to the [`Id`] the [`Primitive`] was
+    /// drawn with, and it persists across frames as long as a [`Primitive`]
+    /// keeps being drawn with that [`Id`]. It is the place to keep pipelines,
+    /// buffers and any other GPU resources that should outlive a frame.
+    fn prepare(
+        &self,
+        format: wgpu::TextureFormat,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        bounds: Rectangle,
+        target_size: Size<u32>,
+        scale_factor: f32,
+        storage: &mut Storage,
+    );
+
+    /// Records the draw calls of the [`Primitive`] in the given render pass.
+    ///
+    /// The `bounds` are the physical bounds of the [`Primitive`], while the
+    /// `clip_bounds` are the physical bounds of its visible part; the scissor
+    /// rectangle of the render pass is set to them beforehand.
+    fn render<'a>(
+        &'a self,
+        storage: &'a Storage,
+        bounds: Rectangle,
+        clip_bounds: Rectangle<u32>,
+        render_pass: &mut wgpu::RenderPass<'a>,
+    );
+}
+
+/// A renderer that can draw custom pipeline primitives.
+pub trait Renderer: core::Renderer {
+    /// Draws the given [`Primitive`] inside the given bounds, using the
+    /// [`Storage`] with the given [`Id`].
+    fn draw_pipeline_primitive(
+        &mut self,
+        id: Id,
+        bounds: Rectangle,
+        primitive: impl Primitive,
+    );
+}
+
+/// The identifier of the [`Storage`] of some custom pipeline primitives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+/// Persistent storage for the GPU resources of custom pipelines, keyed by
+/// type.
+#[derive(Default, Debug)]
+pub struct Storage {
+    resources: HashMap<TypeId, Box<dyn Any + Send>>,
+}
+
+impl Storage {
+    /// Returns `true` if a value of type `T` is stored.
+    pub fn has<T: 'static>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Stores the given value, replacing any previous value of the same type.
+    pub fn store<T: 'static + Send>(&mut self, data: T) {
+        let _ = self.resources.insert(TypeId::of::<T>(), Box::new(data));
+    }
+
+    /// Returns a reference to the stored value of type `T`, if any.
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.resources
+            .get(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_ref::<T>())
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if any.
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.resources
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_mut::<T>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_keeps_a_value_per_type() {
+        let mut storage = Storage::default();
+
+        assert!(!storage.has::<u32>());
+
+        storage.store(1_u32);
+        storage.store("buffer");
+
+        assert_eq!(storage.get::<u32>(), Some(&1));
+        assert_eq!(storage.get::<&str>(), Some(&"buffer"));
+        assert_eq!(storage.get::<f32>(), None);
+
+        *storage.get_mut::<u32>().expect("Get stored value") += 1;
+        storage.store("other buffer");
+
+        assert_eq!(storage.get::<u32>(), Some(&2));
+        assert_eq!(storage.get::<&str>(), Some(&"other buffer"));
+    }
+
+    #[test]
+    fn unique_ids_are_different() {
+        assert_ne!(Id::unique(), Id::unique());
+    }
+}
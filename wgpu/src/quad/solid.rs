@@ -105,6 +105,12 @@ impl Pipeline {
                                 5 => Float32x4,
                                 // Border width
                                 6 => Float32,
+                                // Shadow color
+                                7 => Float32x4,
+                                // Shadow offset
+                                8 => Float32x2,
+                                // Shadow blur radius
+                                9 => Float32,
                             ),
                         },
                     ],
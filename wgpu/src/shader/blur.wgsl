@@ -0,0 +1,92 @@
+struct Uniforms {
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+    direction: vec2<f32>,
+    radius: f32,
+    _padding: f32,
+}
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var u_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+// Blurs the texture along the direction of the uniforms, with a Gaussian
+// kernel whose radius spans three standard deviations.
+fn blur(frag_coord: vec2<f32>) -> vec4<f32> {
+    let size = vec2<i32>(textureDimensions(u_texture));
+    let sigma = max(uniforms.radius / 3.0, 0.5);
+    let extent = i32(ceil(max(uniforms.radius, 1.0)));
+
+    var sum = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total = 0.0;
+
+    for (var i: i32 = -extent; i <= extent; i = i + 1) {
+        let offset = vec2<i32>(uniforms.direction * f32(i));
+        let position = clamp(
+            vec2<i32>(frag_coord) + offset,
+            vec2<i32>(0, 0),
+            size - vec2<i32>(1, 1)
+        );
+        let weight = exp(-f32(i * i) / (2.0 * sigma * sigma));
+
+        sum = sum + textureLoad(u_texture, position, 0) * weight;
+        total = total + weight;
+    }
+
+    return sum / total;
+}
+
+// Order matches CSS border radius attribute:
+// radi.x = top-left, radi.y = top-right, radi.z = bottom-right, radi.w = bottom-left
+fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>) -> f32 {
+    var rx = select(radi.x, radi.y, position.x > center.x);
+    var ry = select(radi.w, radi.z, position.x > center.x);
+    return select(rx, ry, position.y > center.y);
+}
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+@fragment
+fn fs_blur(input: VertexOutput) -> @location(0) vec4<f32> {
+    return blur(input.position.xy);
+}
+
+@fragment
+fn fs_composite(input: VertexOutput) -> @location(0) vec4<f32> {
+    let position = uniforms.bounds.xy;
+    let size = uniforms.bounds.zw;
+    let center = position + size * 0.5;
+
+    let radius = select_border_radius(uniforms.border_radius, input.position.xy, center);
+    let distance = rounded_box_sdf(input.position.xy - center, size * 0.5, radius);
+    let coverage = 1.0 - smoothstep(-0.5, 0.5, distance);
+
+    // The blurred backdrop is premultiplied, so every channel is masked
+    return blur(input.position.xy) * coverage;
+}
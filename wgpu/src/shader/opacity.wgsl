@@ -0,0 +1,65 @@
+struct Uniforms {
+    inverse_transformation: mat4x4<f32>,
+    opacity: f32,
+    _padding_0: f32,
+    _padding_1: f32,
+    _padding_2: f32,
+}
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(-1.0, 1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(1.0, -1.0)
+);
+
+@group(0) @binding(0) var<uniform> uniforms: Uniforms;
+@group(0) @binding(1) var u_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @builtin(vertex_index) vertex_index: u32,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+}
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.position = vec4<f32>(positions[input.vertex_index], 0.0, 1.0);
+
+    return out;
+}
+
+// Loads a texel of the layer, which is transparent outside of its bounds
+fn texel(position: vec2<i32>) -> vec4<f32> {
+    let size = vec2<i32>(textureDimensions(u_texture));
+
+    if (any(position < vec2<i32>(0, 0)) || any(position >= size)) {
+        return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    }
+
+    return textureLoad(u_texture, position, 0);
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // The layer is sampled bilinearly where the fragment was transformed from;
+    // untransformed fragments land exactly on the center of a texel
+    let position = (uniforms.inverse_transformation * vec4<f32>(input.position.xy, 0.0, 1.0)).xy - vec2<f32>(0.5, 0.5);
+    let origin = floor(position);
+    let t = position - origin;
+    let texel_0 = vec2<i32>(origin);
+
+    let color = mix(
+        mix(texel(texel_0), texel(texel_0 + vec2<i32>(1, 0)), t.x),
+        mix(texel(texel_0 + vec2<i32>(0, 1)), texel(texel_0 + vec2<i32>(1, 1)), t.x),
+        t.y
+    );
+
+    // The layer is premultiplied, so every channel is faded
+    return color * uniforms.opacity;
+}
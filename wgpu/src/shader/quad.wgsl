@@ -37,3 +37,40 @@ fn select_border_radius(radi: vec4<f32>, position: vec2<f32>, center: vec2<f32>)
     rx = select(rx, ry, position.y > center.y);
     return rx;
 }
+
+fn rounded_box_sdf(to_center: vec2<f32>, size: vec2<f32>, radius: f32) -> f32 {
+    return length(max(abs(to_center) - size + vec2<f32>(radius, radius), vec2<f32>(0.0, 0.0))) - radius;
+}
+
+// Composites the color of a quad over its drop shadow, which is the rounded box of the quad
+// displaced by `shadow_offset` and softened by `shadow_blur_radius`.
+fn with_shadow(
+    quad_color: vec4<f32>,
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radi: vec4<f32>,
+    shadow_color: vec4<f32>,
+    shadow_offset: vec2<f32>,
+    shadow_blur_radius: f32
+) -> vec4<f32> {
+    if (shadow_color.a <= 0.0) {
+        return quad_color;
+    }
+
+    let shadow_center = position + shadow_offset + size * 0.5;
+    let shadow_radius = select_border_radius(radi, frag_coord, shadow_center);
+    let shadow_distance = rounded_box_sdf(frag_coord - shadow_center, size * 0.5, shadow_radius);
+    let blur = max(shadow_blur_radius, 0.5);
+    let shadow_alpha = shadow_color.a * (1.0 - smoothstep(-blur, blur, shadow_distance));
+
+    let alpha = quad_color.a + shadow_alpha * (1.0 - quad_color.a);
+
+    if (alpha <= 0.0) {
+        return vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    }
+
+    let color = (quad_color.rgb * quad_color.a + shadow_color.rgb * shadow_alpha * (1.0 - quad_color.a)) / alpha;
+
+    return vec4<f32>(color, alpha);
+}
@@ -6,10 +6,14 @@ struct GradientVertexInput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(7) @interpolate(flat) kind: u32,
+    @location(8) position_and_scale: vec4<f32>,
+    @location(9) border_color: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
+    @location(11) border_width: f32,
+    @location(12) shadow_color: vec4<f32>,
+    @location(13) shadow_offset: vec2<f32>,
+    @location(14) shadow_blur_radius: f32,
 }
 
 struct GradientVertexOutput {
@@ -20,10 +24,14 @@ struct GradientVertexOutput {
     @location(4) @interpolate(flat) colors_4: vec4<u32>,
     @location(5) @interpolate(flat) offsets: vec4<u32>,
     @location(6) direction: vec4<f32>,
-    @location(7) position_and_scale: vec4<f32>,
-    @location(8) border_color: vec4<f32>,
-    @location(9) border_radius: vec4<f32>,
-    @location(10) border_width: f32,
+    @location(7) @interpolate(flat) kind: u32,
+    @location(8) position_and_scale: vec4<f32>,
+    @location(9) border_color: vec4<f32>,
+    @location(10) border_radius: vec4<f32>,
+    @location(11) border_width: f32,
+    @location(12) shadow_color: vec4<f32>,
+    @location(13) shadow_offset: vec2<f32>,
+    @location(14) shadow_blur_radius: f32,
 }
 
 @vertex
@@ -41,11 +49,19 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    var shadow_offset: vec2<f32> = input.shadow_offset * globals.scale;
+    var shadow_blur_radius: f32 = input.shadow_blur_radius * globals.scale;
+
+    // Grow the quad to cover its shadow, if any
+    var shadow_extent: f32 = select(0.0, 1.0, input.shadow_color.a > 0.0);
+    var bounds_pos: vec2<f32> = pos + (min(shadow_offset, vec2<f32>(0.0, 0.0)) - shadow_blur_radius) * shadow_extent;
+    var bounds_scale: vec2<f32> = scale + (abs(shadow_offset) + shadow_blur_radius * 2.0) * shadow_extent;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(bounds_scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, bounds_scale.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(bounds_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
@@ -54,11 +70,20 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_3 = input.colors_3;
     out.colors_4 = input.colors_4;
     out.offsets = input.offsets;
-    out.direction = input.direction * globals.scale;
+    // The angle of conic gradients must not be scaled
+    out.direction = select(
+        input.direction * globals.scale,
+        vec4<f32>(input.direction.xy * globals.scale, input.direction.zw),
+        input.kind == 2u
+    );
+    out.kind = input.kind;
     out.position_and_scale = vec4<f32>(pos, scale);
     out.border_color = input.border_color;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = shadow_offset;
+    out.shadow_blur_radius = shadow_blur_radius;
 
     return out;
 }
@@ -71,17 +96,12 @@ fn random(coords: vec2<f32>) -> f32 {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    kind: u32,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
-
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    let coord_offset = gradient_offset(raw_position, direction, kind);
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -152,7 +172,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.kind, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
@@ -194,7 +214,16 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         border_radius + 0.5,
         dist);
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    return with_shadow(
+        vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha),
+        input.position.xy,
+        pos,
+        scale,
+        input.border_radius,
+        input.shadow_color,
+        input.shadow_offset,
+        input.shadow_blur_radius
+    );
 }
 
 fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
@@ -203,3 +232,30 @@ fn unpack_u32(color: vec2<u32>) -> vec4<f32> {
 
     return vec4<f32>(rg.y, rg.x, ba.y, ba.x);
 }
+
+/// Returns the offset of a position along a linear, radial or conic gradient
+fn gradient_offset(raw_position: vec2<f32>, direction: vec4<f32>, kind: u32) -> f32 {
+    switch kind {
+        // Radial: center & radii
+        case 1u: {
+            return length((raw_position - direction.xy) / max(direction.zw, vec2<f32>(0.0001, 0.0001)));
+        }
+        // Conic: center & starting angle
+        case 2u: {
+            let v = raw_position - direction.xy;
+
+            return fract((atan2(v.y, v.x) - direction.z) / 6.28318530718);
+        }
+        // Linear: start & end
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
+
+            let v1 = end - start;
+            let v2 = raw_position - start;
+            let unit = normalize(v1);
+
+            return dot(unit, v2) / length(v1);
+        }
+    }
+}
@@ -6,6 +6,9 @@ struct SolidVertexInput {
     @location(4) border_color: vec4<f32>,
     @location(5) border_radius: vec4<f32>,
     @location(6) border_width: f32,
+    @location(7) shadow_color: vec4<f32>,
+    @location(8) shadow_offset: vec2<f32>,
+    @location(9) shadow_blur_radius: f32,
 }
 
 struct SolidVertexOutput {
@@ -16,6 +19,9 @@ struct SolidVertexOutput {
     @location(3) scale: vec2<f32>,
     @location(4) border_radius: vec4<f32>,
     @location(5) border_width: f32,
+    @location(6) shadow_color: vec4<f32>,
+    @location(7) shadow_offset: vec2<f32>,
+    @location(8) shadow_blur_radius: f32,
 }
 
 @vertex
@@ -33,11 +39,19 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
         min(input.border_radius.w, min_border_radius)
     );
 
+    var shadow_offset: vec2<f32> = input.shadow_offset * globals.scale;
+    var shadow_blur_radius: f32 = input.shadow_blur_radius * globals.scale;
+
+    // Grow the quad to cover its shadow, if any
+    var shadow_extent: f32 = select(0.0, 1.0, input.shadow_color.a > 0.0);
+    var bounds_pos: vec2<f32> = pos + (min(shadow_offset, vec2<f32>(0.0, 0.0)) - shadow_blur_radius) * shadow_extent;
+    var bounds_scale: vec2<f32> = scale + (abs(shadow_offset) + shadow_blur_radius * 2.0) * shadow_extent;
+
     var transform: mat4x4<f32> = mat4x4<f32>(
-        vec4<f32>(scale.x + 1.0, 0.0, 0.0, 0.0),
-        vec4<f32>(0.0, scale.y + 1.0, 0.0, 0.0),
+        vec4<f32>(bounds_scale.x + 1.0, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, bounds_scale.y + 1.0, 0.0, 0.0),
         vec4<f32>(0.0, 0.0, 1.0, 0.0),
-        vec4<f32>(pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
+        vec4<f32>(bounds_pos - vec2<f32>(0.5, 0.5), 0.0, 1.0)
     );
 
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
@@ -47,6 +61,9 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = shadow_offset;
+    out.shadow_blur_radius = shadow_blur_radius;
 
     return out;
 }
@@ -95,5 +112,14 @@ fn solid_fs_main(
         dist
     );
 
-    return vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha);
+    return with_shadow(
+        vec4<f32>(mixed_color.x, mixed_color.y, mixed_color.z, mixed_color.w * radius_alpha),
+        input.position.xy,
+        input.pos,
+        input.scale,
+        input.border_radius,
+        input.shadow_color,
+        input.shadow_offset,
+        input.shadow_blur_radius
+    );
 }
@@ -30,6 +30,55 @@ impl<Theme> Compositor<Theme> {
         settings: Settings,
         compatible_window: Option<&W>,
     ) -> Option<Self> {
+        let instance = Self::instance(settings);
+
+        #[allow(unsafe_code)]
+        let compatible_surface = compatible_window
+            .and_then(|window| unsafe { instance.create_surface(window).ok() });
+
+        let adapter =
+            Self::adapter(&instance, settings, compatible_surface.as_ref())
+                .await?;
+
+        let format = compatible_surface.as_ref().and_then(|surface| {
+            let capabilities = surface.get_capabilities(&adapter);
+
+            let mut formats = capabilities.formats.iter().copied();
+
+            let format = if color::GAMMA_CORRECTION {
+                formats.find(wgpu::TextureFormat::is_srgb)
+            } else {
+                formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
+            };
+
+            format.or_else(|| {
+                log::warn!("No format found!");
+
+                capabilities.formats.first().copied()
+            })
+        })?;
+
+        Self::with_adapter(instance, adapter, settings, format).await
+    }
+
+    /// Requests a new [`Compositor`] with the given [`Settings`] that can
+    /// only render offscreen, without any window.
+    ///
+    /// Returns `None` if no graphics adapter could be found.
+    pub async fn request_headless(settings: Settings) -> Option<Self> {
+        let instance = Self::instance(settings);
+        let adapter = Self::adapter(&instance, settings, None).await?;
+
+        let format = if color::GAMMA_CORRECTION {
+            wgpu::TextureFormat::Rgba8UnormSrgb
+        } else {
+            wgpu::TextureFormat::Rgba8Unorm
+        };
+
+        Self::with_adapter(instance, adapter, settings, format).await
+    }
+
+    fn instance(settings: Settings) -> wgpu::Instance {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: settings.internal_backend,
             ..Default::default()
@@ -46,10 +95,14 @@ impl<Theme> Compositor<Theme> {
             log::info!("Available adapters: {available_adapters:#?}");
         }
 
-        #[allow(unsafe_code)]
-        let compatible_surface = compatible_window
-            .and_then(|window| unsafe { instance.create_surface(window).ok() });
+        instance
+    }
 
+    async fn adapter(
+        instance: &wgpu::Instance,
+        settings: Settings,
+        compatible_surface: Option<&wgpu::Surface>,
+    ) -> Option<wgpu::Adapter> {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::util::power_preference_from_env()
@@ -58,31 +111,22 @@ impl<Theme> Compositor<Theme> {
                     } else {
                         wgpu::PowerPreference::HighPerformance
                     }),
-                compatible_surface: compatible_surface.as_ref(),
+                compatible_surface,
                 force_fallback_adapter: false,
             })
             .await?;
 
         log::info!("Selected: {:#?}", adapter.get_info());
 
-        let format = compatible_surface.as_ref().and_then(|surface| {
-            let capabilities = surface.get_capabilities(&adapter);
-
-            let mut formats = capabilities.formats.iter().copied();
-
-            let format = if color::GAMMA_CORRECTION {
-                formats.find(wgpu::TextureFormat::is_srgb)
-            } else {
-                formats.find(|format| !wgpu::TextureFormat::is_srgb(format))
-            };
-
-            format.or_else(|| {
-                log::warn!("No format found!");
-
-                capabilities.formats.first().copied()
-            })
-        })?;
+        Some(adapter)
+    }
 
+    async fn with_adapter(
+        instance: wgpu::Instance,
+        adapter: wgpu::Adapter,
+        settings: Settings,
+        format: wgpu::TextureFormat,
+    ) -> Option<Self> {
         log::info!("Selected format: {format:?}");
 
         #[cfg(target_arch = "wasm32")]
@@ -151,6 +195,20 @@ pub fn new<Theme, W: HasRawWindowHandle + HasRawDisplayHandle>(
     Ok((compositor, backend))
 }
 
+/// Creates a [`Compositor`] and its [`Backend`] for the given [`Settings`]
+/// that can only render offscreen, with [`screenshot`].
+pub fn headless<Theme>(
+    settings: Settings,
+) -> Result<(Compositor<Theme>, Backend), Error> {
+    let compositor =
+        futures::executor::block_on(Compositor::request_headless(settings))
+            .ok_or(Error::GraphicsAdapterNotFound)?;
+
+    let backend = compositor.create_backend();
+
+    Ok((compositor, backend))
+}
+
 /// Presents the given primitives with the given [`Compositor`] and [`Backend`].
 pub fn present<Theme, T: AsRef<str>>(
     compositor: &mut Compositor<Theme>,
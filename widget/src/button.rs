@@ -1,14 +1,18 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
+use crate::core::accessibility;
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::Operation;
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Padding, Point,
     Rectangle, Shell, Vector, Widget,
@@ -179,6 +183,19 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
+        let mut node = accessibility::Node::new(accessibility::Role::Button)
+            .disabled(self.on_press.is_none());
+
+        if self.on_press.is_some() {
+            node = node.action(accessibility::Action::Click);
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(id) = operation.accessible(None, layout.bounds(), &node) {
+            state.accessibility = Some(id);
+        }
+
         operation.container(None, layout.bounds(), &mut |operation| {
             self.content.as_widget().operate(
                 &mut tree.children[0],
@@ -292,9 +309,13 @@ where
 }
 
 /// The local state of a [`Button`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct State {
     is_pressed: bool,
+    is_hovered: bool,
+    hover: Animation<f32>,
+    now: Instant,
+    accessibility: Option<accessibility::Id>,
 }
 
 impl State {
@@ -304,6 +325,18 @@ impl State {
     }
 }
 
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            is_pressed: false,
+            is_hovered: false,
+            hover: Animation::new(0.0),
+            now: Instant::now(),
+            accessibility: None,
+        }
+    }
+}
+
 /// Processes the given [`Event`] and updates the [`State`] of a [`Button`]
 /// accordingly.
 pub fn update<'a, Message: Clone>(
@@ -352,6 +385,45 @@ pub fn update<'a, Message: Clone>(
 
             state.is_pressed = false;
         }
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Mouse(mouse::Event::CursorLeft)
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            let state = state();
+            let is_hovered =
+                on_press.is_some() && cursor.is_over(layout.bounds());
+
+            if is_hovered != state.is_hovered {
+                state.is_hovered = is_hovered;
+
+                // Draw samples the animation at `now`, so both must agree
+                state.now = Instant::now();
+                state
+                    .hover
+                    .go(if is_hovered { 1.0 } else { 0.0 }, state.now);
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            let state = state();
+
+            state.now = now;
+
+            if state.hover.is_animating(now) {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Accessibility(accessibility::Event::Click(target)) => {
+            if state().accessibility != Some(target) {
+                return event::Status::Ignored;
+            }
+
+            if let Some(on_press) = on_press.clone() {
+                shell.publish(on_press);
+
+                return event::Status::Captured;
+            }
+        }
         _ => {}
     }
 
@@ -377,19 +449,34 @@ where
 
     let styling = if !is_enabled {
         style_sheet.disabled(style)
-    } else if is_mouse_over {
+    } else {
         let state = state();
 
-        if state.is_pressed {
-            style_sheet.pressed(style)
+        let hover = if state.hover.is_animating(state.now) {
+            state.hover.value(state.now)
+        } else if is_mouse_over {
+            1.0
         } else {
+            0.0
+        };
+
+        if is_mouse_over && state.is_pressed {
+            style_sheet.pressed(style)
+        } else if hover <= 0.0 {
+            style_sheet.active(style)
+        } else if hover >= 1.0 {
             style_sheet.hovered(style)
+        } else {
+            style_sheet
+                .active(style)
+                .interpolate(&style_sheet.hovered(style), hover)
         }
-    } else {
-        style_sheet.active(style)
     };
 
-    if styling.background.is_some() || styling.border_width > 0.0 {
+    if styling.background.is_some()
+        || styling.border_width > 0.0
+        || styling.shadow.color.a > 0.0
+    {
         if styling.shadow_offset != Vector::default() {
             // TODO: Implement proper shadow support
             renderer.fill_quad(
@@ -402,6 +489,7 @@ where
                     border_radius: styling.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 Background::Color([0.0, 0.0, 0.0, 0.5].into()),
             );
@@ -413,6 +501,7 @@ where
                 border_radius: styling.border_radius,
                 border_width: styling.border_width,
                 border_color: styling.border_color,
+                shadow: styling.shadow,
             },
             styling
                 .background
@@ -384,6 +384,12 @@ where
             viewport,
         );
 
+        if let Some(redraw_request) = local_shell.redraw_request() {
+            shell.request_redraw(redraw_request);
+        }
+
+        shell.request_input_method(local_shell.input_method());
+
         // Then finally react to them here
         for message in local_messages {
             let TextInputEvent::TextChanged(new_value) = message;
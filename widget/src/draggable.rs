@@ -0,0 +1,556 @@
+//! Pick up some content and drag it around, carrying a payload.
+//!
+//! A [`Draggable`] is the source of a drag; a [`Droppable`] is its target.
+//! The application keeps track of the payload being dragged between the
+//! [`Draggable::on_drag`] and [`Draggable::on_release`] messages, and hands it
+//! to the drop targets with [`Droppable::dragging`].
+//!
+//! [`Droppable`]: crate::Droppable
+//! [`Droppable::dragging`]: crate::Droppable::dragging
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Size, Vector,
+    Widget,
+};
+
+/// The distance the cursor needs to travel while pressed before a drag
+/// starts.
+const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
+
+/// Some content that can be dragged around, carrying a payload.
+///
+/// While dragging, a preview of the content follows the cursor.
+#[allow(missing_debug_implementations)]
+pub struct Draggable<'a, Payload, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    payload: Payload,
+    on_drag: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    on_release: Option<Message>,
+}
+
+impl<'a, Payload, Message, Renderer> Draggable<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+{
+    /// Creates a new [`Draggable`] with the given payload and content.
+    pub fn new(
+        payload: Payload,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            payload,
+            on_drag: None,
+            on_release: None,
+        }
+    }
+
+    /// Sets the message that should be produced with the payload when a drag
+    /// starts.
+    pub fn on_drag(
+        mut self,
+        on_drag: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        self.on_drag = Some(Box::new(on_drag));
+        self
+    }
+
+    /// Sets the message that should be produced when a drag ends, whether
+    /// the content was dropped on a target or not.
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    pressed_at: Option<Point>,
+    drag: Option<Vector>,
+}
+
+impl<'a, Payload, Message, Renderer> Widget<Message, Renderer>
+    for Draggable<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let status = if state.drag.is_none() {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event.clone(),
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            )
+        } else {
+            // The content does not get any events while it is being dragged,
+            // but it must still let go of the press that started the drag
+            // without acting on it
+            if is_release(&event) {
+                let _ = self.content.as_widget_mut().on_event(
+                    &mut tree.children[0],
+                    event.clone(),
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                );
+            }
+
+            event::Status::Ignored
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                // Pressing some interactive content may still start a drag
+                state.pressed_at = cursor.position_over(bounds);
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let (Some(pressed_at), Some(position)) =
+                    (state.pressed_at, cursor.position())
+                else {
+                    return status;
+                };
+
+                if state.drag.is_none()
+                    && position.distance(pressed_at) > DRAG_DEADBAND_DISTANCE
+                {
+                    state.drag = Some(pressed_at - bounds.position());
+
+                    if let Some(on_drag) = &self.on_drag {
+                        shell.publish(on_drag(self.payload.clone()));
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                state.pressed_at = None;
+
+                if state.drag.take().is_some() {
+                    if let Some(message) = &self.on_release {
+                        shell.publish(message.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+
+        if state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::default()
+            && cursor.is_over(layout.bounds())
+        {
+            mouse::Interaction::Grab
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let Some(grab) = state.downcast_ref::<State>().drag else {
+            return self.content.as_widget_mut().overlay(
+                &mut children[0],
+                layout,
+                renderer,
+            );
+        };
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Preview {
+                content: &self.content,
+                tree: &mut children[0],
+                size: layout.bounds().size(),
+                grab,
+            }),
+        ))
+    }
+}
+
+impl<'a, Payload, Message, Renderer>
+    From<Draggable<'a, Payload, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Payload: Clone + 'a,
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(
+        draggable: Draggable<'a, Payload, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(draggable)
+    }
+}
+
+fn is_release(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+    )
+}
+
+/// The content of a [`Draggable`] following the cursor.
+struct Preview<'a, 'b, Message, Renderer> {
+    content: &'b Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    size: Size,
+    grab: Vector,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Preview<'a, 'b, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        _bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let mut node = self.content.as_widget().layout(
+            self.tree,
+            renderer,
+            &layout::Limits::new(self.size, self.size),
+        );
+
+        node.move_to(position);
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let Some(position) = cursor.position() else {
+            return;
+        };
+
+        // The preview is positioned at draw time, so it follows the cursor
+        // without laying out the interface again
+        let translation = position - self.grab - layout.position();
+        let bounds = layout.bounds() + translation;
+
+        renderer.with_layer(bounds, |renderer| {
+            renderer.with_translation(translation, |renderer| {
+                self.content.as_widget().draw(
+                    self.tree,
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    mouse::Cursor::Unavailable,
+                    &layout.bounds(),
+                );
+            });
+        });
+    }
+
+    fn is_over(
+        &self,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        _cursor_position: Point,
+    ) -> bool {
+        // The drop targets under the preview must keep getting the cursor
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_renderer::{renderer, Renderer};
+    use crate::{Button, Droppable, Space};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Pressed,
+        Dragged(usize),
+        Released,
+        Dropped(usize),
+    }
+
+    /// Sends the events to the widget with the cursor at the given
+    /// positions, returning the published messages.
+    fn simulate(
+        widget: impl Into<Element<'static, Message, Renderer>>,
+        events: &[(Event, Point)],
+    ) -> Vec<Message> {
+        let renderer = renderer();
+        let mut element = widget.into();
+        let mut tree = Tree::new(&element);
+
+        let node = element.as_widget().layout(
+            &mut tree,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        for (event, position) in events {
+            let _ = element.as_widget_mut().on_event(
+                &mut tree,
+                event.clone(),
+                Layout::new(&node),
+                mouse::Cursor::Available(*position),
+                &renderer,
+                &mut crate::core::clipboard::Null,
+                &mut shell,
+                &node.bounds(),
+            );
+        }
+
+        messages
+    }
+
+    fn press(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    fn move_to(x: f32, y: f32) -> (Event, Point) {
+        let position = Point::new(x, y);
+
+        (
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            position,
+        )
+    }
+
+    fn release(x: f32, y: f32) -> (Event, Point) {
+        (
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            Point::new(x, y),
+        )
+    }
+
+    fn card() -> Draggable<'static, usize, Message, Renderer> {
+        Draggable::new(
+            7,
+            Button::new(Space::new(100, 40)).on_press(Message::Pressed),
+        )
+        .on_drag(Message::Dragged)
+        .on_release(Message::Released)
+    }
+
+    #[test]
+    fn drag_starts_once_the_cursor_leaves_the_deadband() {
+        let messages =
+            simulate(card(), &[press(10.0, 10.0), move_to(15.0, 15.0)]);
+
+        assert_eq!(messages, vec![]);
+
+        let messages = simulate(
+            card(),
+            &[press(10.0, 10.0), move_to(15.0, 15.0), move_to(30.0, 10.0)],
+        );
+
+        assert_eq!(messages, vec![Message::Dragged(7)]);
+    }
+
+    #[test]
+    fn releasing_before_dragging_cancels_the_drag() {
+        let messages = simulate(
+            card(),
+            &[
+                press(10.0, 10.0),
+                move_to(15.0, 10.0),
+                release(15.0, 10.0),
+                move_to(60.0, 10.0),
+            ],
+        );
+
+        assert_eq!(messages, vec![Message::Pressed]);
+    }
+
+    #[test]
+    fn dragged_content_is_released_without_being_pressed() {
+        let messages = simulate(
+            card(),
+            &[
+                press(10.0, 10.0),
+                move_to(40.0, 10.0),
+                release(40.0, 10.0),
+                // The button must not be left pressed
+                release(40.0, 10.0),
+            ],
+        );
+
+        assert_eq!(messages, vec![Message::Dragged(7), Message::Released]);
+    }
+
+    #[test]
+    fn payload_is_dropped_on_the_hovered_target() {
+        let target = |dragging| {
+            Droppable::new(Space::new(100, 100))
+                .dragging(dragging)
+                .on_drop(Message::Dropped)
+        };
+
+        let messages = simulate(
+            target(Some(7)),
+            &[move_to(50.0, 50.0), release(50.0, 50.0)],
+        );
+
+        assert_eq!(messages, vec![Message::Dropped(7)]);
+
+        let messages = simulate(
+            target(Some(7)),
+            &[move_to(150.0, 50.0), release(150.0, 50.0)],
+        );
+
+        assert_eq!(messages, vec![]);
+
+        let messages =
+            simulate(target(None), &[move_to(50.0, 50.0), release(50.0, 50.0)]);
+
+        assert_eq!(messages, vec![]);
+    }
+}
@@ -0,0 +1,327 @@
+//! Receive the payload of a [`Draggable`] dropped on some content.
+//!
+//! [`Draggable`]: crate::Draggable
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Layout, Length, Rectangle, Shell,
+    Widget,
+};
+
+pub use crate::style::droppable::{Appearance, StyleSheet};
+
+/// Some content where the payload of a [`Draggable`] can be dropped.
+///
+/// A [`Droppable`] only reacts while it knows the payload being dragged,
+/// which the application provides with [`Droppable::dragging`].
+///
+/// # Example
+/// ```no_run
+/// # type Draggable<'a, Payload, Message> =
+/// #     iced_widget::Draggable<'a, Payload, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Droppable<'a, Payload, Message> =
+/// #     iced_widget::Droppable<'a, Payload, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// # type Column<'a, Message> =
+/// #     iced_widget::Column<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::text;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Picked(usize),
+///     Released,
+///     Moved { card: usize, column: usize },
+/// }
+///
+/// struct Board {
+///     columns: Vec<Vec<usize>>,
+///     dragging: Option<usize>,
+/// }
+///
+/// impl Board {
+///     fn column(&self, column: usize) -> Droppable<'_, usize, Message> {
+///         let cards = self.columns[column].iter().map(|&card| {
+///             let card: Draggable<'_, usize, Message> =
+///                 Draggable::new(card, text(card))
+///                     .on_drag(Message::Picked)
+///                     .on_release(Message::Released);
+///
+///             card.into()
+///         });
+///
+///         Droppable::new(Column::with_children(cards.collect()))
+///             .dragging(self.dragging)
+///             .on_drop(move |card| Message::Moved { card, column })
+///     }
+/// }
+/// ```
+///
+/// [`Draggable`]: crate::Draggable
+#[allow(missing_debug_implementations)]
+pub struct Droppable<'a, Payload, Message, Renderer = crate::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    dragging: Option<Payload>,
+    on_drop: Option<Box<dyn Fn(Payload) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Payload, Message, Renderer> Droppable<'a, Payload, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Droppable`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            dragging: None,
+            on_drop: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the payload being dragged, if any.
+    pub fn dragging(mut self, payload: Option<Payload>) -> Self {
+        self.dragging = payload;
+        self
+    }
+
+    /// Sets the message that should be produced with the payload when it is
+    /// dropped on the [`Droppable`].
+    pub fn on_drop(
+        mut self,
+        on_drop: impl Fn(Payload) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(on_drop));
+        self
+    }
+
+    /// Sets the style of the [`Droppable`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_hovered: bool,
+}
+
+impl<'a, Payload, Message, Renderer> Widget<Message, Renderer>
+    for Droppable<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        // Nested drop targets capture the drop before their parents
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let state = tree.state.downcast_mut::<State>();
+
+        state.is_hovered =
+            self.dragging.is_some() && cursor.is_over(layout.bounds());
+
+        if let event::Status::Captured = status {
+            return event::Status::Captured;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if !state.is_hovered {
+                    return event::Status::Ignored;
+                }
+
+                state.is_hovered = false;
+
+                if let (Some(payload), Some(on_drop)) =
+                    (self.dragging.clone(), &self.on_drop)
+                {
+                    shell.publish(on_drop(payload));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        renderer_style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if self.dragging.is_some() {
+            let appearance = if state.is_hovered {
+                theme.hovered(&self.style)
+            } else {
+                theme.active(&self.style)
+            };
+
+            if appearance.background.is_some() || appearance.border_width > 0.0
+            {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: layout.bounds(),
+                        border_radius: appearance.border_radius,
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                        ..renderer::Quad::default()
+                    },
+                    appearance
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            renderer_style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Payload, Message, Renderer>
+    From<Droppable<'a, Payload, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Payload: Clone + 'a,
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(
+        droppable: Droppable<'a, Payload, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(droppable)
+    }
+}
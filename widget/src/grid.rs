@@ -0,0 +1,429 @@
+//! Distribute content in rows and columns that align with each other.
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell,
+    Widget,
+};
+
+pub use crate::core::layout::grid::Cell;
+
+/// A container that distributes its contents in a grid of rows and columns.
+///
+/// Every column is as wide as its widest content and every row is as tall as
+/// its tallest content, unless a different [`Length`] is given for the
+/// track.
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer = crate::Renderer> {
+    column_spacing: f32,
+    row_spacing: f32,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    align_x: Alignment,
+    align_y: Alignment,
+    cells: Vec<Option<Cell>>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`].
+    pub fn new() -> Self {
+        Grid {
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            align_x: Alignment::Start,
+            align_y: Alignment::Start,
+            cells: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the [`Length`] of the columns of the [`Grid`].
+    ///
+    /// The amount of columns decides where the contents added with
+    /// [`Grid::push`] wrap into a new row.
+    pub fn columns(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the [`Length`] of the rows of the [`Grid`].
+    ///
+    /// Rows without a [`Length`] shrink to fit their contents.
+    pub fn rows(
+        mut self,
+        rows: impl IntoIterator<Item = impl Into<Length>>,
+    ) -> Self {
+        self.rows = rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the spacing _between_ both the rows and the columns of the
+    /// [`Grid`].
+    pub fn spacing(self, amount: impl Into<Pixels>) -> Self {
+        let amount = amount.into();
+
+        self.row_spacing(amount).column_spacing(amount)
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    pub fn column_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.column_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    pub fn row_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.row_spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents added with
+    /// [`Grid::push`] inside of their cell.
+    pub fn align_x(mut self, alignment: Alignment) -> Self {
+        self.align_x = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents added with
+    /// [`Grid::push`] inside of their cell.
+    pub fn align_y(mut self, alignment: Alignment) -> Self {
+        self.align_y = alignment;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] in the next free cell, from left
+    /// to right and top to bottom.
+    pub fn push(
+        mut self,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.cells.push(None);
+        self.children.push(child.into());
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] in the given [`Cell`].
+    pub fn push_cell(
+        mut self,
+        cell: Cell,
+        child: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        self.cells.push(Some(cell));
+        self.children.push(child.into());
+        self
+    }
+
+    /// Returns the [`Cell`] of every child, placing the ones added with
+    /// [`Grid::push`] in the free cells.
+    fn placements(&self) -> Vec<Cell> {
+        let columns = self.columns.len().max(1);
+
+        // The tracks taken by the cells given with `Grid::push_cell`, by row.
+        // Placed cells never need to be tracked, since the cursor only moves
+        // forward.
+        let mut occupied: Vec<Vec<bool>> = Vec::new();
+
+        for cell in self.cells.iter().flatten() {
+            for row in cell.rows() {
+                if occupied.len() <= row {
+                    occupied.resize(row + 1, vec![false; columns]);
+                }
+
+                for column in cell.columns().take_while(|c| *c < columns) {
+                    occupied[row][column] = true;
+                }
+            }
+        }
+
+        let mut next = 0;
+
+        self.cells
+            .iter()
+            .map(|cell| {
+                cell.unwrap_or_else(|| {
+                    while occupied
+                        .get(next / columns)
+                        .is_some_and(|row| row[next % columns])
+                    {
+                        next += 1;
+                    }
+
+                    let cell = Cell::new(next / columns, next % columns)
+                        .align_x(self.align_x)
+                        .align_y(self.align_y);
+
+                    next += 1;
+
+                    cell
+                })
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.children.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            self.padding,
+            self.column_spacing,
+            self.row_spacing,
+            &self.columns,
+            &self.rows,
+            &self.placements(),
+            &self.children,
+            &mut tree.children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child
+                        .as_widget()
+                        .operate(state, layout, renderer, operation);
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.children
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget().mouse_interaction(
+                    state, layout, cursor, viewport, renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self
+            .children
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        overlay::from_children(&mut self.children, tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cell, Grid};
+    use crate::core::layout;
+    use crate::core::renderer::Null;
+    use crate::core::widget::Tree;
+    use crate::core::{Length, Rectangle, Size, Widget};
+    use crate::Space;
+
+    fn cell() -> Space {
+        Space::new(10, 10)
+    }
+
+    #[test]
+    fn contents_are_placed_around_fixed_cells() {
+        let grid: Grid<'_, (), Null> = Grid::new()
+            .columns([Length::Shrink; 3])
+            .push_cell(Cell::new(0, 1).span(2, 1), cell())
+            .push(cell())
+            .push(cell())
+            .push_cell(Cell::new(1, 2), cell())
+            .push(cell())
+            .push(cell());
+
+        let placements: Vec<_> = grid
+            .placements()
+            .iter()
+            .map(|cell| (cell.row, cell.column))
+            .collect();
+
+        assert_eq!(
+            placements,
+            vec![(0, 1), (0, 0), (0, 2), (1, 2), (1, 0), (2, 0)]
+        );
+    }
+
+    #[test]
+    fn contents_are_laid_out_in_their_cells() {
+        let grid: Grid<'_, (), Null> = Grid::new()
+            .columns([Length::Shrink; 2])
+            .spacing(5)
+            .push_cell(Cell::new(0, 0).span(1, 2), Space::new(40, 10))
+            .push(cell())
+            .push(cell())
+            .push(cell());
+
+        let mut tree = Tree::new(&grid as &dyn Widget<(), Null>);
+
+        let node = grid.layout(
+            &mut tree,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let bounds: Vec<_> =
+            node.children().iter().map(layout::Node::bounds).collect();
+
+        assert_eq!(node.size(), Size::new(40.0, 40.0));
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new([0.0, 0.0].into(), Size::new(40.0, 10.0)),
+                Rectangle::new([0.0, 15.0].into(), Size::new(10.0, 10.0)),
+                Rectangle::new([22.5, 15.0].into(), Size::new(10.0, 10.0)),
+                Rectangle::new([0.0, 30.0].into(), Size::new(10.0, 10.0)),
+            ]
+        );
+    }
+}
@@ -5,8 +5,15 @@ use crate::combo_box::{self, ComboBox};
 use crate::container::{self, Container};
 use crate::core;
 use crate::core::widget::operation;
-use crate::core::{Element, Length, Pixels};
+use crate::core::{Element, Length, Pixels, Transformation};
+use crate::draggable::Draggable;
+use crate::droppable::{self, Droppable};
+use crate::grid::Grid;
 use crate::keyed;
+use crate::list::List;
+use crate::menu_bar::{self, MenuBar};
+use crate::modal::{self, Modal};
+use crate::opacity::Opacity;
 use crate::overlay;
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
@@ -15,13 +22,19 @@ use crate::rule::{self, Rule};
 use crate::runtime::Command;
 use crate::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
+use crate::table::{self, Table};
 use crate::text::{self, Text};
+use crate::text_editor::{self, TextEditor};
 use crate::text_input::{self, TextInput};
+use crate::toast::{self, Toaster};
 use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
+use crate::transform::Transform;
+use crate::tree_view::{self, TreeView};
 use crate::{Column, MouseArea, Row, Space, VerticalSlider};
 
 use std::borrow::Cow;
+use std::hash::Hash;
 use std::ops::RangeInclusive;
 
 /// Creates a [`Column`] with the given children.
@@ -80,6 +93,16 @@ where
     keyed::Column::with_children(children)
 }
 
+/// Creates a new [`keyed::Row`] with the given children.
+pub fn keyed_row<'a, Key, Message, Renderer>(
+    children: impl IntoIterator<Item = (Key, Element<'a, Message, Renderer>)>,
+) -> keyed::Row<'a, Key, Message, Renderer>
+where
+    Key: Copy + Eq + Hash,
+{
+    keyed::Row::with_children(children)
+}
+
 /// Creates a new [`Row`] with the given children.
 ///
 /// [`Row`]: crate::Row
@@ -89,6 +112,117 @@ pub fn row<Message, Renderer>(
     Row::with_children(children)
 }
 
+/// Creates a new [`Draggable`] with the given payload and content.
+///
+/// [`Draggable`]: crate::Draggable
+pub fn draggable<'a, Payload, Message, Renderer>(
+    payload: Payload,
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Draggable<'a, Payload, Message, Renderer>
+where
+    Payload: Clone,
+{
+    Draggable::new(payload, content)
+}
+
+/// Creates a new [`Droppable`] with the given content.
+///
+/// [`Droppable`]: crate::Droppable
+pub fn droppable<'a, Payload, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+) -> Droppable<'a, Payload, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: droppable::StyleSheet,
+{
+    Droppable::new(content)
+}
+
+/// Creates a new [`Grid`] with the given [`Length`] of its columns.
+pub fn grid<'a, Message, Renderer>(
+    columns: impl IntoIterator<Item = impl Into<Length>>,
+) -> Grid<'a, Message, Renderer> {
+    Grid::new().columns(columns)
+}
+
+/// Creates a new [`List`] with the given amount of rows and a function that
+/// builds the row with a given index.
+///
+/// [`List`]: crate::List
+pub fn list<'a, Message, Renderer>(
+    count: usize,
+    view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> List<'a, usize, Message, Renderer> {
+    List::new(count, view)
+}
+
+/// Creates a new [`Table`] with the given columns.
+///
+/// [`Table`]: crate::Table
+pub fn table<'a, Message, Renderer>(
+    columns: impl IntoIterator<Item = table::Column<'a, Message, Renderer>>,
+) -> Table<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: table::StyleSheet,
+{
+    Table::new(columns)
+}
+
+/// Creates a new [`TreeView`] with the given root nodes.
+///
+/// [`TreeView`]: crate::TreeView
+pub fn tree_view<'a, Key, Message, Renderer>(
+    roots: impl IntoIterator<Item = tree_view::Node<'a, Key, Message, Renderer>>,
+) -> TreeView<'a, Key, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: tree_view::StyleSheet,
+{
+    TreeView::new(roots)
+}
+
+/// Creates a new [`MenuBar`] with the given menus.
+///
+/// [`MenuBar`]: crate::MenuBar
+pub fn menu_bar<Message, Renderer>(
+    menus: Vec<menu_bar::Menu<Message>>,
+) -> MenuBar<Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: menu_bar::StyleSheet,
+{
+    MenuBar::new(menus)
+}
+
+/// Creates a new [`Modal`] with the given base content.
+///
+/// [`Modal`]: crate::Modal
+pub fn modal<'a, Message, Renderer>(
+    base: impl Into<Element<'a, Message, Renderer>>,
+) -> Modal<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: modal::StyleSheet,
+{
+    Modal::new(base)
+}
+
+/// Creates a new [`Toaster`] with the given content and toasts.
+///
+/// [`Toaster`]: crate::Toaster
+pub fn toaster<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    toasts: &'a [toast::Toast],
+    on_close: impl Fn(toast::Id) -> Message + 'a,
+) -> Toaster<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: toast::StyleSheet,
+{
+    Toaster::new(content, toasts, on_close)
+}
+
 /// Creates a new [`Scrollable`] with the provided content.
 ///
 /// [`Scrollable`]: crate::Scrollable
@@ -143,6 +277,29 @@ where
     Text::new(text.to_string())
 }
 
+/// Creates a new [`Rich`] text with the given spans.
+///
+/// [`Rich`]: text::Rich
+pub fn rich_text<'a, Link, Renderer>(
+    spans: impl IntoIterator<Item = text::Span<'a, Link, Renderer::Font>>,
+) -> text::Rich<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text::StyleSheet,
+{
+    text::Rich::new(spans)
+}
+
+/// Creates a new [`Span`] of text with the given content.
+///
+/// [`Span`]: text::Span
+pub fn span<'a, Link, Font>(
+    text: impl Into<Cow<'a, str>>,
+) -> text::Span<'a, Link, Font> {
+    text::Span::new(text)
+}
+
 /// Creates a new [`Checkbox`].
 ///
 /// [`Checkbox`]: crate::Checkbox
@@ -206,6 +363,20 @@ where
     TextInput::new(placeholder, value)
 }
 
+/// Creates a new [`TextEditor`].
+///
+/// [`TextEditor`]: crate::TextEditor
+pub fn text_editor<'a, Message, Renderer>(
+    content: &'a text_editor::Content<Renderer>,
+) -> TextEditor<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::text::Renderer,
+    Renderer::Theme: text_editor::StyleSheet,
+{
+    TextEditor::new(content)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// [`Slider`]: crate::Slider
@@ -370,6 +541,17 @@ where
     crate::Canvas::new(program)
 }
 
+/// Creates a new [`Shader`].
+///
+/// [`Shader`]: crate::Shader
+#[cfg(feature = "wgpu")]
+pub fn shader<Message, P>(program: P) -> crate::Shader<Message, P>
+where
+    P: crate::shader::Program<Message>,
+{
+    crate::Shader::new(program)
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<Message>() -> Command<Message>
 where
@@ -386,6 +568,32 @@ where
     Command::widget(operation::focusable::focus_next())
 }
 
+/// Creates a new [`Transform`] applying a [`Transformation`] to some content.
+///
+/// [`Transform`]: crate::Transform
+pub fn transform<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    transformation: Transformation,
+) -> Transform<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Transform::new(content, transformation)
+}
+
+/// Creates a new [`Opacity`] drawing some content with the given opacity.
+///
+/// [`Opacity`]: crate::Opacity
+pub fn opacity<'a, Message, Renderer>(
+    content: impl Into<Element<'a, Message, Renderer>>,
+    opacity: f32,
+) -> Opacity<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Opacity::new(content, opacity)
+}
+
 /// A container intercepting mouse events.
 pub fn mouse_area<'a, Message, Renderer>(
     widget: impl Into<Element<'a, Message, Renderer>>,
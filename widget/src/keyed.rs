@@ -37,17 +37,36 @@
 //! The widgets in this module will all ask for a "hint" of some sort. In order
 //! to help them keep continuity, you need to make sure the hint stays the same
 //! for the same items in your user interface between `view` calls.
+//!
+//! Custom container widgets can keep the same continuity by storing the keys
+//! of their children in their state and reconciliating them with
+//! [`tree::diff_children_keyed`].
+//!
+//! [`tree::diff_children_keyed`]: crate::core::widget::tree::diff_children_keyed
 pub mod column;
+pub mod row;
 
 pub use column::Column;
+pub use row::Row;
 
-/// Creates a [`Column`] with the given children.
+/// Creates a [`Column`] with the given `key => element` children.
 #[macro_export]
 macro_rules! keyed_column {
     () => (
-        $crate::Column::new()
+        $crate::keyed::Column::new()
+    );
+    ($($key:expr => $x:expr),+ $(,)?) => (
+        $crate::keyed::Column::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
+    );
+}
+
+/// Creates a [`Row`] with the given `key => element` children.
+#[macro_export]
+macro_rules! keyed_row {
+    () => (
+        $crate::keyed::Row::new()
     );
-    ($($x:expr),+ $(,)?) => (
-        $crate::keyed::Column::with_children(vec![$($crate::core::Element::from($x)),+])
+    ($($key:expr => $x:expr),+ $(,)?) => (
+        $crate::keyed::Row::with_children(vec![$(($key, $crate::core::Element::from($x))),+])
     );
 }
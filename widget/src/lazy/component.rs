@@ -1,4 +1,5 @@
 //! Build and reuse custom widgets using The Elm Architecture.
+use crate::core::accessibility;
 use crate::core::event;
 use crate::core::layout::{self, Layout};
 use crate::core::mouse;
@@ -303,6 +304,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut heads = self.state.take().unwrap().into_heads();
 
@@ -362,8 +365,9 @@ where
                 &mut self,
                 state: &mut dyn widget::operation::Focusable,
                 id: Option<&widget::Id>,
+                bounds: Rectangle,
             ) {
-                self.operation.focusable(state, id);
+                self.operation.focusable(state, id, bounds);
             }
 
             fn text_input(
@@ -374,6 +378,19 @@ where
                 self.operation.text_input(state, id);
             }
 
+            fn text(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                text: &str,
+            ) {
+                self.operation.text(id, bounds, text);
+            }
+
+            fn wants_text(&self) -> bool {
+                self.operation.wants_text()
+            }
+
             fn scrollable(
                 &mut self,
                 state: &mut dyn widget::operation::Scrollable,
@@ -384,6 +401,15 @@ where
                 self.operation.scrollable(state, id, bounds, translation);
             }
 
+            fn accessible(
+                &mut self,
+                id: Option<&widget::Id>,
+                bounds: Rectangle,
+                node: &accessibility::Node,
+            ) -> Option<accessibility::Id> {
+                self.operation.accessible(id, bounds, node)
+            }
+
             fn custom(
                 &mut self,
                 state: &mut dyn std::any::Any,
@@ -641,6 +667,8 @@ where
             shell.request_redraw(redraw_request);
         }
 
+        shell.request_input_method(local_shell.input_method());
+
         if !local_messages.is_empty() {
             let mut inner =
                 self.overlay.take().unwrap().0.take().unwrap().into_heads();
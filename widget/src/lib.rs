@@ -18,30 +18,46 @@ pub use iced_style as style;
 
 mod column;
 mod mouse_area;
-mod row;
 
 pub mod button;
 pub mod checkbox;
 pub mod combo_box;
 pub mod container;
+pub mod draggable;
+pub mod droppable;
+pub mod grid;
 pub mod keyed;
+pub mod list;
+pub mod menu_bar;
+pub mod modal;
+pub mod opacity;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod selection;
 pub mod slider;
 pub mod space;
+pub mod table;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
+pub mod toast;
 pub mod toggler;
 pub mod tooltip;
+pub mod transform;
+pub mod tree_view;
 pub mod vertical_slider;
 
 mod helpers;
 
+#[cfg(test)]
+mod test_renderer;
+
 pub use helpers::*;
 
 #[cfg(feature = "lazy")]
@@ -64,8 +80,22 @@ pub use combo_box::ComboBox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use draggable::Draggable;
+#[doc(no_inline)]
+pub use droppable::Droppable;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use list::List;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -84,14 +114,24 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
+pub use toast::Toaster;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 #[doc(no_inline)]
+pub use transform::Transform;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
 pub use vertical_slider::VerticalSlider;
 
 #[cfg(feature = "svg")]
@@ -115,6 +155,13 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "wgpu")]
+pub mod shader;
+
+#[cfg(feature = "wgpu")]
+#[doc(no_inline)]
+pub use shader::Shader;
+
 #[cfg(feature = "qr_code")]
 pub mod qr_code;
 
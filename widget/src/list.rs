@@ -0,0 +1,679 @@
+//! Display a huge amount of rows, building only the visible ones.
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Length, Pixels, Point, Rectangle, Shell, Size, Widget,
+};
+use crate::runtime::Command;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A vertical list of rows that only builds the rows intersecting the
+/// visible part of the list.
+///
+/// A [`List`] is meant to be placed inside of a [`Scrollable`]; the rows
+/// outside of its viewport are never turned into an [`Element`].
+///
+/// The state of every built row is preserved by its key, which is the index
+/// of the row unless [`List::key`] is used. Rows that are no longer built
+/// lose their state.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct List<'a, Key, Message, Renderer = crate::Renderer> {
+    id: Option<Id>,
+    count: usize,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    key: Box<dyn Fn(usize) -> Key + 'a>,
+    row_height: RowHeight,
+    spacing: f32,
+    width: Length,
+    rows: RefCell<Vec<(Key, Element<'a, Message, Renderer>)>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RowHeight {
+    Fixed(f32),
+    Estimated(f32),
+}
+
+impl<'a, Message, Renderer> List<'a, usize, Message, Renderer> {
+    /// The default estimated height of the rows of a [`List`].
+    pub const DEFAULT_ROW_HEIGHT: f32 = 30.0;
+
+    /// Creates a new [`List`] with the given amount of rows and a function
+    /// that builds the row with a given index.
+    pub fn new(
+        count: usize,
+        view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        List {
+            id: None,
+            count,
+            view: Box::new(view),
+            key: Box::new(|index| index),
+            row_height: RowHeight::Estimated(Self::DEFAULT_ROW_HEIGHT),
+            spacing: 0.0,
+            width: Length::Fill,
+            rows: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<'a, Key, Message, Renderer> List<'a, Key, Message, Renderer> {
+    /// Sets the function that produces the key of the row with a given
+    /// index.
+    ///
+    /// The state and the measured height of a row follow its key when rows
+    /// are inserted, removed or reordered. Keys must be unique.
+    pub fn key<K>(
+        self,
+        key: impl Fn(usize) -> K + 'a,
+    ) -> List<'a, K, Message, Renderer> {
+        List {
+            id: self.id,
+            count: self.count,
+            view: self.view,
+            key: Box::new(key),
+            row_height: self.row_height,
+            spacing: self.spacing,
+            width: self.width,
+            rows: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`List`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the fixed height of every row of the [`List`].
+    ///
+    /// Rows are laid out with exactly this height, which makes scrolling
+    /// perfectly stable.
+    pub fn row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Fixed(height.into().0);
+        self
+    }
+
+    /// Sets the estimated height of the rows of the [`List`] that have not
+    /// been built yet.
+    ///
+    /// Rows shrink to fit their contents and their actual height replaces the
+    /// estimate once they are built.
+    pub fn estimated_row_height(mut self, height: impl Into<Pixels>) -> Self {
+        self.row_height = RowHeight::Estimated(height.into().0);
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`List`].
+    pub fn spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.spacing = amount.into().0;
+        self
+    }
+
+    /// Sets the width of the [`List`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+}
+
+struct State<Key> {
+    trees: HashMap<Key, Tree>,
+    /// The measured height of every row, along with the key of the row it
+    /// was measured for.
+    heights: Vec<Option<(Key, f32)>>,
+    rows: operation::list::Rows,
+    range: Range<usize>,
+    viewport: Option<Rectangle>,
+}
+
+impl<Key> Default for State<Key> {
+    fn default() -> Self {
+        Self {
+            trees: HashMap::new(),
+            heights: Vec::new(),
+            rows: operation::list::Rows::default(),
+            range: 0..0,
+            viewport: None,
+        }
+    }
+}
+
+impl<'a, Key, Message, Renderer> Widget<Message, Renderer>
+    for List<'a, Key, Message, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: crate::core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Key>::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Key>>();
+
+        let viewport = state
+            .viewport
+            .unwrap_or_else(|| initial_viewport(limits.max()));
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+
+        state.heights.resize(self.count, None);
+
+        // Measured heights move along with their rows; the ones whose row
+        // has been moved elsewhere go back to the estimate
+        if let RowHeight::Estimated(_) = self.row_height {
+            for (index, height) in state.heights.iter_mut().enumerate() {
+                if height
+                    .as_ref()
+                    .is_some_and(|(key, _)| *key != (self.key)(index))
+                {
+                    *height = None;
+                }
+            }
+        }
+
+        // Rows are built for the viewport and an extra viewport in both
+        // directions, so scrolling does not relayout on every frame
+
+        let range = visible_range(
+            &offsets(heights(&state.heights), self.row_height, self.spacing),
+            viewport.y - viewport.height,
+            viewport.y + viewport.height * 2.0,
+        );
+
+        let row_limits = match self.row_height {
+            RowHeight::Fixed(height) => {
+                layout::Limits::new(Size::ZERO, Size::new(max_width, height))
+            }
+            RowHeight::Estimated(_) => layout::Limits::new(
+                Size::ZERO,
+                Size::new(max_width, f32::INFINITY),
+            ),
+        };
+
+        let mut rows = self.rows.borrow_mut();
+        rows.clear();
+
+        let mut nodes: Vec<_> = range
+            .clone()
+            .map(|index| {
+                let key = (self.key)(index);
+                let element = (self.view)(index);
+
+                let tree = state
+                    .trees
+                    .entry(key.clone())
+                    .or_insert_with(|| Tree::new(&element));
+
+                tree.diff(&element);
+
+                let node =
+                    element.as_widget().layout(tree, renderer, &row_limits);
+
+                if let RowHeight::Estimated(_) = self.row_height {
+                    state.heights[index] =
+                        Some((key.clone(), node.size().height));
+                }
+
+                rows.push((key, element));
+
+                node
+            })
+            .collect();
+
+        // Forget the rows that are no longer built
+        {
+            let built: HashSet<&Key> =
+                rows.iter().map(|(key, _)| key).collect();
+
+            state.trees.retain(|key, _| built.contains(key));
+        }
+
+        let offsets =
+            offsets(heights(&state.heights), self.row_height, self.spacing);
+
+        for (index, node) in range.clone().zip(&mut nodes) {
+            node.move_to(Point::new(0.0, offsets[index]));
+        }
+
+        let width = nodes
+            .iter()
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max);
+
+        let height = match (offsets.last(), self.row_height) {
+            (Some(offset), RowHeight::Fixed(height)) => offset + height,
+            (Some(offset), RowHeight::Estimated(estimate)) => {
+                offset
+                    + state.heights[self.count - 1]
+                        .as_ref()
+                        .map_or(estimate, |(_, height)| *height)
+            }
+            (None, _) => 0.0,
+        };
+
+        state.rows.offsets = offsets;
+        state.range = range;
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(width, height)),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        state.rows.bounds = layout.bounds();
+        operation.custom(&mut state.rows, id);
+
+        operation.container(id, layout.bounds(), &mut |operation| {
+            for ((key, row), layout) in
+                self.rows.borrow().iter().zip(layout.children())
+            {
+                if let Some(tree) = state.trees.get_mut(key) {
+                    row.as_widget().operate(tree, layout, renderer, operation);
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let bounds = layout.bounds();
+
+        let visible = Rectangle {
+            x: viewport.x - bounds.x,
+            y: viewport.y - bounds.y,
+            ..*viewport
+        };
+
+        if state.viewport != Some(visible) {
+            state.viewport = Some(visible);
+
+            let needed = visible_range(
+                &state.rows.offsets,
+                visible.y,
+                visible.y + visible.height,
+            );
+
+            if needed.start < state.range.start || needed.end > state.range.end
+            {
+                shell.invalidate_layout();
+            }
+        }
+
+        self.rows
+            .get_mut()
+            .iter_mut()
+            .zip(layout.children())
+            .map(|((key, row), layout)| {
+                let Some(tree) = state.trees.get_mut(key) else {
+                    return event::Status::Ignored;
+                };
+
+                row.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        self.rows
+            .borrow()
+            .iter()
+            .zip(layout.children())
+            .filter_map(|((key, row), layout)| {
+                Some(row.as_widget().mouse_interaction(
+                    state.trees.get(key)?,
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        for ((key, row), layout) in
+            self.rows.borrow().iter().zip(layout.children())
+        {
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            if let Some(tree) = state.trees.get(key) {
+                row.as_widget().draw(
+                    tree, renderer, theme, style, layout, cursor, viewport,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let mut trees: HashMap<&Key, &mut Tree> =
+            state.trees.iter_mut().collect();
+
+        let children = self
+            .rows
+            .get_mut()
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|((key, row), layout)| {
+                let tree = trees.remove(&*key)?;
+
+                row.as_widget_mut().overlay(tree, layout, renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Renderer> From<List<'a, Key, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(list: List<'a, Key, Message, Renderer>) -> Self {
+        Self::new(list)
+    }
+}
+
+/// The identifier of a [`List`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] containing the
+/// [`List`] with the given [`Id`] until the row with the given index is at
+/// the top.
+///
+/// [`Scrollable`]: crate::Scrollable
+pub fn scroll_to_index<Message: 'static>(
+    id: Id,
+    index: usize,
+) -> Command<Message> {
+    Command::widget(operation::list::scroll_to_index(id.0, index))
+}
+
+/// Returns the vertical offset of every row, using the estimated height of
+/// the rows that have not been measured.
+fn offsets(
+    heights: impl IntoIterator<Item = Option<f32>>,
+    row_height: RowHeight,
+    spacing: f32,
+) -> Vec<f32> {
+    heights
+        .into_iter()
+        .scan(0.0, |offset, height| {
+            let start = *offset;
+
+            *offset += spacing
+                + match row_height {
+                    RowHeight::Fixed(height) => height,
+                    RowHeight::Estimated(estimate) => {
+                        height.unwrap_or(estimate)
+                    }
+                };
+
+            Some(start)
+        })
+        .collect()
+}
+
+/// Returns the measured heights of the rows, without their keys.
+fn heights<Key>(
+    heights: &[Option<(Key, f32)>],
+) -> impl Iterator<Item = Option<f32>> + '_ {
+    heights
+        .iter()
+        .map(|height| height.as_ref().map(|(_, height)| *height))
+}
+
+/// Returns the viewport assumed before the [`List`] knows its actual one.
+///
+/// The rows are laid out in the available space, which is unbounded inside
+/// of a [`Scrollable`]; in that case, a screenful of rows is built instead.
+///
+/// [`Scrollable`]: crate::Scrollable
+fn initial_viewport(max: Size) -> Rectangle {
+    const FALLBACK_HEIGHT: f32 = 1080.0;
+
+    let height = if max.height.is_finite() {
+        max.height
+    } else {
+        FALLBACK_HEIGHT
+    };
+
+    Rectangle::new(Point::ORIGIN, Size::new(max.width, height))
+}
+
+/// Returns the range of rows intersecting the given vertical span.
+fn visible_range(offsets: &[f32], top: f32, bottom: f32) -> Range<usize> {
+    let start = offsets
+        .partition_point(|offset| *offset <= top)
+        .saturating_sub(1);
+    let end = offsets.partition_point(|offset| *offset < bottom);
+
+    start..end.max(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        initial_viewport, offsets, visible_range, List, RowHeight, State,
+    };
+    use crate::core::layout;
+    use crate::core::renderer::Null;
+    use crate::core::widget::Tree;
+    use crate::core::{Point, Rectangle, Size, Widget};
+    use crate::Space;
+
+    #[test]
+    fn only_rows_in_the_viewport_are_visible() {
+        let offsets = offsets(
+            [Some(10.0), None, None, Some(40.0), None],
+            RowHeight::Estimated(20.0),
+            5.0,
+        );
+
+        assert_eq!(offsets, vec![0.0, 15.0, 40.0, 65.0, 110.0]);
+
+        assert_eq!(visible_range(&offsets, 0.0, 50.0), 0..3);
+        assert_eq!(visible_range(&offsets, 20.0, 70.0), 1..4);
+        assert_eq!(visible_range(&offsets, -100.0, 1000.0), 0..5);
+        assert_eq!(visible_range(&offsets, 500.0, 600.0), 4..5);
+    }
+
+    #[test]
+    fn rows_are_visible_before_the_viewport_is_known() {
+        let offsets = offsets([None; 100], RowHeight::Fixed(30.0), 0.0);
+
+        let viewport = initial_viewport(Size::new(200.0, 95.0));
+        assert_eq!(
+            visible_range(&offsets, viewport.y, viewport.y + viewport.height),
+            0..4
+        );
+
+        let viewport = initial_viewport(Size::new(200.0, f32::INFINITY));
+        let range =
+            visible_range(&offsets, viewport.y, viewport.y + viewport.height);
+
+        assert_eq!(range.start, 0);
+        assert!(range.end > 0 && range.end < offsets.len());
+    }
+
+    fn layout<Key>(list: &List<'_, Key, (), Null>, tree: &mut Tree) -> Size
+    where
+        Key: Clone + Eq + std::hash::Hash + 'static,
+    {
+        list.layout(
+            tree,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, f32::INFINITY)),
+        )
+        .size()
+    }
+
+    #[test]
+    fn rows_no_longer_built_are_forgotten() {
+        let list: List<'_, usize, (), Null> =
+            List::new(2, |_| Space::with_height(10).into());
+
+        let mut tree = Tree::new(&list as &dyn Widget<(), Null>);
+
+        {
+            let state = tree.state.downcast_mut::<State<usize>>();
+
+            for key in 0..4 {
+                let _ = state.trees.insert(key, Tree::empty());
+            }
+        }
+
+        let _ = layout(&list, &mut tree);
+
+        let state = tree.state.downcast_ref::<State<usize>>();
+        let mut keys: Vec<_> = state.trees.keys().copied().collect();
+        keys.sort_unstable();
+
+        assert_eq!(keys, vec![0, 1]);
+    }
+
+    #[test]
+    fn measured_heights_follow_their_rows() {
+        fn list<'a>(
+            rows: &'a [(&'static str, u16)],
+        ) -> List<'a, &'static str, (), Null> {
+            List::new(rows.len(), |index| {
+                Space::with_height(rows[index].1).into()
+            })
+            .key(|index| rows[index].0)
+            .estimated_row_height(30)
+        }
+
+        let rows = [("a", 10), ("b", 50)];
+        let mut tree = Tree::new(&list(&rows) as &dyn Widget<(), Null>);
+
+        assert_eq!(layout(&list(&rows), &mut tree).height, 60.0);
+
+        // A new row is inserted at the top, and only that one gets built
+        tree.state.downcast_mut::<State<&'static str>>().viewport =
+            Some(Rectangle::new(Point::ORIGIN, Size::new(200.0, 1.0)));
+
+        let rows = [("c", 20), ("a", 10), ("b", 50)];
+        let size = layout(&list(&rows), &mut tree);
+
+        let state = tree.state.downcast_ref::<State<&'static str>>();
+
+        // The heights of "a" and "b" are no longer at their indices
+        assert_eq!(state.rows.offsets, vec![0.0, 20.0, 50.0]);
+        assert_eq!(size.height, 80.0);
+    }
+}
@@ -0,0 +1,642 @@
+//! Show a bar of menus with cascading submenus and keyboard accelerators.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+use crate::overlay::cascade::{self, Cascade};
+
+use std::fmt;
+
+pub use crate::style::menu::{Appearance, StyleSheet};
+
+/// A horizontal bar of [`Menu`] titles, each opening its [`Menu`] with a
+/// click.
+///
+/// The [`Shortcut`]s of the items of every [`Menu`] are handled even when the
+/// menus are closed.
+///
+/// # Example
+/// ```no_run
+/// # type MenuBar<Message> =
+/// #     iced_widget::MenuBar<Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::core::keyboard::{KeyCode, Modifiers};
+/// use iced_widget::menu_bar::{Item, Menu, Shortcut};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     ToggleWrap,
+/// }
+///
+/// let file = Menu::new("File")
+///     .push(
+///         Item::new("Save")
+///             .on_select(Message::Save)
+///             .shortcut(Shortcut::new(Modifiers::COMMAND, KeyCode::S)),
+///     )
+///     .push(Item::separator())
+///     .push(Item::new("Quit"));
+///
+/// let view = Menu::new("View")
+///     .push(Item::new("Wrap lines").on_select(Message::ToggleWrap).checked(true));
+///
+/// let menu_bar: MenuBar<Message> = MenuBar::new(vec![file, view]);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> MenuBar<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the titles of a [`MenuBar`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 10.0,
+        bottom: 4.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`MenuBar`] with the given menus.
+    pub fn new(menus: Vec<Menu<Message>>) -> Self {
+        MenuBar {
+            menus,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn push(mut self, menu: Menu<Message>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the titles and the items of the [`MenuBar`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    menu: usize,
+    cascade: cascade::State,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuBar<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let height =
+            f32::from(text::LineHeight::default().to_absolute(text_size))
+                + self.padding.vertical();
+
+        let mut x = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let width = renderer
+                    .create_paragraph(Text {
+                        content: &menu.title,
+                        bounds: Size::INFINITY,
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Top,
+                        shaping: text::Shaping::Basic,
+                    })
+                    .min_width()
+                    + self.padding.horizontal();
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        let hovered = layout
+            .children()
+            .position(|title| cursor.is_over(title.bounds()));
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                if let Some(message) = self.menus.iter().find_map(|menu| {
+                    find_shortcut(&menu.items, key_code, modifiers)
+                }) {
+                    state.cascade.close();
+
+                    shell.publish(message.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(index) = hovered {
+                    if state.cascade.is_open() && state.menu == index {
+                        state.cascade.close();
+                    } else {
+                        state.menu = index;
+                        state.cascade.open();
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Once a menu is open, hovering a title switches to its menu
+                if let Some(index) = hovered {
+                    if state.cascade.is_open() && state.menu != index {
+                        state.menu = index;
+                        state.cascade.open();
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout
+            .children()
+            .any(|title| cursor.is_over(title.bounds()))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.appearance(&self.style);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            appearance.background,
+        );
+
+        for (index, (menu, title)) in
+            self.menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title.bounds();
+            let is_open = state.cascade.is_open() && state.menu == index;
+
+            if is_open {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.selected_background,
+                );
+            }
+
+            renderer.fill_text(
+                Text {
+                    content: &menu.title,
+                    bounds: Size::new(f32::INFINITY, bounds.height),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                },
+                Point::new(bounds.x + self.padding.left, bounds.center_y()),
+                if is_open {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.cascade.is_open() {
+            return None;
+        }
+
+        let menu = self.menus.get(state.menu)?;
+        let title = layout.children().nth(state.menu)?.bounds();
+
+        let mut cascade = Cascade::new(&mut state.cascade, &menu.items)
+            .target(layout.bounds())
+            .padding(self.padding)
+            .style(self.style.clone());
+
+        if let Some(text_size) = self.text_size {
+            cascade = cascade.text_size(text_size);
+        }
+
+        if let Some(font) = self.font {
+            cascade = cascade.font(font);
+        }
+
+        Some(cascade.overlay(Point::new(title.x, title.y + title.height)))
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuBar<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(menu_bar: MenuBar<Message, Renderer>) -> Self {
+        Element::new(menu_bar)
+    }
+}
+
+/// A titled list of [`Item`]s.
+///
+/// A [`Menu`] can be shown in a [`MenuBar`], nested in another [`Menu`] as a
+/// submenu, or opened as a context menu with [`MouseArea::context_menu`].
+///
+/// [`MouseArea::context_menu`]: crate::MouseArea::context_menu
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    pub(crate) title: String,
+    pub(crate) items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates an empty [`Menu`] with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self::with_items(title, Vec::new())
+    }
+
+    /// Creates a [`Menu`] with the given title and items.
+    pub fn with_items(
+        title: impl Into<String>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            items,
+        }
+    }
+
+    /// Adds an [`Item`] to the [`Menu`].
+    pub fn push(mut self, item: Item<Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+/// An entry of a [`Menu`].
+///
+/// An [`Item`] without a message to produce is disabled.
+#[derive(Debug, Clone)]
+pub struct Item<Message> {
+    pub(crate) label: String,
+    pub(crate) on_select: Option<Message>,
+    pub(crate) shortcut: Option<Shortcut>,
+    pub(crate) checked: Option<bool>,
+    pub(crate) submenu: Option<Menu<Message>>,
+    pub(crate) is_separator: bool,
+}
+
+impl<Message> Item<Message> {
+    /// Creates a new [`Item`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            on_select: None,
+            shortcut: None,
+            checked: None,
+            submenu: None,
+            is_separator: false,
+        }
+    }
+
+    /// Creates an [`Item`] that opens the given [`Menu`] next to it, labeled
+    /// with its title.
+    pub fn submenu(menu: Menu<Message>) -> Self {
+        let label = menu.title.clone();
+
+        Self {
+            submenu: Some(menu),
+            ..Self::new(label)
+        }
+    }
+
+    /// Creates an [`Item`] that separates the items around it.
+    pub fn separator() -> Self {
+        Self {
+            is_separator: true,
+            ..Self::new(String::new())
+        }
+    }
+
+    /// Sets the message that should be produced when the [`Item`] is
+    /// selected.
+    pub fn on_select(mut self, message: Message) -> Self {
+        self.on_select = Some(message);
+        self
+    }
+
+    /// Sets the [`Shortcut`] of the [`Item`].
+    ///
+    /// The [`Shortcut`] is displayed next to the label and selects the
+    /// [`Item`] when pressed.
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Makes the [`Item`] checkable, showing a checkmark when it is checked.
+    pub fn checked(mut self, is_checked: bool) -> Self {
+        self.checked = Some(is_checked);
+        self
+    }
+
+    /// Returns whether the [`Item`] can be selected.
+    pub(crate) fn is_enabled(&self) -> bool {
+        match &self.submenu {
+            Some(menu) => !menu.items.is_empty(),
+            None => !self.is_separator && self.on_select.is_some(),
+        }
+    }
+}
+
+/// A keyboard accelerator of an [`Item`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The modifiers that must be pressed.
+    pub modifiers: keyboard::Modifiers,
+    /// The key that must be pressed.
+    pub key_code: keyboard::KeyCode,
+}
+
+impl Shortcut {
+    /// Creates a new [`Shortcut`].
+    pub fn new(
+        modifiers: keyboard::Modifiers,
+        key_code: keyboard::KeyCode,
+    ) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns whether the given key press triggers the [`Shortcut`].
+    pub fn matches(
+        &self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+    ) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        for (modifier, name) in [
+            (keyboard::Modifiers::CTRL, "Ctrl"),
+            (keyboard::Modifiers::ALT, "Alt"),
+            (keyboard::Modifiers::SHIFT, "Shift"),
+            (keyboard::Modifiers::LOGO, logo),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        let key = format!("{:?}", self.key_code);
+
+        // Digits are named `Key0` to `Key9`
+        let key = key
+            .strip_prefix("Key")
+            .filter(|digit| digit.len() == 1)
+            .unwrap_or(&key);
+
+        write!(f, "{key}")
+    }
+}
+
+/// Returns the message of the enabled [`Item`] triggered by the given key
+/// press, searching the submenus as well.
+pub(crate) fn find_shortcut<Message>(
+    items: &[Item<Message>],
+    key_code: keyboard::KeyCode,
+    modifiers: keyboard::Modifiers,
+) -> Option<&Message> {
+    items.iter().find_map(|item| {
+        if item
+            .shortcut
+            .is_some_and(|shortcut| shortcut.matches(key_code, modifiers))
+        {
+            return item.on_select.as_ref();
+        }
+
+        item.submenu
+            .as_ref()
+            .and_then(|menu| find_shortcut(&menu.items, key_code, modifiers))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_shortcut, Item, Menu, Shortcut};
+    use crate::core::keyboard::{KeyCode, Modifiers};
+
+    #[test]
+    fn shortcuts_are_found_in_submenus() {
+        let items = vec![
+            Item::new("Open")
+                .on_select(1)
+                .shortcut(Shortcut::new(Modifiers::CTRL, KeyCode::O)),
+            Item::separator(),
+            Item::submenu(Menu::new("Export").push(
+                Item::new("As PNG").on_select(2).shortcut(Shortcut::new(
+                    Modifiers::CTRL | Modifiers::SHIFT,
+                    KeyCode::E,
+                )),
+            )),
+            Item::new("Disabled")
+                .shortcut(Shortcut::new(Modifiers::CTRL, KeyCode::D)),
+        ];
+
+        assert_eq!(
+            find_shortcut(&items, KeyCode::O, Modifiers::CTRL),
+            Some(&1)
+        );
+        assert_eq!(
+            find_shortcut(
+                &items,
+                KeyCode::E,
+                Modifiers::CTRL | Modifiers::SHIFT
+            ),
+            Some(&2)
+        );
+        assert_eq!(find_shortcut(&items, KeyCode::E, Modifiers::CTRL), None);
+        assert_eq!(find_shortcut(&items, KeyCode::D, Modifiers::CTRL), None);
+    }
+
+    #[test]
+    fn shortcuts_are_displayed_with_their_modifiers() {
+        let shortcut =
+            Shortcut::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::Key1);
+
+        assert_eq!(shortcut.to_string(), "Ctrl+Shift+1");
+        assert_eq!(
+            Shortcut::new(Modifiers::ALT, KeyCode::F4).to_string(),
+            "Alt+F4"
+        );
+    }
+}
@@ -0,0 +1,633 @@
+//! Show dialogs over some content, dimming it and trapping the focus.
+use crate::core::alignment::Alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::operation::{Focusable, Operation};
+use crate::core::widget::{tree, Id, Tree};
+use crate::core::{
+    Clipboard, Element, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+pub use crate::style::modal::{Appearance, StyleSheet};
+
+/// Some content with a stack of [`Dialog`]s shown over it.
+///
+/// Every [`Dialog`] dims everything behind it, and only the topmost one
+/// receives events. The focus is trapped inside the topmost [`Dialog`]:
+/// pressing Tab cycles through its focusable widgets, and focus operations
+/// ignore the content behind it.
+///
+/// # Example
+/// ```no_run
+/// # type Modal<'a, Message> =
+/// #     iced_widget::Modal<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::modal::Dialog;
+/// use iced_widget::{button, text};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Open,
+///     Close,
+/// }
+///
+/// let is_open = true;
+///
+/// let mut modal: Modal<'_, Message> =
+///     Modal::new(button(text("Open")).on_press(Message::Open));
+///
+/// if is_open {
+///     modal = modal.push(
+///         Dialog::new(button(text("Close")).on_press(Message::Close))
+///             .on_close(Message::Close),
+///     );
+/// }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    base: Element<'a, Message, Renderer>,
+    dialogs: Vec<Dialog<'a, Message, Renderer>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Modal`] with the given base content and no dialogs.
+    pub fn new(base: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            base: base.into(),
+            dialogs: Vec::new(),
+            style: Default::default(),
+        }
+    }
+
+    /// Shows a [`Dialog`] on top of the others.
+    pub fn push(mut self, dialog: Dialog<'a, Message, Renderer>) -> Self {
+        self.dialogs.push(dialog);
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// Some content shown centered in a [`Modal`].
+#[allow(missing_debug_implementations)]
+pub struct Dialog<'a, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_close: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Dialog<'a, Message, Renderer> {
+    /// Creates a new [`Dialog`] with the given content.
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            on_close: None,
+        }
+    }
+
+    /// Sets the message that should be produced when Escape is pressed or
+    /// the backdrop of the [`Dialog`] is clicked.
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`Modal`].
+#[derive(Debug, Default)]
+struct State {
+    /// The amount of dialogs that got the initial focus.
+    focused: usize,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.base)
+            .chain(self.dialogs.iter().map(|dialog| &dialog.content))
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.base)
+            .chain(self.dialogs.iter().map(|dialog| &dialog.content))
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.base.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        // The topmost dialog is operated as an overlay
+        if self.dialogs.is_empty() {
+            self.base.as_widget().operate(
+                &mut tree.children[0],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        if !self.dialogs.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        tree.state.downcast_mut::<State>().focused = 0;
+
+        self.base.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let (base, dialogs) = children.split_first_mut()?;
+
+        if self.dialogs.is_empty() {
+            return self.base.as_widget_mut().overlay(base, layout, renderer);
+        }
+
+        let state = state.downcast_mut::<State>();
+        let count = self.dialogs.len();
+        let mut focused = Some(&mut state.focused);
+
+        let layers = self
+            .dialogs
+            .iter_mut()
+            .zip(dialogs)
+            .enumerate()
+            .map(|(index, (dialog, tree))| {
+                overlay::Element::new(
+                    layout.position(),
+                    Box::new(Layer {
+                        dialog,
+                        tree,
+                        focused: if index + 1 == count {
+                            focused.take()
+                        } else {
+                            None
+                        },
+                        count,
+                        style: &self.style,
+                    }),
+                )
+            })
+            .collect();
+
+        Some(overlay::Group::with_children(layers).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(modal: Modal<'a, Message, Renderer>) -> Self {
+        Element::new(modal)
+    }
+}
+
+/// A [`Dialog`] and its backdrop, covering the whole window.
+struct Layer<'a, 'b, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    dialog: &'b mut Dialog<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    /// Only present for the topmost dialog.
+    focused: Option<&'b mut usize>,
+    count: usize,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Layer<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let mut content = self
+            .dialog
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        content.align(Alignment::Center, Alignment::Center, bounds);
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // Dialogs below the topmost one are inert
+        let Some(focused) = self.focused.as_deref_mut() else {
+            return event::Status::Ignored;
+        };
+
+        let content = layout.children().next().unwrap();
+
+        if *focused != self.count {
+            if *focused < self.count {
+                focus(
+                    &self.dialog.content,
+                    self.tree,
+                    content,
+                    renderer,
+                    |focused, total| {
+                        (focused.is_none() && total > 0).then_some(0)
+                    },
+                );
+            }
+
+            *focused = self.count;
+        }
+
+        match &event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                if let Some(message) = &self.dialog.on_close {
+                    shell.publish(message.clone());
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(content.bounds()) =>
+            {
+                if let Some(message) = &self.dialog.on_close {
+                    shell.publish(message.clone());
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let status = self.dialog.content.as_widget_mut().on_event(
+            self.tree,
+            event.clone(),
+            content,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        );
+
+        if let (
+            event::Status::Ignored,
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }),
+        ) = (status, event)
+        {
+            focus(
+                &self.dialog.content,
+                self.tree,
+                content,
+                renderer,
+                |focused, total| cycle(focused, total, !modifiers.shift()),
+            );
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            appearance.backdrop,
+        );
+
+        self.dialog.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            if self.focused.is_some() {
+                cursor
+            } else {
+                mouse::Cursor::Unavailable
+            },
+            &layout.bounds(),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        if self.focused.is_some() {
+            self.dialog.content.as_widget().operate(
+                self.tree,
+                layout.children().next().unwrap(),
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.focused.is_none() {
+            return mouse::Interaction::default();
+        }
+
+        self.dialog.content.as_widget().mouse_interaction(
+            self.tree,
+            layout.children().next().unwrap(),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Renderer>> {
+        if self.focused.is_some() {
+            self.dialog.content.as_widget_mut().overlay(
+                self.tree,
+                layout.children().next().unwrap(),
+                renderer,
+            )
+        } else {
+            None
+        }
+    }
+}
+
+/// Moves the focus among the focusable widgets of some content.
+///
+/// The `target` receives the index of the focused widget, if any, and the
+/// total amount of focusable widgets, and returns the index of the widget to
+/// focus.
+fn focus<Message, Renderer>(
+    content: &Element<'_, Message, Renderer>,
+    tree: &mut Tree,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    target: impl FnOnce(Option<usize>, usize) -> Option<usize>,
+) where
+    Renderer: renderer::Renderer,
+{
+    #[derive(Default)]
+    struct Focus {
+        total: usize,
+        focused: Option<usize>,
+        target: Option<usize>,
+        current: usize,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            match self.target {
+                None => {
+                    if state.is_focused() {
+                        self.focused = Some(self.total);
+                    }
+
+                    self.total += 1;
+                }
+                Some(target) => {
+                    if self.current == target {
+                        state.focus();
+                    } else {
+                        state.unfocus();
+                    }
+
+                    self.current += 1;
+                }
+            }
+        }
+
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self);
+        }
+    }
+
+    let mut operation = Focus::default();
+
+    content
+        .as_widget()
+        .operate(tree, layout, renderer, &mut operation);
+
+    operation.target = target(operation.focused, operation.total);
+
+    if operation.target.is_some() {
+        content
+            .as_widget()
+            .operate(tree, layout, renderer, &mut operation);
+    }
+}
+
+/// Returns the index of the widget that should be focused after the focused
+/// one, wrapping around at both ends.
+fn cycle(focused: Option<usize>, total: usize, forward: bool) -> Option<usize> {
+    if total == 0 {
+        return None;
+    }
+
+    Some(match (focused, forward) {
+        (None, true) => 0,
+        (None, false) => total - 1,
+        (Some(index), true) => (index + 1) % total,
+        (Some(index), false) => (index + total - 1) % total,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cycle;
+
+    #[test]
+    fn focus_cycles_through_both_ends() {
+        assert_eq!(cycle(None, 3, true), Some(0));
+        assert_eq!(cycle(None, 3, false), Some(2));
+        assert_eq!(cycle(Some(2), 3, true), Some(0));
+        assert_eq!(cycle(Some(0), 3, false), Some(2));
+        assert_eq!(cycle(Some(1), 3, true), Some(2));
+        assert_eq!(cycle(None, 0, true), None);
+    }
+}
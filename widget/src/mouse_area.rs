@@ -5,11 +5,14 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::text;
 use crate::core::touch;
 use crate::core::widget::{tree, Operation, Tree};
 use crate::core::{
-    Clipboard, Element, Layout, Length, Rectangle, Shell, Widget,
+    Clipboard, Element, Layout, Length, Point, Rectangle, Shell, Vector, Widget,
 };
+use crate::menu_bar::Menu;
+use crate::overlay::cascade::{self, Cascade};
 
 /// Emit messages on mouse events.
 #[allow(missing_debug_implementations)]
@@ -21,6 +24,7 @@ pub struct MouseArea<'a, Message, Renderer> {
     on_right_release: Option<Message>,
     on_middle_press: Option<Message>,
     on_middle_release: Option<Message>,
+    context_menu: Option<Box<dyn ContextMenu<Message, Renderer> + 'a>>,
 }
 
 impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
@@ -65,12 +69,48 @@ impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
         self.on_middle_release = Some(message);
         self
     }
+
+    /// The [`Menu`] to open at the cursor on a right button press.
+    #[must_use]
+    pub fn context_menu(self, menu: Menu<Message>) -> Self
+    where
+        Message: Clone + 'a,
+        Renderer: text::Renderer + 'a,
+        Renderer::Theme: cascade::StyleSheet,
+    {
+        self.context_menu_with_style(
+            menu,
+            <Renderer::Theme as cascade::StyleSheet>::Style::default(),
+        )
+    }
+
+    /// The [`Menu`] to open at the cursor on a right button press, drawn
+    /// with the given style.
+    #[must_use]
+    pub fn context_menu_with_style(
+        mut self,
+        menu: Menu<Message>,
+        style: impl Into<<Renderer::Theme as cascade::StyleSheet>::Style>,
+    ) -> Self
+    where
+        Message: Clone + 'a,
+        Renderer: text::Renderer + 'a,
+        Renderer::Theme: cascade::StyleSheet,
+    {
+        self.context_menu = Some(Box::new(Context {
+            menu,
+            style: style.into(),
+        }));
+        self
+    }
 }
 
 /// Local state of the [`MouseArea`].
 #[derive(Default)]
 struct State {
     // TODO: Support on_mouse_enter and on_mouse_exit
+    context_menu: cascade::State,
+    context_menu_offset: Vector,
 }
 
 impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
@@ -84,6 +124,7 @@ impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
             on_right_release: None,
             on_middle_press: None,
             on_middle_release: None,
+            context_menu: None,
         }
     }
 }
@@ -168,6 +209,24 @@ where
             return event::Status::Captured;
         }
 
+        if self.context_menu.is_some() {
+            if let Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Right,
+            )) = event
+            {
+                if let Some(position) = cursor.position_over(layout.bounds()) {
+                    let state = tree.state.downcast_mut::<State>();
+
+                    state.context_menu.open();
+                    state.context_menu_offset = position - layout.position();
+
+                    let _ = update(self, &event, layout, cursor, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         update(self, &event, layout, cursor, shell)
     }
 
@@ -215,11 +274,32 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
-        self.content.as_widget_mut().overlay(
-            &mut tree.children[0],
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut children[0],
             layout,
             renderer,
-        )
+        );
+
+        let state = state.downcast_mut::<State>();
+        let position = layout.position() + state.context_menu_offset;
+
+        let context_menu = self
+            .context_menu
+            .as_ref()
+            .filter(|_| state.context_menu.is_open())
+            .map(|menu| menu.overlay(&mut state.context_menu, position));
+
+        match (content, context_menu) {
+            (Some(content), Some(context_menu)) => Some(
+                overlay::Group::with_children(vec![content, context_menu])
+                    .overlay(),
+            ),
+            (content, context_menu) => content.or(context_menu),
+        }
     }
 }
 
@@ -314,3 +394,39 @@ fn update<Message: Clone, Renderer>(
 
     event::Status::Ignored
 }
+
+/// A [`Menu`] opened by a [`MouseArea`], which knows how to show itself.
+trait ContextMenu<Message, Renderer> {
+    fn overlay<'a>(
+        &'a self,
+        state: &'a mut cascade::State,
+        position: Point,
+    ) -> overlay::Element<'a, Message, Renderer>;
+}
+
+struct Context<Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: cascade::StyleSheet,
+{
+    menu: Menu<Message>,
+    style: <Renderer::Theme as cascade::StyleSheet>::Style,
+}
+
+impl<Message, Renderer> ContextMenu<Message, Renderer>
+    for Context<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+    Renderer::Theme: cascade::StyleSheet,
+{
+    fn overlay<'a>(
+        &'a self,
+        state: &'a mut cascade::State,
+        position: Point,
+    ) -> overlay::Element<'a, Message, Renderer> {
+        Cascade::new(state, &self.menu.items)
+            .style(self.style.clone())
+            .overlay(position)
+    }
+}
@@ -0,0 +1,193 @@
+//! Fade some content as a whole.
+//!
+//! # Example
+//! ```no_run
+//! # type Opacity<'a, Message> =
+//! #     iced_widget::Opacity<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+//! #
+//! use iced_widget::{button, column, text};
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Save,
+//! }
+//!
+//! let disabled_panel: Opacity<'_, Message> = Opacity::new(
+//!     column![text("Settings"), button("Save").on_press(Message::Save)],
+//!     0.5,
+//! );
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, Layout, Length, Rectangle, Shell, Widget,
+};
+
+/// A widget that draws its content with some opacity.
+///
+/// The content is composited as a whole, so overlapping children do not
+/// show through each other. Its layout and interactions are left untouched.
+///
+/// Overlays of the content are not faded.
+#[allow(missing_debug_implementations)]
+pub struct Opacity<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer> {
+    /// Creates a new [`Opacity`] drawing the content with the given opacity,
+    /// between `0.0` (invisible) and `1.0` (opaque).
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        opacity: f32,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            opacity: opacity.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if self.opacity <= 0.0 {
+            return;
+        }
+
+        renderer.with_opacity(self.opacity, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout,
+            renderer,
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Opacity<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(opacity: Opacity<'a, Message, Renderer>) -> Self {
+        Element::new(opacity)
+    }
+}
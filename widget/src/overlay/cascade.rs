@@ -0,0 +1,606 @@
+//! Show menus with cascading submenus.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::{
+    Clipboard, Color, Padding, Pixels, Point, Rectangle, Shell, Size,
+};
+use crate::menu_bar::{self, Item};
+
+pub use iced_style::menu::{Appearance, StyleSheet};
+
+/// A stack of menus, where every open submenu is shown next to its item.
+#[allow(missing_debug_implementations)]
+pub struct Cascade<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    state: &'a mut State,
+    items: &'a [Item<Message>],
+    target: Rectangle,
+    padding: Padding,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Cascade<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the items of a [`Cascade`].
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 4.0,
+        right: 10.0,
+        bottom: 4.0,
+        left: 10.0,
+    };
+
+    /// Creates a new [`Cascade`] with the given [`State`] and the items of
+    /// its first menu.
+    pub fn new(state: &'a mut State, items: &'a [Item<Message>]) -> Self {
+        Cascade {
+            state,
+            items,
+            target: Rectangle::new(Point::ORIGIN, Size::ZERO),
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the bounds of the widget that opened the [`Cascade`].
+    ///
+    /// Presses on the target do not close the [`Cascade`], so the widget can
+    /// handle them itself.
+    pub fn target(mut self, target: Rectangle) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Sets the [`Padding`] of the items of the [`Cascade`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`Cascade`].
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the [`Cascade`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Cascade`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Turns the [`Cascade`] into an overlay [`Element`] with its first menu
+    /// at the given position.
+    ///
+    /// [`Element`]: overlay::Element
+    pub fn overlay(
+        self,
+        position: Point,
+    ) -> overlay::Element<'a, Message, Renderer> {
+        overlay::Element::new(position, Box::new(self))
+    }
+
+    fn panel(
+        &self,
+        renderer: &Renderer,
+        items: &[Item<Message>],
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let measure = |content: &str| {
+            renderer
+                .create_paragraph(Text {
+                    content,
+                    bounds: Size::INFINITY,
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                })
+                .min_width()
+        };
+
+        let (labels, shortcuts) = items
+            .iter()
+            .filter(|item| !item.is_separator)
+            .fold((0.0, 0.0), |(labels, shortcuts), item| {
+                let shortcut = item
+                    .shortcut
+                    .map(|shortcut| measure(&shortcut.to_string()))
+                    .unwrap_or(0.0);
+
+                (
+                    f32::max(labels, measure(&item.label)),
+                    f32::max(shortcuts, shortcut),
+                )
+            });
+
+        // The checkmark and the submenu arrow get a column each
+        let gap = if shortcuts > 0.0 {
+            text_size.0 * 2.0
+        } else {
+            0.0
+        };
+        let width = self.padding.horizontal()
+            + text_size.0 * 2.0
+            + labels
+            + gap
+            + shortcuts;
+
+        let item_height =
+            f32::from(text::LineHeight::default().to_absolute(text_size))
+                + self.padding.vertical();
+
+        let mut height = 0.0;
+
+        let children = items
+            .iter()
+            .map(|item| {
+                let item_height = if item.is_separator {
+                    self.padding.vertical() + 1.0
+                } else {
+                    item_height
+                };
+
+                let mut node = layout::Node::new(Size::new(width, item_height));
+                node.move_to(Point::new(0.0, height));
+
+                height += item_height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, height), children)
+    }
+
+    fn open(
+        &mut self,
+        level: usize,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let path = &mut self.state.path;
+
+        if path.len() != level + 1 || path[level] != index {
+            path.truncate(level);
+            path.push(index);
+
+            shell.invalidate_layout();
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Cascade<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let mut panels: Vec<layout::Node> = Vec::new();
+        let mut position = position;
+
+        for (level, items) in levels(self.items, &self.state.path).enumerate() {
+            let mut panel = self.panel(renderer, items);
+            let size = panel.size();
+
+            // Submenus open to the right of their parent, unless there is
+            // no room left
+            let x = match panels.last().map(layout::Node::bounds) {
+                Some(parent)
+                    if parent.x + parent.width + size.width > bounds.width =>
+                {
+                    parent.x - size.width
+                }
+                Some(parent) => parent.x + parent.width,
+                None => position.x.min(bounds.width - size.width),
+            }
+            .max(0.0);
+
+            let y = position.y.min(bounds.height - size.height).max(0.0);
+
+            if let Some(item) = self
+                .state
+                .path
+                .get(level)
+                .and_then(|&index| panel.children().get(index))
+            {
+                position.y = y + item.bounds().y;
+            }
+
+            panel.move_to(Point::new(x, y));
+            panels.push(panel);
+        }
+
+        layout::Node::with_children(bounds, panels)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let hovered = hovered(self.items, &self.state.path, layout, cursor);
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index, item)) = hovered {
+                    if item.submenu.is_some() && item.is_enabled() {
+                        self.open(level, index, shell);
+                    } else if self.state.path.len() > level {
+                        self.state.path.truncate(level);
+
+                        shell.invalidate_layout();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((level, index, item)) = hovered {
+                    if item.submenu.is_some() {
+                        if item.is_enabled() {
+                            self.open(level, index, shell);
+                        }
+                    } else if let Some(message) = &item.on_select {
+                        self.state.close();
+
+                        shell.publish(message.clone());
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                return self.press_outside(layout, cursor);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                return self.press_outside(layout, cursor);
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                if key_code == keyboard::KeyCode::Escape {
+                    self.state.close();
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(message) =
+                    menu_bar::find_shortcut(self.items, key_code, modifiers)
+                {
+                    self.state.close();
+
+                    shell.publish(message.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        match hovered(self.items, &self.state.path, layout, cursor) {
+            Some((_, _, item)) if item.is_enabled() => {
+                mouse::Interaction::Pointer
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let appearance = theme.appearance(&self.style);
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        let hovered = hovered(self.items, &self.state.path, layout, cursor)
+            .map(|(level, index, _)| (level, index));
+
+        for (level, (items, panel)) in levels(self.items, &self.state.path)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = panel.bounds();
+            let open = self.state.path.get(level).copied();
+
+            // Every panel gets its own layer, so submenus cover the text of
+            // their parents
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_color: appearance.border_color,
+                        border_width: appearance.border_width,
+                        border_radius: appearance.border_radius,
+                        ..renderer::Quad::default()
+                    },
+                    appearance.background,
+                );
+
+                for (index, (item, layout)) in
+                    items.iter().zip(panel.children()).enumerate()
+                {
+                    let bounds = layout.bounds();
+
+                    if item.is_separator {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + self.padding.left,
+                                    y: bounds.center_y().floor(),
+                                    width: bounds.width
+                                        - self.padding.horizontal(),
+                                    height: 1.0,
+                                },
+                                ..renderer::Quad::default()
+                            },
+                            appearance.border_color,
+                        );
+
+                        continue;
+                    }
+
+                    let is_enabled = item.is_enabled();
+                    let is_highlighted = is_enabled
+                        && (hovered == Some((level, index))
+                            || open == Some(index));
+
+                    if is_highlighted {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: bounds.x + appearance.border_width,
+                                    width: bounds.width
+                                        - appearance.border_width * 2.0,
+                                    ..bounds
+                                },
+                                border_radius: appearance.border_radius,
+                                ..renderer::Quad::default()
+                            },
+                            appearance.selected_background,
+                        );
+                    }
+
+                    let color = if is_highlighted {
+                        appearance.selected_text_color
+                    } else if is_enabled {
+                        appearance.text_color
+                    } else {
+                        Color {
+                            a: appearance.text_color.a * 0.5,
+                            ..appearance.text_color
+                        }
+                    };
+
+                    let text = Text {
+                        content: "",
+                        bounds: Size::new(f32::INFINITY, bounds.height),
+                        size: text_size,
+                        line_height: text::LineHeight::default(),
+                        font,
+                        horizontal_alignment: alignment::Horizontal::Left,
+                        vertical_alignment: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                    };
+
+                    let left = bounds.x + self.padding.left;
+                    let right = bounds.x + bounds.width - self.padding.right;
+
+                    if item.checked == Some(true) {
+                        renderer.fill_text(
+                            Text {
+                                content: &Renderer::CHECKMARK_ICON.to_string(),
+                                font: Renderer::ICON_FONT,
+                                ..text
+                            },
+                            Point::new(left, bounds.center_y()),
+                            color,
+                        );
+                    }
+
+                    renderer.fill_text(
+                        Text {
+                            content: &item.label,
+                            ..text
+                        },
+                        Point::new(left + text_size.0, bounds.center_y()),
+                        color,
+                    );
+
+                    if let Some(shortcut) = item.shortcut {
+                        renderer.fill_text(
+                            Text {
+                                content: &shortcut.to_string(),
+                                horizontal_alignment:
+                                    alignment::Horizontal::Right,
+                                ..text
+                            },
+                            Point::new(right - text_size.0, bounds.center_y()),
+                            color,
+                        );
+                    }
+
+                    if item.submenu.is_some() {
+                        renderer.fill_text(
+                            Text {
+                                content: "›",
+                                horizontal_alignment:
+                                    alignment::Horizontal::Right,
+                                ..text
+                            },
+                            Point::new(right, bounds.center_y()),
+                            color,
+                        );
+                    }
+                }
+            });
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|panel| panel.bounds().contains(cursor_position))
+    }
+}
+
+impl<'a, Message, Renderer> Cascade<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn press_outside(
+        &mut self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> event::Status {
+        if layout
+            .children()
+            .any(|panel| cursor.is_over(panel.bounds()))
+        {
+            return event::Status::Captured;
+        }
+
+        if !cursor.is_over(self.target) {
+            self.state.close();
+        }
+
+        event::Status::Ignored
+    }
+}
+
+/// The local state of a [`Cascade`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    is_open: bool,
+    path: Vec<usize>,
+}
+
+impl State {
+    /// Creates a new, closed [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Cascade`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the [`Cascade`] with all of its submenus closed.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.path.clear();
+    }
+
+    /// Closes the [`Cascade`].
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.path.clear();
+    }
+}
+
+/// Returns the items of every open menu, starting with the first one.
+fn levels<'a, 'b, Message>(
+    items: &'a [Item<Message>],
+    path: &'b [usize],
+) -> impl Iterator<Item = &'a [Item<Message>]> + 'b
+where
+    'a: 'b,
+{
+    std::iter::once(items).chain(path.iter().scan(items, |items, &index| {
+        let submenu = items.get(index)?.submenu.as_ref()?;
+
+        *items = &submenu.items;
+
+        Some(*items)
+    }))
+}
+
+/// Returns the level, the index and the item under the cursor, if any.
+fn hovered<'a, Message>(
+    items: &'a [Item<Message>],
+    path: &[usize],
+    layout: Layout<'_>,
+    cursor: mouse::Cursor,
+) -> Option<(usize, usize, &'a Item<Message>)> {
+    let position = cursor.position()?;
+
+    // Submenus are drawn on top of their parents
+    levels(items, path)
+        .zip(layout.children())
+        .enumerate()
+        .filter_map(|(level, (items, panel))| {
+            items.iter().zip(panel.children()).enumerate().find_map(
+                |(index, (item, layout))| {
+                    layout
+                        .bounds()
+                        .contains(position)
+                        .then_some((level, index, item))
+                },
+            )
+        })
+        .last()
+}
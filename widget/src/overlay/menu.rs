@@ -309,6 +309,7 @@ where
                 border_color: appearance.border_color,
                 border_width: appearance.border_width,
                 border_radius: appearance.border_radius,
+                ..renderer::Quad::default()
             },
             appearance.background,
         );
@@ -521,6 +522,7 @@ where
                         border_color: Color::TRANSPARENT,
                         border_width: 0.0,
                         border_radius: appearance.border_radius,
+                        ..renderer::Quad::default()
                     },
                     appearance.selected_background,
                 );
@@ -892,6 +892,7 @@ pub fn draw<Renderer, T>(
                                     .border_radius,
                                 border_width: hovered_region_style.border_width,
                                 border_color: hovered_region_style.border_color,
+                                ..renderer::Quad::default()
                             },
                             theme.hovered_region(style).background,
                         );
@@ -921,6 +922,7 @@ pub fn draw<Renderer, T>(
                 border_radius: hovered_region_style.border_radius,
                 border_width: hovered_region_style.border_width,
                 border_color: hovered_region_style.border_color,
+                ..renderer::Quad::default()
             },
             theme.hovered_region(style).background,
         );
@@ -984,6 +986,7 @@ pub fn draw<Renderer, T>(
                         border_radius: 0.0.into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        ..renderer::Quad::default()
                     },
                     highlight.color,
                 );
@@ -658,6 +658,7 @@ pub fn draw<'a, T, Renderer>(
             border_color: style.border_color,
             border_width: style.border_width,
             border_radius: style.border_radius,
+            ..renderer::Quad::default()
         },
         style.background,
     );
@@ -1,8 +1,9 @@
 //! Provide progress feedback to your users.
+use crate::core::accessibility;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::widget::Tree;
+use crate::core::widget::{Operation, Tree};
 use crate::core::{Color, Element, Layout, Length, Rectangle, Size, Widget};
 
 use std::ops::RangeInclusive;
@@ -108,6 +109,21 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let _ = operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::ProgressBar)
+                .value(self.value.to_string()),
+        );
+    }
+
     fn draw(
         &self,
         _state: &Tree,
@@ -136,6 +152,7 @@ where
                 border_radius: style.border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
             },
             style.background,
         );
@@ -150,6 +167,7 @@ where
                     border_radius: style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    ..renderer::Quad::default()
                 },
                 style.bar,
             );
@@ -1,4 +1,5 @@
 //! Create choices using radio buttons.
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
 use crate::core::layout;
@@ -194,11 +195,14 @@ where
     Renderer::Theme: StyleSheet + crate::text::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            label: widget::text::State::default(),
+            accessibility: None,
+        })
     }
 
     fn width(&self) -> Length {
@@ -220,12 +224,11 @@ where
             self.spacing,
             |_| layout::Node::new(Size::new(self.size, self.size)),
             |limits| {
-                let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                 widget::text::layout(
-                    state,
+                    &mut state.label,
                     renderer,
                     limits,
                     self.width,
@@ -242,9 +245,32 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let id = operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::RadioButton)
+                .label(self.label.as_str())
+                .toggled(self.is_selected)
+                .action(accessibility::Action::Click),
+        );
+
+        if let Some(id) = id {
+            state.accessibility = Some(id);
+        }
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -262,6 +288,17 @@ where
                     return event::Status::Captured;
                 }
             }
+            Event::Accessibility(accessibility::Event::Click(target))
+                if tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .accessibility
+                    == Some(target) =>
+            {
+                shell.publish(self.on_click.clone());
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
@@ -316,6 +353,7 @@ where
                     border_radius: (size / 2.0).into(),
                     border_width: custom_style.border_width,
                     border_color: custom_style.border_color,
+                    ..renderer::Quad::default()
                 },
                 custom_style.background,
             );
@@ -332,6 +370,7 @@ where
                         border_radius: (dot_size / 2.0).into(),
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        ..renderer::Quad::default()
                     },
                     custom_style.dot_color,
                 );
@@ -345,7 +384,10 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .label,
                 crate::text::Appearance {
                     color: custom_style.text_color,
                 },
@@ -354,6 +396,13 @@ where
     }
 }
 
+/// The local state of a [`Radio`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    accessibility: Option<accessibility::Id>,
+}
+
 impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -6,8 +6,8 @@ use crate::core::overlay;
 use crate::core::renderer;
 use crate::core::widget::{Operation, Tree};
 use crate::core::{
-    Alignment, Clipboard, Element, Length, Padding, Pixels, Rectangle, Shell,
-    Widget,
+    Alignment, Clipboard, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
 };
 
 /// A container that distributes its contents horizontally.
@@ -83,6 +83,16 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self.children.push(child.into());
         self
     }
+
+    /// Turns the [`Row`] into a [`Wrapping`] row, which flows its contents
+    /// onto new lines when they do not fit in the available width.
+    pub fn wrap(self) -> Wrapping<'a, Message, Renderer> {
+        Wrapping {
+            row: self,
+            line_spacing: None,
+            align_x: Alignment::Start,
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Default for Row<'a, Message, Renderer> {
@@ -245,3 +255,296 @@ where
         Self::new(row)
     }
 }
+
+/// A [`Row`] that flows its contents onto new lines when they do not fit in
+/// the available width.
+///
+/// Every line is as tall as its tallest content, which is aligned vertically
+/// inside of the line with [`Row::align_items`].
+#[allow(missing_debug_implementations)]
+pub struct Wrapping<'a, Message, Renderer = crate::Renderer> {
+    row: Row<'a, Message, Renderer>,
+    line_spacing: Option<f32>,
+    align_x: Alignment,
+}
+
+impl<'a, Message, Renderer> Wrapping<'a, Message, Renderer> {
+    /// Sets the vertical spacing _between_ lines.
+    ///
+    /// By default, it is the same as the spacing of the [`Row`].
+    pub fn line_spacing(mut self, amount: impl Into<Pixels>) -> Self {
+        self.line_spacing = Some(amount.into().0);
+        self
+    }
+
+    /// Sets the horizontal alignment of the lines of the [`Wrapping`] row.
+    pub fn align_x(mut self, align: Alignment) -> Self {
+        self.align_x = align;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrapping<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.row.children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.row.diff(tree);
+    }
+
+    fn width(&self) -> Length {
+        self.row.width
+    }
+
+    fn height(&self) -> Length {
+        self.row.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.row.width)
+            .height(self.row.height)
+            .pad(self.row.padding);
+
+        let child_limits = limits.loose();
+        let spacing = self.row.spacing;
+        let line_spacing = self.line_spacing.unwrap_or(spacing);
+        let max_width = limits.max().width;
+
+        let mut nodes: Vec<layout::Node> =
+            Vec::with_capacity(self.row.children.len());
+        let mut lines = Vec::new();
+
+        let mut line_start = 0;
+        let mut line_height = 0.0f32;
+        let mut x = 0.0;
+        let mut y = 0.0;
+
+        for (i, (child, tree)) in
+            self.row.children.iter().zip(&mut tree.children).enumerate()
+        {
+            let mut node =
+                child.as_widget().layout(tree, renderer, &child_limits);
+            let size = node.size();
+
+            if i > line_start && x + size.width > max_width {
+                lines.push((line_start..i, x - spacing, line_height));
+
+                y += line_height + line_spacing;
+                x = 0.0;
+                line_start = i;
+                line_height = 0.0;
+            }
+
+            node.move_to(Point::new(x, y));
+            nodes.push(node);
+
+            line_height = line_height.max(size.height);
+            x += size.width + spacing;
+        }
+
+        if line_start < nodes.len() {
+            lines.push((line_start..nodes.len(), x - spacing, line_height));
+            y += line_height;
+        }
+
+        let content_width =
+            lines.iter().map(|(_, width, _)| *width).fold(0.0, f32::max);
+
+        let size = limits.resolve(Size::new(content_width, y));
+
+        for (range, width, height) in lines {
+            // Lines are aligned as a whole
+            let offset = match self.align_x {
+                Alignment::Start => 0.0,
+                Alignment::Center => (size.width - width) / 2.0,
+                Alignment::End => size.width - width,
+            };
+
+            for node in &mut nodes[range] {
+                let bounds = node.bounds();
+
+                node.move_to(Point::new(
+                    bounds.x + self.row.padding.left + offset,
+                    bounds.y + self.row.padding.top,
+                ));
+                node.align(
+                    Alignment::Start,
+                    self.row.align_items,
+                    Size::new(0.0, height),
+                );
+            }
+        }
+
+        layout::Node::with_children(size.pad(self.row.padding), nodes)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.row.operate(tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.row.on_event(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.row
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.row
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.row.overlay(tree, layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Wrapping<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(row: Wrapping<'a, Message, Renderer>) -> Self {
+        Self::new(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Row;
+    use crate::core::layout;
+    use crate::core::renderer::Null;
+    use crate::core::widget::Tree;
+    use crate::core::{Alignment, Length, Rectangle, Size, Widget};
+    use crate::Space;
+
+    fn row<'a>(sizes: &[(f32, f32)]) -> Row<'a, (), Null> {
+        sizes.iter().fold(Row::new(), |row, (width, height)| {
+            row.push(Space::new(*width, *height))
+        })
+    }
+
+    fn layout(
+        widget: &dyn Widget<(), Null>,
+        tree: &mut Tree,
+        width: f32,
+    ) -> (Size, Vec<Rectangle>) {
+        let node = widget.layout(
+            tree,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+        );
+
+        (
+            node.size(),
+            node.children().iter().map(layout::Node::bounds).collect(),
+        )
+    }
+
+    #[test]
+    fn contents_are_broken_into_lines() {
+        let wrapping = row(&[(40.0, 10.0), (40.0, 20.0), (40.0, 10.0)])
+            .spacing(10)
+            .wrap();
+        let mut tree = Tree::new(&wrapping as &dyn Widget<(), Null>);
+
+        let (size, bounds) = layout(&wrapping, &mut tree, 100.0);
+
+        assert_eq!(size, Size::new(90.0, 40.0));
+        assert_eq!(
+            bounds,
+            vec![
+                Rectangle::new([0.0, 0.0].into(), Size::new(40.0, 10.0)),
+                Rectangle::new([50.0, 0.0].into(), Size::new(40.0, 20.0)),
+                Rectangle::new([0.0, 30.0].into(), Size::new(40.0, 10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn lines_are_spaced_and_padded() {
+        let wrapping = row(&[(60.0, 10.0), (60.0, 10.0)])
+            .spacing(10)
+            .padding(5)
+            .wrap()
+            .line_spacing(2);
+        let mut tree = Tree::new(&wrapping as &dyn Widget<(), Null>);
+
+        let (size, bounds) = layout(&wrapping, &mut tree, 100.0);
+
+        assert_eq!(size, Size::new(70.0, 32.0));
+        assert_eq!(bounds[0].position(), [5.0, 5.0].into());
+        assert_eq!(bounds[1].position(), [5.0, 17.0].into());
+    }
+
+    #[test]
+    fn lines_and_their_contents_are_aligned() {
+        let wrapping = row(&[(40.0, 10.0), (40.0, 20.0), (40.0, 10.0)])
+            .spacing(10)
+            .width(Length::Fill)
+            .align_items(Alignment::Center)
+            .wrap()
+            .align_x(Alignment::End);
+        let mut tree = Tree::new(&wrapping as &dyn Widget<(), Null>);
+
+        let (size, bounds) = layout(&wrapping, &mut tree, 100.0);
+
+        assert_eq!(size, Size::new(100.0, 40.0));
+        assert_eq!(bounds[0].position(), [10.0, 5.0].into());
+        assert_eq!(bounds[1].position(), [60.0, 0.0].into());
+        assert_eq!(bounds[2].position(), [60.0, 30.0].into());
+    }
+}
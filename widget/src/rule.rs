@@ -130,6 +130,7 @@ where
                 border_radius: style.radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                ..renderer::Quad::default()
             },
             style.color,
         );
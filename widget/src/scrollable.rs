@@ -1,14 +1,17 @@
 //! Navigate an endless amount of content with a scrollbar.
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::event::{self, Event};
 use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::operation::{self, Operation};
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Background, Clipboard, Color, Element, Layout, Length, Pixels, Point,
     Rectangle, Shell, Size, Vector, Widget,
@@ -525,6 +528,44 @@ pub fn update<Message>(
     let (mouse_over_y_scrollbar, mouse_over_x_scrollbar) =
         scrollbars.is_mouse_over(cursor);
 
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { .. })
+        | Event::Mouse(mouse::Event::CursorLeft)
+        | Event::Touch(touch::Event::FingerMoved { .. }) => {
+            let now = Instant::now();
+            let mut is_animating = false;
+
+            for (hover, is_hovered) in [
+                (&mut state.y_hover, mouse_over_y_scrollbar),
+                (&mut state.x_hover, mouse_over_x_scrollbar),
+            ] {
+                let target = if is_hovered { 1.0 } else { 0.0 };
+
+                if *hover.target() != target {
+                    hover.go(target, now);
+                    is_animating = true;
+                }
+            }
+
+            if is_animating {
+                // Draw samples the animations at `now`, so both must agree
+                state.now = now;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
+            state.now = now;
+
+            if state.y_hover.is_animating(now)
+                || state.x_hover.is_animating(now)
+            {
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+            }
+        }
+        _ => {}
+    }
+
     let event_status = {
         let cursor = match cursor_over_scrollable {
             Some(cursor_position)
@@ -540,18 +581,33 @@ pub fn update<Message>(
 
         let translation = state.translation(direction, bounds, content_bounds);
 
-        update_content(
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        let status = update_content(
             event.clone(),
             content,
             cursor,
             clipboard,
-            shell,
+            &mut local_shell,
             &Rectangle {
                 y: bounds.y + translation.y,
                 x: bounds.x + translation.x,
                 ..bounds
             },
-        )
+        );
+
+        // The content requests its input method where it is laid out, but
+        // it is displayed scrolled. The translated request comes first, so
+        // it takes precedence when merging.
+        shell.request_input_method(
+            &local_shell
+                .input_method()
+                .translate(Vector::new(-translation.x, -translation.y)),
+        );
+        shell.merge(local_shell, std::convert::identity);
+
+        status
     };
 
     if let event::Status::Captured = event_status {
@@ -916,6 +972,7 @@ pub fn draw<Renderer>(
                             border_radius: style.border_radius,
                             border_width: style.border_width,
                             border_color: style.border_color,
+                            ..renderer::Quad::default()
                         },
                         style
                             .background
@@ -936,6 +993,7 @@ pub fn draw<Renderer>(
                             border_radius: style.scroller.border_radius,
                             border_width: style.scroller.border_width,
                             border_color: style.scroller.border_color,
+                            ..renderer::Quad::default()
                         },
                         style.scroller.color,
                     );
@@ -954,7 +1012,12 @@ pub fn draw<Renderer>(
                     let style = if state.y_scroller_grabbed_at.is_some() {
                         theme.dragging(style)
                     } else if cursor_over_scrollable.is_some() {
-                        theme.hovered(style, mouse_over_y_scrollbar)
+                        hover_transition(
+                            state.y_hover,
+                            state.now,
+                            mouse_over_y_scrollbar,
+                            |is_hovered| theme.hovered(style, is_hovered),
+                        )
                     } else {
                         theme.active(style)
                     };
@@ -967,7 +1030,14 @@ pub fn draw<Renderer>(
                     let style = if state.x_scroller_grabbed_at.is_some() {
                         theme.dragging_horizontal(style)
                     } else if cursor_over_scrollable.is_some() {
-                        theme.hovered_horizontal(style, mouse_over_x_scrollbar)
+                        hover_transition(
+                            state.x_hover,
+                            state.now,
+                            mouse_over_x_scrollbar,
+                            |is_hovered| {
+                                theme.hovered_horizontal(style, is_hovered)
+                            },
+                        )
                     } else {
                         theme.active_horizontal(style)
                     };
@@ -990,6 +1060,21 @@ pub fn draw<Renderer>(
     }
 }
 
+/// Blends the hovered appearances of a scrollbar while its hover
+/// [`Animation`] is running.
+fn hover_transition(
+    hover: Animation<f32>,
+    now: Instant,
+    is_mouse_over_scrollbar: bool,
+    hovered: impl Fn(bool) -> Scrollbar,
+) -> Scrollbar {
+    if hover.is_animating(now) {
+        hovered(false).interpolate(&hovered(true), hover.value(now))
+    } else {
+        hovered(is_mouse_over_scrollbar)
+    }
+}
+
 fn notify_on_scroll<Message>(
     state: &mut State,
     on_scroll: &Option<Box<dyn Fn(Viewport) -> Message + '_>>,
@@ -1047,6 +1132,9 @@ pub struct State {
     x_scroller_grabbed_at: Option<f32>,
     keyboard_modifiers: keyboard::Modifiers,
     last_notified: Option<Viewport>,
+    y_hover: Animation<f32>,
+    x_hover: Animation<f32>,
+    now: Instant,
 }
 
 impl Default for State {
@@ -1059,6 +1147,9 @@ impl Default for State {
             x_scroller_grabbed_at: None,
             keyboard_modifiers: keyboard::Modifiers::default(),
             last_notified: None,
+            y_hover: Animation::new(0.0),
+            x_hover: Animation::new(0.0),
+            now: Instant::now(),
         }
     }
 }
@@ -0,0 +1,124 @@
+//! Select the items of a widget, like the rows of a [`Table`] or the nodes
+//! of a [`TreeView`].
+//!
+//! [`Table`]: crate::Table
+//! [`TreeView`]: crate::TreeView
+use crate::core::keyboard;
+
+/// The way the items of a widget can be selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SelectionMode {
+    /// A single item can be selected at a time.
+    #[default]
+    Single,
+    /// Many items can be selected by holding the command or shift modifiers.
+    Multiple,
+}
+
+/// Selects the item with the given index, producing the new selection out
+/// of the current one and the new anchor of range selections.
+///
+/// The `item` function produces the item with a given index.
+pub(crate) fn select<T>(
+    selected: &[T],
+    anchor: Option<usize>,
+    index: usize,
+    modifiers: keyboard::Modifiers,
+    mode: SelectionMode,
+    item: impl Fn(usize) -> T,
+) -> (Vec<T>, usize)
+where
+    T: Clone + PartialEq,
+{
+    if mode == SelectionMode::Single {
+        return (vec![item(index)], index);
+    }
+
+    match anchor {
+        Some(anchor) if modifiers.shift() => (
+            (anchor.min(index)..=anchor.max(index)).map(item).collect(),
+            anchor,
+        ),
+        _ if modifiers.command() => {
+            let item = item(index);
+
+            let selection = if selected.contains(&item) {
+                selected.iter().filter(|i| **i != item).cloned().collect()
+            } else {
+                selected.iter().cloned().chain(Some(item)).collect()
+            };
+
+            (selection, index)
+        }
+        _ => (vec![item(index)], index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select, SelectionMode};
+    use crate::core::keyboard::Modifiers;
+
+    #[test]
+    fn items_are_selected_with_modifiers() {
+        let multiple = SelectionMode::Multiple;
+        let index = |index| index;
+
+        assert_eq!(
+            select(
+                &[1],
+                Some(1),
+                3,
+                Modifiers::SHIFT,
+                SelectionMode::Single,
+                index
+            ),
+            (vec![3], 3)
+        );
+        assert_eq!(
+            select(&[1], Some(1), 4, Modifiers::SHIFT, multiple, index),
+            (vec![1, 2, 3, 4], 1)
+        );
+        assert_eq!(
+            select(&[1, 2], Some(2), 2, Modifiers::COMMAND, multiple, index),
+            (vec![1], 2)
+        );
+        assert_eq!(
+            select(&[1], Some(1), 5, Modifiers::COMMAND, multiple, index),
+            (vec![1, 5], 5)
+        );
+        assert_eq!(
+            select(&[1, 5], Some(5), 0, Modifiers::default(), multiple, index),
+            (vec![0], 0)
+        );
+    }
+
+    #[test]
+    fn items_are_produced_from_their_index() {
+        let keys = ["a", "b", "c", "d"];
+        let key = |index: usize| keys[index];
+
+        assert_eq!(
+            select(
+                &["a"],
+                Some(0),
+                2,
+                Modifiers::SHIFT,
+                SelectionMode::Multiple,
+                key
+            ),
+            (vec!["a", "b", "c"], 0)
+        );
+        assert_eq!(
+            select(
+                &["a", "c"],
+                Some(2),
+                0,
+                Modifiers::COMMAND,
+                SelectionMode::Multiple,
+                key
+            ),
+            (vec!["c"], 0)
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! A custom shader widget for wgpu applications.
+pub mod event;
+
+mod program;
+
+pub use event::Event;
+pub use program::Program;
+
+pub use crate::renderer::wgpu::primitive::pipeline::{Primitive, Storage};
+pub use crate::renderer::wgpu::wgpu;
+
+use crate::core;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{Clipboard, Element, Length, Rectangle, Shell, Size, Widget};
+use crate::renderer::wgpu::primitive::pipeline;
+
+use std::marker::PhantomData;
+
+/// A widget which can render custom shaders with Iced's `wgpu` backend.
+///
+/// Its [`Program`] draws a [`Primitive`], which can prepare GPU resources
+/// with the shared `wgpu` device and queue and then record draw calls in
+/// the render pass of the frame, clipped to the bounds of the widget.
+///
+/// Every [`Shader`] widget has its own [`Storage`], which is passed to
+/// [`Primitive::prepare`] and [`Primitive::render`] and persists across
+/// frames; it is the place to keep the GPU resources of the widget.
+#[derive(Debug)]
+pub struct Shader<Message, P: Program<Message>> {
+    width: Length,
+    height: Length,
+    program: P,
+    _message: PhantomData<Message>,
+}
+
+impl<Message, P: Program<Message>> Shader<Message, P> {
+    /// Creates a new [`Shader`] with the given [`Program`].
+    pub fn new(program: P) -> Self {
+        Self {
+            width: Length::Fixed(100.0),
+            height: Length::Fixed(100.0),
+            program,
+            _message: PhantomData,
+        }
+    }
+
+    /// Sets the width of the [`Shader`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Shader`].
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<P, Message, Renderer> Widget<Message, Renderer> for Shader<Message, P>
+where
+    P: Program<Message>,
+    Renderer: pipeline::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<P::State>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            id: pipeline::Id::unique(),
+            program: P::State::default(),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: core::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        let shader_event = match event {
+            core::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
+            core::Event::Touch(touch_event) => Some(Event::Touch(touch_event)),
+            core::Event::Keyboard(keyboard_event) => {
+                Some(Event::Keyboard(keyboard_event))
+            }
+            core::Event::Window(_, window::Event::RedrawRequested(instant)) => {
+                Some(Event::RedrawRequested(instant))
+            }
+            _ => None,
+        };
+
+        if let Some(shader_event) = shader_event {
+            let state = tree.state.downcast_mut::<State<P::State>>();
+
+            let (event_status, message) = self.program.update(
+                &mut state.program,
+                shader_event,
+                bounds,
+                cursor,
+            );
+
+            if let Some(message) = message {
+                shell.publish(message);
+            }
+
+            return event_status;
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State<P::State>>();
+
+        self.program
+            .mouse_interaction(&state.program, bounds, cursor)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        let state = tree.state.downcast_ref::<State<P::State>>();
+
+        renderer.draw_pipeline_primitive(
+            state.id,
+            bounds,
+            self.program.draw(&state.program, cursor, bounds),
+        );
+    }
+}
+
+/// The internal state of a [`Shader`] widget.
+struct State<T> {
+    id: pipeline::Id,
+    program: T,
+}
+
+impl<'a, P, Message, Renderer> From<Shader<Message, P>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + pipeline::Renderer,
+    P: Program<Message> + 'a,
+{
+    fn from(shader: Shader<Message, P>) -> Element<'a, Message, Renderer> {
+        Element::new(shader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Triangle;
+
+    impl Primitive for Triangle {
+        fn prepare(
+            &self,
+            _format: wgpu::TextureFormat,
+            _device: &wgpu::Device,
+            _queue: &wgpu::Queue,
+            _bounds: Rectangle,
+            _target_size: Size<u32>,
+            _scale_factor: f32,
+            _storage: &mut Storage,
+        ) {
+        }
+
+        fn render<'a>(
+            &'a self,
+            _storage: &'a Storage,
+            _bounds: Rectangle,
+            _clip_bounds: Rectangle<u32>,
+            _render_pass: &mut wgpu::RenderPass<'a>,
+        ) {
+        }
+    }
+
+    struct Scene;
+
+    impl Program<()> for Scene {
+        type State = ();
+        type Primitive = Triangle;
+
+        fn draw(
+            &self,
+            _state: &(),
+            _cursor: mouse::Cursor,
+            _bounds: Rectangle,
+        ) -> Triangle {
+            Triangle
+        }
+    }
+
+    #[test]
+    fn every_shader_has_its_own_storage() {
+        let shader = Shader::new(Scene);
+
+        let a = Widget::<(), crate::Renderer>::state(&shader);
+        let b = Widget::<(), crate::Renderer>::state(&shader);
+
+        assert_ne!(
+            a.downcast_ref::<State<()>>().id,
+            b.downcast_ref::<State<()>>().id
+        );
+    }
+}
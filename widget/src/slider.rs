@@ -1,12 +1,14 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
+use crate::core::accessibility;
 use crate::core::event::{self, Event};
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::touch;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
 use crate::core::{
     Clipboard, Color, Element, Layout, Length, Pixels, Point, Rectangle, Shell,
     Size, Widget,
@@ -179,6 +181,28 @@ where
         layout::Node::new(size)
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+
+        let id = operation.accessible(
+            None,
+            layout.bounds(),
+            &accessibility::Node::new(accessibility::Role::Slider)
+                .value(self.value.into().to_string())
+                .action(accessibility::Action::SetValue),
+        );
+
+        if let Some(id) = id {
+            state.accessibility = Some(id);
+        }
+    }
+
     fn on_event(
         &mut self,
         tree: &mut Tree,
@@ -335,6 +359,33 @@ where
                 return event::Status::Captured;
             }
         }
+        Event::Accessibility(accessibility::Event::SetValue(target, input))
+            if state.accessibility == Some(target) =>
+        {
+            let new_value = input
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|new_value| new_value.is_finite())
+                .map(|new_value| {
+                    new_value
+                        .max((*range.start()).into())
+                        .min((*range.end()).into())
+                })
+                .and_then(T::from_f64);
+
+            if let Some(new_value) = new_value {
+                shell.publish((on_change)(new_value));
+
+                if let Some(on_release) = on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                *value = new_value;
+            }
+
+            return event::Status::Captured;
+        }
         _ => {}
     }
 
@@ -405,6 +456,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            ..renderer::Quad::default()
         },
         style.rail.colors.0,
     );
@@ -420,6 +472,7 @@ pub fn draw<T, R>(
             border_radius: style.rail.border_radius,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            ..renderer::Quad::default()
         },
         style.rail.colors.1,
     );
@@ -435,6 +488,7 @@ pub fn draw<T, R>(
             border_radius: handle_border_radius,
             border_width: style.handle.border_width,
             border_color: style.handle.border_color,
+            ..renderer::Quad::default()
         },
         style.handle.color,
     );
@@ -462,6 +516,7 @@ pub fn mouse_interaction(
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    accessibility: Option<accessibility::Id>,
 }
 
 impl State {
@@ -0,0 +1,1021 @@
+//! Display data in rows and columns with a header.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Vector, Widget,
+};
+use crate::selection::select;
+use crate::Space;
+
+pub use crate::selection::SelectionMode;
+pub use crate::style::table::{Appearance, StyleSheet};
+
+/// A widget that displays rows of cells aligned in columns, under a header.
+///
+/// The header sticks to the top of the viewport when the [`Table`] is
+/// scrolled vertically inside of a [`Scrollable`]. Columns can be resized
+/// by dragging the dividers of the header, and rows can be selected.
+///
+/// The widths of the columns, their sort order and the selected rows are
+/// owned by the application; the [`Table`] only produces messages to
+/// change them.
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    columns: Vec<Column<'a, Message, Renderer>>,
+    cells: Vec<Element<'a, Message, Renderer>>,
+    selected: Vec<usize>,
+    selection_mode: SelectionMode,
+    padding: Padding,
+    on_sort: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_select: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default [`Padding`] of the cells of a [`Table`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5.0);
+
+    /// Creates a new [`Table`] with the given columns and no rows.
+    pub fn new(
+        columns: impl IntoIterator<Item = Column<'a, Message, Renderer>>,
+    ) -> Self {
+        Table {
+            columns: columns.into_iter().collect(),
+            cells: Vec::new(),
+            selected: Vec::new(),
+            selection_mode: SelectionMode::Single,
+            padding: Self::DEFAULT_PADDING,
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a row of cells to the [`Table`].
+    ///
+    /// Every cell is placed in the column with the same index. Extra cells
+    /// are discarded and missing ones are left empty.
+    pub fn push(
+        mut self,
+        row: impl IntoIterator<Item = Element<'a, Message, Renderer>>,
+    ) -> Self
+    where
+        Message: 'a,
+        Renderer: 'a,
+    {
+        let columns = self.columns.len();
+        let start = self.cells.len();
+
+        self.cells.extend(row.into_iter().take(columns));
+        self.cells.resize_with(start + columns, || {
+            Space::new(Length::Shrink, Length::Shrink).into()
+        });
+
+        self
+    }
+
+    /// Sets the indices of the selected rows of the [`Table`].
+    pub fn selected(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = rows.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`SelectionMode`] of the [`Table`].
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Sets the [`Padding`] of the cells of the [`Table`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a column
+    /// is clicked, usually to change the sort order of the rows.
+    pub fn on_sort(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resize interactions of the [`Table`], which will use the
+    /// provided function to produce messages.
+    ///
+    /// The `leeway` describes the amount of space around the right edge of
+    /// a column that can be used to grab it.
+    pub fn on_resize<F>(mut self, leeway: impl Into<Pixels>, f: F) -> Self
+    where
+        F: 'a + Fn(ResizeEvent) -> Message,
+    {
+        self.on_resize = Some((leeway.into().0, Box::new(f)));
+        self
+    }
+
+    /// Sets the message that will be produced when the selection of the
+    /// [`Table`] changes, containing the indices of the selected rows.
+    pub fn on_select(mut self, f: impl Fn(Vec<usize>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A column of a [`Table`].
+#[allow(missing_debug_implementations)]
+pub struct Column<'a, Message, Renderer = crate::Renderer> {
+    header: Element<'a, Message, Renderer>,
+    width: f32,
+    min_width: f32,
+    sort: Option<Order>,
+}
+
+impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
+    /// The default minimum width of a [`Column`].
+    pub const DEFAULT_MIN_WIDTH: f32 = 20.0;
+
+    /// Creates a new [`Column`] with the given header and width.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Renderer>>,
+        width: impl Into<Pixels>,
+    ) -> Self {
+        Column {
+            header: header.into(),
+            width: width.into().0,
+            min_width: Self::DEFAULT_MIN_WIDTH,
+            sort: None,
+        }
+    }
+
+    /// Sets the minimum width of the [`Column`] when resizing it.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = min_width.into().0;
+        self
+    }
+
+    /// Sets the sort [`Order`] of the [`Column`], which displays an
+    /// indicator in its header.
+    pub fn sort(mut self, order: Option<Order>) -> Self {
+        self.sort = order;
+        self
+    }
+
+    fn width(&self) -> f32 {
+        self.width.max(self.min_width)
+    }
+}
+
+/// The sort order of a [`Column`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// The rows are sorted from the smallest to the largest value.
+    Ascending,
+    /// The rows are sorted from the largest to the smallest value.
+    Descending,
+}
+
+/// An event produced during a resize interaction of a [`Table`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeEvent {
+    /// The index of the [`Column`] that is being resized.
+    pub column: usize,
+
+    /// The new width of the [`Column`].
+    pub width: f32,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    drag: Option<Drag>,
+    anchor: Option<usize>,
+    modifiers: keyboard::Modifiers,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.children().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.children().collect::<Vec<_>>());
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let columns = self.columns.len();
+
+        if columns == 0 {
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let widths: Vec<f32> = self.columns.iter().map(Column::width).collect();
+        let (headers, cells) = tree.children.split_at_mut(columns);
+
+        // Sorted columns leave room for their indicator
+        let indicator = f32::from(renderer.default_size());
+
+        let (mut nodes, mut height) = layout_row(
+            renderer,
+            &widths,
+            self.padding,
+            0.0,
+            self.columns.iter().zip(headers).map(|(column, tree)| {
+                let reserved = if column.sort.is_some() {
+                    indicator
+                } else {
+                    0.0
+                };
+
+                (&column.header, tree, reserved)
+            }),
+        );
+
+        for (row, trees) in
+            self.cells.chunks(columns).zip(cells.chunks_mut(columns))
+        {
+            let (row, row_height) = layout_row(
+                renderer,
+                &widths,
+                self.padding,
+                height,
+                row.iter().zip(trees).map(|(cell, tree)| (cell, tree, 0.0)),
+            );
+
+            nodes.extend(row);
+            height += row_height;
+        }
+
+        layout::Node::with_children(
+            Size::new(widths.iter().sum(), height),
+            nodes,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((child, state), layout)| {
+                    child.as_widget().operate(
+                        state,
+                        content(layout),
+                        renderer,
+                        operation,
+                    );
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let columns = self.columns.len();
+
+        if columns == 0 {
+            return event::Status::Ignored;
+        }
+
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State>();
+        let header = Header::new(layout, columns, cursor, viewport);
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(drag), Some((_, on_resize))) =
+                    (state.drag, &self.on_resize)
+                {
+                    if let Some(position) = cursor.position() {
+                        let width = (drag.width + position.x - drag.origin)
+                            .max(self.columns[drag.column].min_width);
+
+                        shell.publish(on_resize(ResizeEvent {
+                            column: drag.column,
+                            width,
+                        }));
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if state.drag.is_some() =>
+            {
+                state.drag = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some((leeway, _)) = &self.on_resize {
+                    if let Some((column, position)) =
+                        header.divider(layout, cursor, *leeway)
+                    {
+                        state.drag = Some(Drag {
+                            column,
+                            origin: position.x,
+                            width: self.columns[column].width(),
+                        });
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let (headers, cells) = children.split_at_mut(columns);
+
+        let header_status = self
+            .columns
+            .iter_mut()
+            .zip(headers)
+            .zip(layout.children())
+            .map(|((column, tree), layout)| {
+                column.header.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    content(layout),
+                    header.cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    &header.viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if header_status == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        if is_click(&event) {
+            if let (Some(on_sort), Some(position)) =
+                (&self.on_sort, header.cursor.position())
+            {
+                if let Some(column) = layout
+                    .children()
+                    .take(columns)
+                    .position(|cell| cell.bounds().contains(position))
+                {
+                    shell.publish(on_sort(column));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        let cell_cursor = header.body_cursor(cursor);
+
+        let status = self
+            .cells
+            .iter_mut()
+            .zip(cells)
+            .zip(layout.children().skip(columns))
+            .map(|((cell, tree), layout)| {
+                cell.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    content(layout),
+                    cell_cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Ignored && is_click(&event) {
+            if let (Some(on_select), Some(position)) =
+                (&self.on_select, cell_cursor.position())
+            {
+                if let Some(index) = rows(layout, columns)
+                    .position(|bounds| bounds.contains(position))
+                {
+                    let (selected, anchor) = select(
+                        &self.selected,
+                        state.anchor,
+                        index,
+                        state.modifiers,
+                        self.selection_mode,
+                        |index| index,
+                    );
+
+                    state.anchor = Some(anchor);
+                    shell.publish(on_select(selected));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let columns = self.columns.len();
+
+        if columns == 0 {
+            return mouse::Interaction::default();
+        }
+
+        let state = tree.state.downcast_ref::<State>();
+        let header = Header::new(layout, columns, cursor, viewport);
+
+        if let Some((leeway, _)) = &self.on_resize {
+            if state.drag.is_some()
+                || header.divider(layout, cursor, *leeway).is_some()
+            {
+                return mouse::Interaction::ResizingHorizontally;
+            }
+        }
+
+        let (headers, cells) = tree.children.split_at(columns);
+        let cell_cursor = header.body_cursor(cursor);
+
+        let header_interaction = self
+            .columns
+            .iter()
+            .zip(headers)
+            .zip(layout.children())
+            .map(|((column, tree), layout)| {
+                column.header.as_widget().mouse_interaction(
+                    tree,
+                    content(layout),
+                    header.cursor,
+                    &header.viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        let interaction = self
+            .cells
+            .iter()
+            .zip(cells)
+            .zip(layout.children().skip(columns))
+            .map(|((cell, tree), layout)| {
+                cell.as_widget().mouse_interaction(
+                    tree,
+                    content(layout),
+                    cell_cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if header_interaction == mouse::Interaction::default()
+            && interaction == mouse::Interaction::default()
+            && ((self.on_sort.is_some() && header.cursor.position().is_some())
+                || (self.on_select.is_some()
+                    && cell_cursor.is_over(layout.bounds())))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        header_interaction.max(interaction)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let columns = self.columns.len();
+
+        if columns == 0 {
+            return;
+        }
+
+        let appearance = theme.appearance(&self.style);
+        let header = Header::new(layout, columns, cursor, viewport);
+        let cell_cursor = header.body_cursor(cursor);
+        let (headers, cells) = tree.children.split_at(columns);
+
+        for (index, bounds) in rows(layout, columns).enumerate() {
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let background = if self.selected.contains(&index) {
+                Some(appearance.selected_row_background)
+            } else if index % 2 == 1 {
+                appearance
+                    .alternate_row_background
+                    .or(appearance.row_background)
+            } else {
+                appearance.row_background
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+
+        for (index, ((cell, tree), layout)) in self
+            .cells
+            .iter()
+            .zip(cells)
+            .zip(layout.children().skip(columns))
+            .enumerate()
+        {
+            if !layout.bounds().intersects(viewport) {
+                continue;
+            }
+
+            let style = if self.selected.contains(&(index / columns)) {
+                renderer::Style {
+                    text_color: appearance.selected_text_color,
+                }
+            } else {
+                *style
+            };
+
+            cell.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &style,
+                content(layout),
+                cell_cursor,
+                viewport,
+            );
+        }
+
+        let Some(clip_bounds) = header.bounds.intersection(viewport) else {
+            return;
+        };
+
+        // The header is drawn in its own layer, on top of the rows
+        renderer.with_layer(clip_bounds, |renderer| {
+            renderer.with_translation(
+                Vector::new(0.0, header.offset),
+                |renderer| {
+                    let bounds =
+                        header.bounds - Vector::new(0.0, header.offset);
+
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            ..renderer::Quad::default()
+                        },
+                        appearance.header_background,
+                    );
+
+                    let style = renderer::Style {
+                        text_color: appearance.header_text_color,
+                    };
+
+                    for ((column, tree), layout) in
+                        self.columns.iter().zip(headers).zip(layout.children())
+                    {
+                        column.header.as_widget().draw(
+                            tree,
+                            renderer,
+                            theme,
+                            &style,
+                            content(layout),
+                            header.cursor,
+                            &header.viewport,
+                        );
+
+                        let cell = layout.bounds();
+
+                        if let Some(order) = column.sort {
+                            draw_indicator(
+                                renderer,
+                                order,
+                                Point::new(
+                                    cell.x + cell.width - self.padding.right,
+                                    cell.center_y(),
+                                ),
+                                appearance.header_text_color,
+                            );
+                        }
+
+                        fill_line(
+                            renderer,
+                            Rectangle {
+                                x: cell.x + cell.width
+                                    - appearance.divider_width,
+                                width: appearance.divider_width,
+                                ..cell
+                            },
+                            appearance.divider_color,
+                        );
+                    }
+
+                    fill_line(
+                        renderer,
+                        Rectangle {
+                            y: bounds.y + bounds.height
+                                - appearance.divider_width,
+                            height: appearance.divider_width,
+                            ..bounds
+                        },
+                        appearance.divider_color,
+                    );
+                },
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .columns
+            .iter_mut()
+            .map(|column| &mut column.header)
+            .chain(&mut self.cells)
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((child, state), layout)| {
+                child
+                    .as_widget_mut()
+                    .overlay(state, content(layout), renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn children(
+        &self,
+    ) -> impl Iterator<Item = &Element<'a, Message, Renderer>> {
+        self.columns
+            .iter()
+            .map(|column| &column.header)
+            .chain(&self.cells)
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Self::new(table)
+    }
+}
+
+/// The placement of the header of a [`Table`], which sticks to the top of
+/// the viewport.
+struct Header {
+    columns: usize,
+    bounds: Rectangle,
+    offset: f32,
+    cursor: mouse::Cursor,
+    viewport: Rectangle,
+}
+
+impl Header {
+    fn new(
+        layout: Layout<'_>,
+        columns: usize,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Self {
+        let table = layout.bounds();
+        let height = layout
+            .children()
+            .next()
+            .map_or(0.0, |cell| cell.bounds().height);
+
+        let offset = (viewport.y - table.y).min(table.height - height).max(0.0);
+
+        let bounds = Rectangle {
+            y: table.y + offset,
+            height,
+            ..table
+        };
+
+        let translation = Vector::new(0.0, offset);
+
+        // The cursor of the header is relative to its original position
+        let cursor = match cursor.position() {
+            Some(position) if bounds.contains(position) => {
+                mouse::Cursor::Available(position - translation)
+            }
+            _ => mouse::Cursor::Unavailable,
+        };
+
+        Self {
+            columns,
+            bounds,
+            offset,
+            cursor,
+            viewport: *viewport - translation,
+        }
+    }
+
+    /// Returns the cursor of the rows, which are covered by the header.
+    fn body_cursor(&self, cursor: mouse::Cursor) -> mouse::Cursor {
+        if cursor.is_over(self.bounds) {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        }
+    }
+
+    /// Returns the index of the column whose right edge is under the cursor,
+    /// if any.
+    fn divider(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        leeway: f32,
+    ) -> Option<(usize, Point)> {
+        let position = cursor.position()?;
+
+        if position.y < self.bounds.y
+            || position.y > self.bounds.y + self.bounds.height
+        {
+            return None;
+        }
+
+        layout
+            .children()
+            .take(self.columns)
+            .enumerate()
+            .find(|(_, cell)| {
+                let right = cell.bounds().x + cell.bounds().width;
+
+                (position.x - right).abs() <= leeway / 2.0
+            })
+            .map(|(column, _)| (column, position))
+    }
+}
+
+/// Lays out a row of cells with the given widths, starting at the given
+/// vertical offset.
+///
+/// Every cell is wrapped in a node that fills its slot of the row.
+fn layout_row<'a, 'b, Message, Renderer>(
+    renderer: &Renderer,
+    widths: &[f32],
+    padding: Padding,
+    y: f32,
+    cells: impl Iterator<
+        Item = (&'b Element<'a, Message, Renderer>, &'b mut Tree, f32),
+    >,
+) -> (Vec<layout::Node>, f32)
+where
+    'a: 'b,
+    Message: 'b,
+    Renderer: crate::core::Renderer + 'b,
+{
+    let contents: Vec<_> = cells
+        .zip(widths)
+        .map(|((cell, tree, reserved), width)| {
+            let limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new(
+                    (width - padding.horizontal() - reserved).max(0.0),
+                    f32::INFINITY,
+                ),
+            );
+
+            cell.as_widget().layout(tree, renderer, &limits)
+        })
+        .collect();
+
+    let height = contents
+        .iter()
+        .map(|content| content.size().height)
+        .fold(0.0, f32::max)
+        + padding.vertical();
+
+    let mut x = 0.0;
+
+    let nodes = contents
+        .into_iter()
+        .zip(widths)
+        .map(|(mut content, width)| {
+            content.move_to(Point::new(padding.left, padding.top));
+            content.align(
+                Alignment::Start,
+                Alignment::Center,
+                Size::new(
+                    width - padding.horizontal(),
+                    height - padding.vertical(),
+                ),
+            );
+
+            let mut node = layout::Node::with_children(
+                Size::new(*width, height),
+                vec![content],
+            );
+
+            node.move_to(Point::new(x, y));
+            x += width;
+
+            node
+        })
+        .collect();
+
+    (nodes, height)
+}
+
+/// Returns the layout of the contents of a cell.
+fn content(cell: Layout<'_>) -> Layout<'_> {
+    cell.children().next().expect("Table cell content")
+}
+
+/// Returns the bounds of every row of a [`Table`].
+fn rows(
+    layout: Layout<'_>,
+    columns: usize,
+) -> impl Iterator<Item = Rectangle> + '_ {
+    let table = layout.bounds();
+
+    layout
+        .children()
+        .skip(columns)
+        .step_by(columns)
+        .map(move |cell| {
+            let cell = cell.bounds();
+
+            Rectangle {
+                y: cell.y,
+                height: cell.height,
+                ..table
+            }
+        })
+}
+
+fn is_click(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+    )
+}
+
+fn draw_indicator<Renderer>(
+    renderer: &mut Renderer,
+    order: Order,
+    position: Point,
+    color: Color,
+) where
+    Renderer: text::Renderer,
+{
+    let size = renderer.default_size();
+    let line_height = text::LineHeight::default();
+
+    renderer.fill_text(
+        Text {
+            content: match order {
+                Order::Ascending => "▲",
+                Order::Descending => "▼",
+            },
+            size: Pixels(size.0 * 0.7),
+            line_height,
+            font: renderer.default_font(),
+            bounds: Size::new(
+                f32::from(size),
+                f32::from(line_height.to_absolute(size)),
+            ),
+            horizontal_alignment: alignment::Horizontal::Right,
+            vertical_alignment: alignment::Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        },
+        position,
+        color,
+    );
+}
+
+fn fill_line<Renderer>(renderer: &mut Renderer, bounds: Rectangle, color: Color)
+where
+    Renderer: crate::core::Renderer,
+{
+    if bounds.width > 0.0 && bounds.height > 0.0 {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..renderer::Quad::default()
+            },
+            Background::Color(color),
+        );
+    }
+}
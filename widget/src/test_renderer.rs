@@ -0,0 +1,25 @@
+//! A renderer that lays out text without drawing anything, for tests.
+use crate::core::{Font, Pixels};
+use crate::graphics;
+use crate::style::Theme;
+
+use std::borrow::Cow;
+
+/// A backend that discards every primitive.
+pub struct Backend;
+
+impl graphics::backend::Backend for Backend {
+    type Primitive = ();
+}
+
+impl graphics::backend::Text for Backend {
+    fn load_font(&mut self, _font: Cow<'static, [u8]>) {}
+}
+
+/// A renderer with the built-in [`Theme`] that draws nothing.
+pub type Renderer = graphics::Renderer<Backend, Theme>;
+
+/// Creates a new [`Renderer`] with the default [`Font`].
+pub fn renderer() -> Renderer {
+    Renderer::new(Backend, Font::default(), Pixels(16.0))
+}
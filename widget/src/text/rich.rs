@@ -0,0 +1,542 @@
+use crate::core::alignment;
+use crate::core::event;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph, Span};
+use crate::core::widget::text::{LineHeight, Shaping, StyleSheet};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::widget::Operation;
+use crate::core::{
+    Clipboard, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell,
+    Widget,
+};
+
+/// A paragraph of rich text.
+///
+/// Every [`Span`] can have its own font, color and decorations. Clicking a
+/// [`Span`] with a link produces the link as a message.
+#[allow(missing_debug_implementations)]
+pub struct Rich<'a, Link, Renderer = crate::Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    spans: Vec<Span<'a, Link, Renderer::Font>>,
+    size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    font: Option<Renderer::Font>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    shaping: Shaping,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Link, Renderer> Rich<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Rich`] text with the given spans.
+    pub fn new(
+        spans: impl IntoIterator<Item = Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            size: None,
+            line_height: LineHeight::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            font: None,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            shaping: Shaping::Basic,
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Span`] to the [`Rich`] text.
+    pub fn push(
+        mut self,
+        span: impl Into<Span<'a, Link, Renderer::Font>>,
+    ) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Sets the size of the [`Rich`] text.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Rich`] text.
+    pub fn line_height(mut self, line_height: impl Into<LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the default font of the [`Rich`] text.
+    ///
+    /// It is used by the spans without a font.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Rich`] text.
+    ///
+    /// Its color is used by the spans without a color.
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the [`Rich`] text boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Rich`] text boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`alignment::Horizontal`] of the [`Rich`] text.
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: alignment::Horizontal,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`alignment::Vertical`] of the [`Rich`] text.
+    pub fn vertical_alignment(
+        mut self,
+        alignment: alignment::Vertical,
+    ) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`Shaping`] strategy of the [`Rich`] text.
+    pub fn shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+}
+
+struct State<P: Paragraph> {
+    spans: Vec<Span<'static, (), P::Font>>,
+    span_pressed: Option<usize>,
+    paragraph: P,
+}
+
+impl<'a, Link, Renderer> Widget<Link, Renderer> for Rich<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph> {
+            spans: Vec::new(),
+            span_pressed: None,
+            paragraph: Renderer::Paragraph::default(),
+        })
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        let limits = limits.width(self.width).height(self.height);
+        let bounds = limits.max();
+
+        let text = text::Text {
+            content: "",
+            bounds,
+            size: self.size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+            shaping: self.shaping,
+        };
+
+        let is_same_shape = state.spans.len() == self.spans.len()
+            && state.spans.iter().zip(&self.spans).all(|(old, new)| {
+                old.text == new.text
+                    && old.font == new.font
+                    && old.color == new.color
+                    && old.underline == new.underline
+                    && old.strikethrough == new.strikethrough
+            })
+            && text::compare(
+                &state.paragraph,
+                text::Text {
+                    content: state.paragraph.content(),
+                    ..text
+                },
+            ) != text::Difference::Shape;
+
+        if !is_same_shape {
+            state.paragraph = renderer.create_rich_paragraph(&self.spans, text);
+            state.spans = self.spans.iter().map(Span::without_link).collect();
+        } else if state.paragraph.bounds() != bounds {
+            renderer.resize_paragraph(&mut state.paragraph, bounds);
+        }
+
+        let size = limits.resolve(state.paragraph.min_bounds());
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Link>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(span) = self.link_at(state, layout, cursor) {
+                    state.span_pressed = Some(span);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(span_pressed) = state.span_pressed.take() {
+                    if self.link_at(state, layout, cursor) == Some(span_pressed)
+                    {
+                        if let Some(link) =
+                            self.spans[span_pressed].link.clone()
+                        {
+                            shell.publish(link);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if self.link_at(state, layout, cursor).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let paragraph = &state.paragraph;
+
+        let color = theme
+            .appearance(self.style.clone())
+            .color
+            .unwrap_or(style.text_color);
+
+        let bounds = layout.bounds();
+        let origin = origin(bounds, paragraph);
+
+        let size = f32::from(paragraph.text_size());
+        let line_height = f32::from(
+            paragraph.line_height().to_absolute(paragraph.text_size()),
+        );
+        let thickness = (size / 14.0).max(1.0);
+
+        for (index, span) in self.spans.iter().enumerate() {
+            if !span.underline && !span.strikethrough {
+                continue;
+            }
+
+            let color = span.color.unwrap_or(color);
+
+            for line in paragraph.span_bounds(index) {
+                let line = line + (origin - Point::ORIGIN);
+
+                let mut decorate = |y: f32| {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: line.x,
+                                y: y - thickness / 2.0,
+                                width: line.width,
+                                height: thickness,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        color,
+                    );
+                };
+
+                if span.underline {
+                    decorate(line.y + (line_height + size) / 2.0);
+                }
+
+                if span.strikethrough {
+                    decorate(line.y + line_height / 2.0 + size * 0.1);
+                }
+            }
+        }
+
+        let x = match paragraph.horizontal_alignment() {
+            alignment::Horizontal::Left => bounds.x,
+            alignment::Horizontal::Center => bounds.center_x(),
+            alignment::Horizontal::Right => bounds.x + bounds.width,
+        };
+
+        let y = match paragraph.vertical_alignment() {
+            alignment::Vertical::Top => bounds.y,
+            alignment::Vertical::Center => bounds.center_y(),
+            alignment::Vertical::Bottom => bounds.y + bounds.height,
+        };
+
+        renderer.fill_paragraph(paragraph, Point::new(x, y), color);
+    }
+
+    fn operate(
+        &self,
+        _tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Link>,
+    ) {
+        let content: String =
+            self.spans.iter().map(|span| span.text.as_ref()).collect();
+
+        operation.text(None, layout.bounds(), &content);
+    }
+}
+
+impl<'a, Link, Renderer> Rich<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn link_at(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+        let position = cursor.position_over(bounds)?;
+        let origin = origin(bounds, &state.paragraph);
+
+        let span = state
+            .paragraph
+            .hit_span(Point::ORIGIN + (position - origin))?;
+
+        self.spans
+            .get(span)
+            .and_then(|span| span.link.as_ref())
+            .map(|_| span)
+    }
+}
+
+/// Returns the top left corner of the contents of the [`Paragraph`] when
+/// drawn inside the given bounds.
+fn origin(bounds: Rectangle, paragraph: &impl Paragraph) -> Point {
+    let min_bounds = paragraph.min_bounds();
+
+    let x = match paragraph.horizontal_alignment() {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.center_x() - min_bounds.width / 2.0
+        }
+        alignment::Horizontal::Right => {
+            bounds.x + bounds.width - min_bounds.width
+        }
+    };
+
+    let y = match paragraph.vertical_alignment() {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.center_y() - min_bounds.height / 2.0
+        }
+        alignment::Vertical::Bottom => {
+            bounds.y + bounds.height - min_bounds.height
+        }
+    };
+
+    Point::new(x, y)
+}
+
+impl<'a, Link, Renderer> From<Rich<'a, Link, Renderer>>
+    for Element<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(rich: Rich<'a, Link, Renderer>) -> Element<'a, Link, Renderer> {
+        Element::new(rich)
+    }
+}
+
+impl<'a, Link, Renderer> FromIterator<Span<'a, Link, Renderer::Font>>
+    for Rich<'a, Link, Renderer>
+where
+    Link: Clone + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn from_iter<T: IntoIterator<Item = Span<'a, Link, Renderer::Font>>>(
+        spans: T,
+    ) -> Self {
+        Self::new(spans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Size;
+    use crate::graphics;
+    use crate::test_renderer::{renderer, Renderer};
+
+    fn layout(
+        rich: &Rich<'_, &'static str, Renderer>,
+        renderer: &Renderer,
+    ) -> (Tree, layout::Node) {
+        let mut tree = Tree::new(rich as &dyn Widget<_, _>);
+        let node = rich.layout(
+            &mut tree,
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(1000.0, 1000.0)),
+        );
+
+        (tree, node)
+    }
+
+    fn paragraph(tree: &Tree) -> &graphics::text::Paragraph {
+        &tree
+            .state
+            .downcast_ref::<State<graphics::text::Paragraph>>()
+            .paragraph
+    }
+
+    #[test]
+    fn spans_are_hit_within_their_bounds() {
+        let renderer = renderer();
+        let rich = Rich::new([
+            Span::new("Hello, "),
+            Span::new("world").link("world"),
+            Span::new("!"),
+        ]);
+
+        let (tree, _) = layout(&rich, &renderer);
+        let paragraph = paragraph(&tree);
+
+        let hello = paragraph.span_bounds(0);
+        let world = paragraph.span_bounds(1);
+
+        assert_eq!(hello.len(), 1);
+        assert_eq!(world.len(), 1);
+        assert!(hello[0].width > 0.0);
+        assert!(world[0].x >= hello[0].x + hello[0].width);
+
+        assert_eq!(paragraph.hit_span(hello[0].center()), Some(0));
+        assert_eq!(paragraph.hit_span(world[0].center()), Some(1));
+        assert_eq!(paragraph.hit_span(Point::new(-10.0, -10.0)), None);
+        assert!(paragraph.span_bounds(3).is_empty());
+    }
+
+    #[test]
+    fn clicking_a_link_publishes_it() {
+        let renderer = renderer();
+        let mut rich =
+            Rich::new([Span::new("Hello, "), Span::new("world").link("world")]);
+
+        let (mut tree, node) = layout(&rich, &renderer);
+        let hello = paragraph(&tree).span_bounds(0)[0];
+        let world = paragraph(&tree).span_bounds(1)[0];
+
+        let mut click = |press: Point, release: Point| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            for (event, position) in [
+                (mouse::Event::ButtonPressed(mouse::Button::Left), press),
+                (mouse::Event::ButtonReleased(mouse::Button::Left), release),
+            ] {
+                let _ = rich.on_event(
+                    &mut tree,
+                    Event::Mouse(event),
+                    Layout::new(&node),
+                    mouse::Cursor::Available(position),
+                    &renderer,
+                    &mut crate::core::clipboard::Null,
+                    &mut shell,
+                    &Rectangle::with_size(Size::INFINITY),
+                );
+            }
+
+            messages
+        };
+
+        assert_eq!(click(world.center(), world.center()), vec!["world"]);
+        assert!(click(world.center(), hello.center()).is_empty());
+        assert!(click(hello.center(), hello.center()).is_empty());
+    }
+}
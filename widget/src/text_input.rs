@@ -11,8 +11,10 @@ pub use value::Value;
 
 use editor::Editor;
 
+use crate::core::accessibility;
 use crate::core::alignment;
 use crate::core::event::{self, Event};
+use crate::core::input_method::{self, InputMethod};
 use crate::core::keyboard;
 use crate::core::layout;
 use crate::core::mouse::{self, click};
@@ -314,14 +316,58 @@ where
     fn operate(
         &self,
         tree: &mut Tree,
-        _layout: Layout<'_>,
+        layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn Operation<Message>,
     ) {
         let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
-        operation.focusable(state, self.id.as_ref().map(|id| &id.0));
+        let mut node = accessibility::Node::new(accessibility::Role::TextInput)
+            .label(self.placeholder.as_str())
+            .value(if self.is_secure {
+                self.value.secure().to_string()
+            } else {
+                self.value.to_string()
+            })
+            .focused(state.is_focused())
+            .disabled(self.on_input.is_none());
+
+        if self.on_input.is_some() {
+            node = node
+                .action(accessibility::Action::Focus)
+                .action(accessibility::Action::Click)
+                .action(accessibility::Action::SetValue);
+        }
+
+        if let Some(id) = operation.accessible(
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+            &node,
+        ) {
+            state.accessibility = Some(id);
+        }
+
+        operation.focusable(
+            state,
+            self.id.as_ref().map(|id| &id.0),
+            layout.bounds(),
+        );
         operation.text_input(state, self.id.as_ref().map(|id| &id.0));
+
+        // The placeholder is not the text of the input
+        if operation.wants_text() && !self.value.is_empty() {
+            let text = if self.is_secure {
+                self.value.secure().to_string()
+            } else {
+                self.value.to_string()
+            };
+
+            operation.text(
+                self.id.as_ref().map(|id| &id.0),
+                layout.bounds(),
+                &text,
+            );
+        }
     }
 
     fn on_event(
@@ -779,6 +825,86 @@ where
                 }
             }
         }
+        Event::Accessibility(event) => {
+            let state = state();
+
+            if state.accessibility != Some(event.target()) {
+                if matches!(
+                    event,
+                    accessibility::Event::Focus(_)
+                        | accessibility::Event::Click(_)
+                ) {
+                    state.unfocus();
+                }
+
+                return event::Status::Ignored;
+            }
+
+            let Some(on_input) = on_input else {
+                return event::Status::Ignored;
+            };
+
+            if !state.is_focused() {
+                state.focus();
+            }
+
+            if let accessibility::Event::SetValue(_, content) = event {
+                *value = Value::new(&content);
+                state.move_cursor_to_end();
+
+                let message = (on_input)(content);
+                shell.publish(message);
+
+                update_cache(state, value);
+            }
+
+            return event::Status::Captured;
+        }
+        Event::InputMethod(event) => {
+            let state = state();
+
+            if let Some(focus) = &mut state.is_focused {
+                let Some(on_input) = on_input else {
+                    return event::Status::Ignored;
+                };
+
+                focus.updated_at = Instant::now();
+
+                match event {
+                    input_method::Event::Opened
+                    | input_method::Event::Closed => {
+                        state.preedit = None;
+                    }
+                    input_method::Event::Preedit(content, _) => {
+                        state.preedit = (!content.is_empty()).then(|| {
+                            create_paragraph(
+                                renderer,
+                                layout,
+                                &content,
+                                font,
+                                size,
+                                line_height,
+                            )
+                        });
+                    }
+                    input_method::Event::Commit(content) => {
+                        state.preedit = None;
+
+                        let mut editor = Editor::new(value, &mut state.cursor);
+                        editor.paste(Value::new(&content));
+
+                        let message = (on_input)(editor.contents());
+                        shell.publish(message);
+
+                        update_cache(state, value);
+                    }
+                }
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+        }
         Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) => {
             let state = state();
 
@@ -1003,14 +1129,14 @@ where
 
             state.keyboard_modifiers = modifiers;
         }
-        Event::Window(window::Event::Unfocused) => {
+        Event::Window(_, window::Event::Unfocused) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
                 focus.is_window_focused = false;
             }
         }
-        Event::Window(window::Event::Focused) => {
+        Event::Window(_, window::Event::Focused) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
@@ -1020,7 +1146,7 @@ where
                 shell.request_redraw(window::RedrawRequest::NextFrame);
             }
         }
-        Event::Window(window::Event::RedrawRequested(now)) => {
+        Event::Window(_, window::Event::RedrawRequested(now)) => {
             let state = state();
 
             if let Some(focus) = &mut state.is_focused {
@@ -1034,6 +1160,12 @@ where
                     shell.request_redraw(window::RedrawRequest::At(
                         now + Duration::from_millis(millis_until_redraw as u64),
                     ));
+
+                    if on_input.is_some() {
+                        shell.request_input_method(&input_method(
+                            state, layout, value, is_secure,
+                        ));
+                    }
                 }
             }
         }
@@ -1088,6 +1220,7 @@ pub fn draw<Renderer>(
             border_radius: appearance.border_radius,
             border_width: appearance.border_width,
             border_color: appearance.border_color,
+            ..renderer::Quad::default()
         },
         appearance.background,
     );
@@ -1136,6 +1269,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            ..renderer::Quad::default()
                         },
                         theme.value_color(style),
                     ))
@@ -1177,6 +1311,7 @@ pub fn draw<Renderer>(
                             border_radius: 0.0.into(),
                             border_width: 0.0,
                             border_color: Color::TRANSPARENT,
+                            ..renderer::Quad::default()
                         },
                         theme.selection_color(style),
                     )),
@@ -1194,6 +1329,26 @@ pub fn draw<Renderer>(
 
     let text_width = state.value.min_width();
 
+    let preedit =
+        state
+            .preedit
+            .as_ref()
+            .filter(|_| state.is_focused())
+            .map(|preedit| {
+                let position = match state.cursor.state(value) {
+                    cursor::State::Index(position) => position,
+                    cursor::State::Selection { start, end } => start.min(end),
+                };
+
+                let (x, _) = measure_cursor_and_scroll_offset(
+                    &state.value,
+                    text_bounds,
+                    position,
+                );
+
+                (preedit, x)
+            });
+
     let render = |renderer: &mut Renderer| {
         if let Some((cursor, color)) = cursor {
             renderer.fill_quad(cursor, color);
@@ -1216,6 +1371,41 @@ pub fn draw<Renderer>(
                 theme.value_color(style)
             },
         );
+
+        if let Some((preedit, x)) = preedit {
+            let bounds = Rectangle {
+                x: text_bounds.x + x,
+                y: text_bounds.y,
+                width: preedit.min_width(),
+                height: text_bounds.height,
+            };
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                appearance.background,
+            );
+
+            renderer.fill_paragraph(
+                preedit,
+                Point::new(bounds.x, bounds.center_y()),
+                theme.value_color(style),
+            );
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 1.0,
+                        height: 1.0,
+                        ..bounds
+                    },
+                    ..renderer::Quad::default()
+                },
+                theme.value_color(style),
+            );
+        }
     };
 
     if text_width > text_bounds.width {
@@ -1250,12 +1440,14 @@ pub struct State<P: text::Paragraph> {
     value: P,
     placeholder: P,
     icon: P,
+    preedit: Option<P>,
     is_focused: Option<Focus>,
     is_dragging: bool,
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    accessibility: Option<accessibility::Id>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -1278,12 +1470,14 @@ impl<P: text::Paragraph> State<P> {
             value: P::default(),
             placeholder: P::default(),
             icon: P::default(),
+            preedit: None,
             is_focused: None,
             is_dragging: false,
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            accessibility: None,
         }
     }
 
@@ -1313,6 +1507,7 @@ impl<P: text::Paragraph> State<P> {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = None;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -1454,6 +1649,27 @@ fn replace_paragraph<Renderer>(
     line_height: text::LineHeight,
 ) where
     Renderer: text::Renderer,
+{
+    state.value = create_paragraph(
+        renderer,
+        layout,
+        &value.to_string(),
+        font,
+        text_size,
+        line_height,
+    );
+}
+
+fn create_paragraph<Renderer>(
+    renderer: &Renderer,
+    layout: Layout<'_>,
+    content: &str,
+    font: Option<Renderer::Font>,
+    text_size: Option<Pixels>,
+    line_height: text::LineHeight,
+) -> Renderer::Paragraph
+where
+    Renderer: text::Renderer,
 {
     let font = font.unwrap_or_else(|| renderer.default_font());
     let text_size = text_size.unwrap_or_else(|| renderer.default_size());
@@ -1461,16 +1677,51 @@ fn replace_paragraph<Renderer>(
     let mut children_layout = layout.children();
     let text_bounds = children_layout.next().unwrap().bounds();
 
-    state.value = renderer.create_paragraph(Text {
+    renderer.create_paragraph(Text {
         font,
         line_height,
-        content: &value.to_string(),
+        content,
         bounds: Size::new(f32::INFINITY, text_bounds.height),
         size: text_size,
         horizontal_alignment: alignment::Horizontal::Left,
-        vertical_alignment: alignment::Vertical::Top,
+        vertical_alignment: alignment::Vertical::Center,
         shaping: text::Shaping::Advanced,
-    });
+    })
+}
+
+/// Computes the [`InputMethod`] of a focused [`TextInput`], placing the
+/// candidate window of the input method right below its cursor.
+fn input_method<P: text::Paragraph>(
+    state: &State<P>,
+    layout: Layout<'_>,
+    value: &Value,
+    is_secure: bool,
+) -> InputMethod {
+    let secure_value = is_secure.then(|| value.secure());
+    let value = secure_value.as_ref().unwrap_or(value);
+
+    let text_bounds = layout.children().next().unwrap().bounds();
+
+    let position = match state.cursor.state(value) {
+        cursor::State::Index(position) => position,
+        cursor::State::Selection { start, end } => start.min(end),
+    };
+
+    let (cursor_x, _) =
+        measure_cursor_and_scroll_offset(&state.value, text_bounds, position);
+    let offset = offset(text_bounds, value, state);
+
+    InputMethod::Enabled {
+        position: Point::new(
+            text_bounds.x + cursor_x - offset,
+            text_bounds.y + text_bounds.height,
+        ),
+        purpose: if is_secure {
+            input_method::Purpose::Secure
+        } else {
+            input_method::Purpose::Normal
+        },
+    }
 }
 
 const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
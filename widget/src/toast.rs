@@ -0,0 +1,967 @@
+//! Show notifications stacked in a corner over some content.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::time::{Duration, Instant};
+use crate::core::touch;
+use crate::core::widget::{tree, Operation, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, Color, Element, Length, Padding, Pixels, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{self, AtomicU64};
+
+pub use crate::style::toast::{Appearance, Status, StyleSheet};
+
+/// The time a [`Toast`] stays visible before closing itself, by default.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A notification shown by a [`Toaster`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Toast {
+    /// The [`Id`] of the [`Toast`].
+    ///
+    /// The [`Toaster`] tracks every toast by its [`Id`], which must be
+    /// unique among the toasts of a [`Toaster`].
+    pub id: Id,
+    /// The title of the [`Toast`].
+    pub title: String,
+    /// The body of the [`Toast`].
+    pub body: String,
+    /// The [`Status`] of the [`Toast`].
+    pub status: Status,
+}
+
+impl Toast {
+    /// Creates a new [`Toast`] with a unique [`Id`].
+    pub fn new(
+        title: impl Into<String>,
+        body: impl Into<String>,
+        status: Status,
+    ) -> Self {
+        Self {
+            id: Id::unique(),
+            title: title.into(),
+            body: body.into(),
+            status,
+        }
+    }
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// The identifier of a [`Toast`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::unique()
+    }
+}
+
+/// The corner of a [`Toaster`] where its toasts are stacked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Position {
+    /// The top left corner.
+    TopLeft,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+/// Some content with a stack of [`Toast`] notifications shown over it.
+///
+/// The application owns the list of toasts and removes a toast when the
+/// [`Toaster`] produces its close message with the [`Id`] of the toast,
+/// either because its timeout ran out or because its close button was
+/// pressed. The timeout of a toast is
+/// paused while the cursor is over it.
+///
+/// Only a limited amount of toasts is shown at once; the rest are queued
+/// and show up as soon as the visible ones close.
+///
+/// # Example
+/// ```no_run
+/// # type Toaster<'a, Message> =
+/// #     iced_widget::Toaster<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+/// #
+/// use iced_widget::text;
+/// use iced_widget::toast::{self, Status, Toast};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Close(toast::Id),
+/// }
+///
+/// let toasts = vec![Toast::new(
+///     "Saved",
+///     "Your changes have been saved.",
+///     Status::Success,
+/// )];
+///
+/// let toaster: Toaster<'_, Message> =
+///     Toaster::new(text("Hello!"), &toasts, Message::Close);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Toaster<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    toasts: &'a [Toast],
+    on_close: Box<dyn Fn(Id) -> Message + 'a>,
+    timeout: Duration,
+    max_visible: usize,
+    position: Position,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Toaster<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default width of a [`Toast`].
+    pub const DEFAULT_WIDTH: f32 = 300.0;
+
+    /// The default [`Padding`] of a [`Toast`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(10.0);
+
+    /// The default amount of toasts shown at once.
+    pub const DEFAULT_MAX_VISIBLE: usize = 5;
+
+    /// Creates a new [`Toaster`] with the given content and toasts.
+    ///
+    /// The message produced by `on_close` receives the [`Id`] of the
+    /// [`Toast`] that should be removed.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        toasts: &'a [Toast],
+        on_close: impl Fn(Id) -> Message + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            toasts,
+            on_close: Box::new(on_close),
+            timeout: DEFAULT_TIMEOUT,
+            max_visible: Self::DEFAULT_MAX_VISIBLE,
+            position: Position::default(),
+            width: Self::DEFAULT_WIDTH,
+            padding: Self::DEFAULT_PADDING,
+            spacing: 10.0,
+            text_size: None,
+            font: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the time a [`Toast`] stays visible before closing itself.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum amount of toasts shown at once.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Sets the corner where the toasts of the [`Toaster`] are stacked.
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of every [`Toast`].
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of every [`Toast`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the space between the toasts and around the stack.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the toasts.
+    pub fn text_size(mut self, text_size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(text_size.into());
+        self
+    }
+
+    /// Sets the font of the toasts.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Toaster`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The local state of a [`Toaster`].
+#[derive(Debug, Default)]
+struct State {
+    /// The timer of every visible toast.
+    timers: HashMap<Id, Timer>,
+    /// The toasts closed by the [`Toaster`] that the application has not
+    /// removed yet.
+    closed: HashSet<Id>,
+}
+
+impl State {
+    /// Returns the toasts that are shown, in order.
+    fn visible<'a, 'b: 'a>(
+        &'a self,
+        toasts: &'b [Toast],
+        max_visible: usize,
+    ) -> impl Iterator<Item = &'b Toast> + 'a {
+        toasts
+            .iter()
+            .filter(|toast| !self.closed.contains(&toast.id))
+            .take(max_visible)
+    }
+}
+
+/// The time left before a toast closes itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timer {
+    Running { deadline: Instant },
+    Paused { remaining: Duration },
+}
+
+impl Timer {
+    fn new(now: Instant, timeout: Duration) -> Self {
+        Self::Running {
+            deadline: now + timeout,
+        }
+    }
+
+    fn pause(&mut self, now: Instant) {
+        if let Self::Running { deadline } = *self {
+            *self = Self::Paused {
+                remaining: if deadline > now {
+                    deadline - now
+                } else {
+                    Duration::ZERO
+                },
+            };
+        }
+    }
+
+    /// Resumes the [`Timer`], returning its new deadline if it was paused.
+    fn resume(&mut self, now: Instant) -> Option<Instant> {
+        let Self::Paused { remaining } = *self else {
+            return None;
+        };
+
+        let deadline = now + remaining;
+        *self = Self::Running { deadline };
+
+        Some(deadline)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Toaster<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Forget the closed toasts once the application removes them
+        let ids: HashSet<Id> =
+            self.toasts.iter().map(|toast| toast.id).collect();
+        state.closed.retain(|id| ids.contains(id));
+
+        let visible: HashSet<Id> = state
+            .visible(self.toasts, self.max_visible)
+            .map(|toast| toast.id)
+            .collect();
+
+        state.timers.retain(|id, _| visible.contains(id));
+
+        let timer = Timer::new(Instant::now(), self.timeout);
+
+        for id in visible {
+            let _ = state.timers.entry(id).or_insert(timer);
+        }
+
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let content = self.content.as_widget_mut().overlay(
+            &mut children[0],
+            layout,
+            renderer,
+        );
+
+        let state = state.downcast_mut::<State>();
+
+        let toasts: Vec<&Toast> =
+            state.visible(self.toasts, self.max_visible).collect();
+
+        if toasts.is_empty() {
+            return content;
+        }
+
+        let toasts = overlay::Element::new(
+            layout.position(),
+            Box::new(Overlay {
+                toasts,
+                timers: &mut state.timers,
+                closed: &mut state.closed,
+                has_closed: false,
+                on_close: &self.on_close,
+                bounds: layout.bounds(),
+                position: self.position,
+                width: self.width,
+                padding: self.padding,
+                spacing: self.spacing,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+            }),
+        );
+
+        Some(
+            overlay::Group::with_children(
+                content.into_iter().chain(Some(toasts)).collect(),
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<Toaster<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(toaster: Toaster<'a, Message, Renderer>) -> Self {
+        Element::new(toaster)
+    }
+}
+
+/// The visible toasts of a [`Toaster`].
+struct Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    toasts: Vec<&'b Toast>,
+    timers: &'b mut HashMap<Id, Timer>,
+    closed: &'b mut HashSet<Id>,
+    /// Whether a toast has been closed during the current batch of events.
+    ///
+    /// Only one toast is closed per batch, so the toasts after it do not
+    /// slide into a place the cursor may be about to click.
+    has_closed: bool,
+    on_close: &'b (dyn Fn(Id) -> Message + 'a),
+    bounds: Rectangle,
+    position: Position,
+    width: f32,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    font: Option<Renderer::Font>,
+    style: &'b <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Closes the toast with the given [`Id`], unless a toast has already
+    /// been closed during the current batch of events.
+    ///
+    /// Returns whether the toast was closed.
+    fn close(&mut self, id: Id, shell: &mut Shell<'_, Message>) -> bool {
+        if self.has_closed || self.timers.remove(&id).is_none() {
+            return false;
+        }
+
+        let _ = self.closed.insert(id);
+        self.has_closed = true;
+
+        shell.publish((self.on_close)(id));
+
+        true
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn layout(
+        &mut self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+        let line_height = text::LineHeight::default().to_absolute(text_size);
+
+        let area = self
+            .bounds
+            .intersection(&Rectangle::with_size(bounds))
+            .unwrap_or(self.bounds);
+
+        let width = self.width.min(area.width - self.spacing * 2.0).max(0.0);
+        let inner_width = (width - self.padding.horizontal()).max(0.0);
+        let close = Size::new(text_size.0, line_height.0);
+
+        let measure = |content: &str, max_width: f32| {
+            renderer
+                .create_paragraph(Text {
+                    content,
+                    bounds: Size::new(max_width, f32::INFINITY),
+                    size: text_size,
+                    line_height: text::LineHeight::default(),
+                    font,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                })
+                .min_bounds()
+        };
+
+        let mut offset = self.spacing;
+
+        let toasts = self
+            .toasts
+            .iter()
+            .map(|toast| {
+                let title_width = (inner_width - close.width).max(0.0);
+                let title = measure(&toast.title, title_width);
+                let body = measure(&toast.body, inner_width);
+
+                let header = title.height.max(close.height);
+                let height = self.padding.vertical()
+                    + header
+                    + if toast.body.is_empty() {
+                        0.0
+                    } else {
+                        self.padding.top / 2.0 + body.height
+                    };
+
+                let mut title =
+                    layout::Node::new(Size::new(title_width, title.height));
+                title.move_to(Point::new(self.padding.left, self.padding.top));
+
+                let mut body =
+                    layout::Node::new(Size::new(inner_width, body.height));
+                body.move_to(Point::new(
+                    self.padding.left,
+                    self.padding.top + header + self.padding.top / 2.0,
+                ));
+
+                let mut close_button = layout::Node::new(close);
+                close_button.move_to(Point::new(
+                    width - self.padding.right - close.width,
+                    self.padding.top,
+                ));
+
+                let mut node = layout::Node::with_children(
+                    Size::new(width, height),
+                    vec![title, body, close_button],
+                );
+
+                let x = match self.position {
+                    Position::TopLeft | Position::BottomLeft => {
+                        area.x + self.spacing
+                    }
+                    Position::TopRight | Position::BottomRight => {
+                        area.x + area.width - self.spacing - width
+                    }
+                };
+
+                let y = match self.position {
+                    Position::TopLeft | Position::TopRight => area.y + offset,
+                    Position::BottomLeft | Position::BottomRight => {
+                        area.y + area.height - offset - height
+                    }
+                };
+
+                node.move_to(Point::new(x, y));
+                offset += height + self.spacing;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(bounds, toasts)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let now = match event {
+            Event::Window(_, window::Event::RedrawRequested(now)) => now,
+            _ => Instant::now(),
+        };
+
+        // The timer of a toast is paused while the cursor is over it
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            let Some(timer) = self.timers.get_mut(&toast.id) else {
+                continue;
+            };
+
+            if cursor.is_over(layout.bounds()) {
+                timer.pause(now);
+            } else if let Some(deadline) = timer.resume(now) {
+                shell.request_redraw(window::RedrawRequest::At(deadline));
+            }
+        }
+
+        match event {
+            Event::Window(_, window::Event::RedrawRequested(_)) => {
+                let mut next_redraw: Option<window::RedrawRequest> = None;
+
+                for toast in self.toasts.clone() {
+                    let Some(Timer::Running { deadline }) =
+                        self.timers.get(&toast.id).copied()
+                    else {
+                        continue;
+                    };
+
+                    let redraw = if deadline > now {
+                        window::RedrawRequest::At(deadline)
+                    } else if self.close(toast.id, shell) {
+                        continue;
+                    } else {
+                        window::RedrawRequest::NextFrame
+                    };
+
+                    next_redraw = Some(
+                        next_redraw.map_or(redraw, |next| next.min(redraw)),
+                    );
+                }
+
+                if let Some(redraw) = next_redraw {
+                    shell.request_redraw(redraw);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(index) = layout
+                    .children()
+                    .position(|layout| cursor.is_over(layout.bounds()))
+                else {
+                    return event::Status::Ignored;
+                };
+
+                let close_button = layout
+                    .children()
+                    .nth(index)
+                    .and_then(|layout| layout.children().nth(2))
+                    .map(|layout| layout.bounds());
+
+                if close_button.is_some_and(|bounds| cursor.is_over(bounds)) {
+                    let _ = self.close(self.toasts[index].id, shell);
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let text_size =
+            self.text_size.unwrap_or_else(|| renderer.default_size());
+        let font = self.font.unwrap_or_else(|| renderer.default_font());
+
+        for (toast, layout) in self.toasts.iter().zip(layout.children()) {
+            let appearance = theme.appearance(self.style, toast.status);
+            let bounds = layout.bounds();
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                    shadow: appearance.shadow,
+                },
+                appearance.background,
+            );
+
+            let mut children = layout.children();
+            let (Some(title), Some(body), Some(close_button)) =
+                (children.next(), children.next(), children.next())
+            else {
+                continue;
+            };
+
+            let text = |content, bounds: Rectangle| Text {
+                content,
+                bounds: bounds.size(),
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                font,
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                shaping: text::Shaping::Basic,
+            };
+
+            renderer.fill_text(
+                text(&toast.title, title.bounds()),
+                title.position(),
+                appearance.text_color,
+            );
+
+            renderer.fill_text(
+                text(&toast.body, body.bounds()),
+                body.position(),
+                appearance.text_color,
+            );
+
+            let close_button = close_button.bounds();
+
+            renderer.fill_text(
+                Text {
+                    horizontal_alignment: alignment::Horizontal::Center,
+                    ..text("×", close_button)
+                },
+                Point::new(close_button.center_x(), close_button.y),
+                if cursor.is_over(close_button) {
+                    appearance.text_color
+                } else {
+                    Color {
+                        a: appearance.text_color.a * 0.7,
+                        ..appearance.text_color
+                    }
+                },
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_close_button = layout
+            .children()
+            .filter_map(|layout| layout.children().nth(2))
+            .any(|layout| cursor.is_over(layout.bounds()));
+
+        if is_over_close_button {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn is_over(
+        &self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        cursor_position: Point,
+    ) -> bool {
+        layout
+            .children()
+            .any(|layout| layout.bounds().contains(cursor_position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paused_timer_keeps_its_remaining_time() {
+        let start = Instant::now();
+        let mut timer = Timer::new(start, Duration::from_secs(5));
+
+        timer.pause(start + Duration::from_secs(2));
+
+        assert_eq!(
+            timer,
+            Timer::Paused {
+                remaining: Duration::from_secs(3)
+            }
+        );
+
+        let later = start + Duration::from_secs(10);
+
+        assert_eq!(timer.resume(later), Some(later + Duration::from_secs(3)));
+        assert_eq!(timer.resume(later), None);
+    }
+
+    fn toasts(amount: usize) -> Vec<Toast> {
+        (0..amount)
+            .map(|i| Toast::new(format!("Toast {i}"), "", Status::Primary))
+            .collect()
+    }
+
+    #[test]
+    fn closed_toasts_are_replaced_until_they_are_removed() {
+        let mut toasts = toasts(3);
+
+        let toaster: Toaster<'_, Id> =
+            Toaster::new(crate::Space::new(10, 10), &toasts, |id| id)
+                .max_visible(2);
+
+        let mut tree = Tree::new(&toaster as &dyn Widget<Id, crate::Renderer>);
+        toaster.diff(&mut tree);
+
+        let state = tree.state.downcast_mut::<State>();
+        assert!(state.timers.contains_key(&toasts[0].id));
+        assert!(state.timers.contains_key(&toasts[1].id));
+        assert!(!state.timers.contains_key(&toasts[2].id));
+
+        // The toaster closes the first toast, but the application has not
+        // removed it yet
+        let _ = state.timers.remove(&toasts[0].id);
+        let _ = state.closed.insert(toasts[0].id);
+
+        toaster.diff(&mut tree);
+
+        let state = tree.state.downcast_mut::<State>();
+        assert!(state.timers.contains_key(&toasts[1].id));
+        assert!(state.timers.contains_key(&toasts[2].id));
+        assert_eq!(state.timers.len(), 2);
+
+        drop(toaster);
+        let _ = toasts.remove(0);
+
+        let toaster: Toaster<'_, Id> =
+            Toaster::new(crate::Space::new(10, 10), &toasts, |id| id)
+                .max_visible(2);
+
+        toaster.diff(&mut tree);
+
+        let state = tree.state.downcast_mut::<State>();
+        assert!(state.closed.is_empty());
+        assert_eq!(state.timers.len(), 2);
+    }
+
+    #[test]
+    fn only_one_toast_is_closed_per_batch_of_events() {
+        let toasts = toasts(3);
+        let on_close = |id| id;
+        let style = Default::default();
+
+        let mut timers: HashMap<Id, Timer> = toasts
+            .iter()
+            .map(|toast| {
+                (toast.id, Timer::new(Instant::now(), DEFAULT_TIMEOUT))
+            })
+            .collect();
+        let mut closed = HashSet::new();
+
+        let mut overlay: Overlay<'_, '_, Id, crate::Renderer> = Overlay {
+            toasts: toasts.iter().collect(),
+            timers: &mut timers,
+            closed: &mut closed,
+            has_closed: false,
+            on_close: &on_close,
+            bounds: Rectangle::with_size(Size::new(100.0, 100.0)),
+            position: Position::default(),
+            width: 100.0,
+            padding: Padding::ZERO,
+            spacing: 0.0,
+            text_size: None,
+            font: None,
+            style: &style,
+        };
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        assert!(overlay.close(toasts[1].id, &mut shell));
+        assert!(!overlay.close(toasts[2].id, &mut shell));
+
+        assert_eq!(messages, vec![toasts[1].id]);
+        assert!(closed.contains(&toasts[1].id));
+        assert!(!timers.contains_key(&toasts[1].id));
+        assert!(timers.contains_key(&toasts[2].id));
+    }
+}
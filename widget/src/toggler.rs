@@ -1,13 +1,17 @@
 //! Show toggle controls using togglers.
+use crate::core::accessibility;
 use crate::core::alignment;
+use crate::core::animation::{Animation, Interpolate};
 use crate::core::event;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
+use crate::core::time::Instant;
 use crate::core::touch;
 use crate::core::widget;
 use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
 use crate::core::{
     Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size,
     Widget,
@@ -161,11 +165,18 @@ where
     Renderer::Theme: StyleSheet + crate::text::StyleSheet,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<widget::text::State<Renderer::Paragraph>>()
+        tree::Tag::of::<State<Renderer::Paragraph>>()
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(widget::text::State::<Renderer::Paragraph>::default())
+        tree::State::new(State::<Renderer::Paragraph> {
+            label: widget::text::State::default(),
+            toggle: Animation::new(if self.is_toggled { 1.0 } else { 0.0 }),
+            hover: Animation::new(0.0),
+            is_hovered: false,
+            now: Instant::now(),
+            accessibility: None,
+        })
     }
 
     fn width(&self) -> Length {
@@ -190,12 +201,11 @@ where
             |_| layout::Node::new(Size::new(2.0 * self.size, self.size)),
             |limits| {
                 if let Some(label) = self.label.as_deref() {
-                    let state = tree
-                    .state
-                    .downcast_mut::<widget::text::State<Renderer::Paragraph>>();
+                    let state =
+                        tree.state.downcast_mut::<State<Renderer::Paragraph>>();
 
                     widget::text::layout(
-                        state,
+                        &mut state.label,
                         renderer,
                         limits,
                         self.width,
@@ -215,9 +225,31 @@ where
         )
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let mut node = accessibility::Node::new(accessibility::Role::Toggler)
+            .toggled(self.is_toggled)
+            .action(accessibility::Action::Click);
+
+        if let Some(label) = self.label.as_deref() {
+            node = node.label(label);
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if let Some(id) = operation.accessible(None, layout.bounds(), &node) {
+            state.accessibility = Some(id);
+        }
+    }
+
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        tree: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: mouse::Cursor,
@@ -239,6 +271,57 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Accessibility(accessibility::Event::Click(target))
+                if tree
+                    .state
+                    .downcast_ref::<State<Renderer::Paragraph>>()
+                    .accessibility
+                    == Some(target) =>
+            {
+                shell.publish((self.on_toggle)(!self.is_toggled));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Mouse(mouse::Event::CursorLeft)
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let is_hovered = cursor.is_over(layout.bounds());
+
+                if is_hovered != state.is_hovered {
+                    state.is_hovered = is_hovered;
+
+                    // Draw samples the animation at `now`, so both must agree
+                    state.now = Instant::now();
+                    state
+                        .hover
+                        .go(if is_hovered { 1.0 } else { 0.0 }, state.now);
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
+            Event::Window(_, window::Event::RedrawRequested(now)) => {
+                let state =
+                    tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+                let toggle = if self.is_toggled { 1.0 } else { 0.0 };
+
+                state.now = now;
+
+                if *state.toggle.target() != toggle {
+                    state.toggle.go(toggle, now);
+                }
+
+                if state.toggle.is_animating(now)
+                    || state.hover.is_animating(now)
+                {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                event::Status::Ignored
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -275,6 +358,8 @@ where
         /// between the background Quad and foreground Quad.
         const SPACE_RATIO: f32 = 0.05;
 
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
         let mut children = layout.children();
         let toggler_layout = children.next().unwrap();
 
@@ -285,7 +370,7 @@ where
                 renderer,
                 style,
                 label_layout,
-                tree.state.downcast_ref(),
+                &state.label,
                 crate::text::Appearance::default(),
             );
         }
@@ -293,10 +378,43 @@ where
         let bounds = toggler_layout.bounds();
         let is_mouse_over = cursor.is_over(layout.bounds());
 
-        let style = if is_mouse_over {
-            theme.hovered(&self.style, self.is_toggled)
+        let toggle = if *state.toggle.target()
+            == if self.is_toggled { 1.0 } else { 0.0 }
+        {
+            state.toggle.value(state.now)
+        } else if self.is_toggled {
+            1.0
+        } else {
+            0.0
+        };
+
+        let hover = if state.hover.is_animating(state.now) {
+            state.hover.value(state.now)
+        } else if is_mouse_over {
+            1.0
         } else {
-            theme.active(&self.style, self.is_toggled)
+            0.0
+        };
+
+        let appearance = |is_toggled| {
+            let active = theme.active(&self.style, is_toggled);
+
+            if hover <= 0.0 {
+                active
+            } else if hover >= 1.0 {
+                theme.hovered(&self.style, is_toggled)
+            } else {
+                active
+                    .interpolate(&theme.hovered(&self.style, is_toggled), hover)
+            }
+        };
+
+        let style = if toggle <= 0.0 {
+            appearance(false)
+        } else if toggle >= 1.0 {
+            appearance(true)
+        } else {
+            appearance(false).interpolate(&appearance(true), toggle)
         };
 
         let border_radius = bounds.height / BORDER_RADIUS_RATIO;
@@ -317,17 +435,19 @@ where
                 border_color: style
                     .background_border
                     .unwrap_or(style.background),
+                ..renderer::Quad::default()
             },
             style.background,
         );
 
         let toggler_foreground_bounds = Rectangle {
             x: bounds.x
-                + if self.is_toggled {
-                    bounds.width - 2.0 * space - (bounds.height - (4.0 * space))
-                } else {
-                    2.0 * space
-                },
+                + (2.0 * space).interpolate(
+                    &(bounds.width
+                        - 2.0 * space
+                        - (bounds.height - (4.0 * space))),
+                    toggle,
+                ),
             y: bounds.y + (2.0 * space),
             width: bounds.height - (4.0 * space),
             height: bounds.height - (4.0 * space),
@@ -341,12 +461,24 @@ where
                 border_color: style
                     .foreground_border
                     .unwrap_or(style.foreground),
+                ..renderer::Quad::default()
             },
             style.foreground,
         );
     }
 }
 
+/// The local state of a [`Toggler`].
+#[derive(Debug)]
+struct State<P: text::Paragraph> {
+    label: widget::text::State<P>,
+    toggle: Animation<f32>,
+    hover: Animation<f32>,
+    is_hovered: bool,
+    now: Instant,
+    accessibility: Option<accessibility::Id>,
+}
+
 impl<'a, Message, Renderer> From<Toggler<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -0,0 +1,391 @@
+//! Rotate, scale or skew some content.
+//!
+//! # Example
+//! ```no_run
+//! # type Transform<'a, Message> =
+//! #     iced_widget::Transform<'a, Message, iced_widget::renderer::Renderer<iced_widget::style::Theme>>;
+//! #
+//! use iced_widget::core::{Radians, Transformation};
+//! use iced_widget::text;
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {}
+//!
+//! let label: Transform<'_, Message> = Transform::new(
+//!     text("Rotated!"),
+//!     Transformation::rotate(Radians(std::f32::consts::FRAC_PI_4)),
+//! )
+//! .centered();
+//! ```
+use crate::core::event::{self, Event};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::touch;
+use crate::core::widget::{Operation, Tree};
+use crate::core::{
+    Clipboard, Element, InputMethod, Layout, Length, Rectangle, Shell,
+    Transformation, Vector, Widget,
+};
+
+/// A widget that applies a [`Transformation`] to its content.
+///
+/// The [`Transformation`] is relative to the top-left corner of the content,
+/// unless the [`Transform`] is [`centered`]. Cursor positions are mapped back
+/// through the inverse [`Transformation`], so the content can be interacted
+/// with as usual.
+///
+/// The layout of the content is left untouched; the transformed content may
+/// overflow the bounds of the [`Transform`].
+///
+/// [`centered`]: Self::centered
+#[allow(missing_debug_implementations)]
+pub struct Transform<'a, Message, Renderer = crate::Renderer> {
+    content: Element<'a, Message, Renderer>,
+    transformation: Transformation,
+    centered: bool,
+}
+
+impl<'a, Message, Renderer> Transform<'a, Message, Renderer> {
+    /// Creates a new [`Transform`] applying the given [`Transformation`] to
+    /// the content.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        transformation: Transformation,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            transformation,
+            centered: false,
+        }
+    }
+
+    /// Applies the [`Transformation`] around the center of the content,
+    /// instead of its top-left corner.
+    #[must_use]
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+
+    fn transformation(&self, bounds: Rectangle) -> Transformation {
+        let origin = if self.centered {
+            bounds.center()
+        } else {
+            bounds.position()
+        };
+
+        Transformation::translate(origin.x, origin.y)
+            * self.transformation
+            * Transformation::translate(-origin.x, -origin.y)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Transform<'a, Message, Renderer>
+where
+    Renderer: crate::core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let transformation = self.transformation(layout.bounds());
+
+        let Some(inverse) = transformation.inverse() else {
+            return event::Status::Ignored;
+        };
+
+        let mut local_messages = Vec::new();
+        let mut local_shell = Shell::new(&mut local_messages);
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            map_event(event, inverse),
+            layout,
+            map_cursor(cursor, inverse),
+            renderer,
+            clipboard,
+            &mut local_shell,
+            &(*viewport * inverse),
+        );
+
+        // The candidate window of the input method follows the content. The
+        // transformed request comes first, so it takes precedence when
+        // merging.
+        let input_method = *local_shell.input_method();
+
+        if let InputMethod::Enabled { position, .. } = input_method {
+            shell.request_input_method(
+                &input_method.translate(position * transformation - position),
+            );
+        }
+
+        shell.merge(local_shell, std::convert::identity);
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let Some(inverse) = self.transformation(layout.bounds()).inverse()
+        else {
+            return mouse::Interaction::default();
+        };
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            map_cursor(cursor, inverse),
+            &(*viewport * inverse),
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let transformation = self.transformation(layout.bounds());
+
+        let Some(inverse) = transformation.inverse() else {
+            return;
+        };
+
+        renderer.with_transformation(transformation, |renderer| {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout,
+                map_cursor(cursor, inverse),
+                &(*viewport * inverse),
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        // Overlays are not transformed, but they follow the content around
+        let position = layout.position();
+        let translation =
+            position * self.transformation(layout.bounds()) - position;
+
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+            .map(|overlay| overlay.translate(translation))
+    }
+}
+
+impl<'a, Message, Renderer> From<Transform<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::core::Renderer + 'a,
+{
+    fn from(transform: Transform<'a, Message, Renderer>) -> Self {
+        Element::new(transform)
+    }
+}
+
+fn map_cursor(cursor: mouse::Cursor, inverse: Transformation) -> mouse::Cursor {
+    match cursor {
+        mouse::Cursor::Available(position) => {
+            mouse::Cursor::Available(position * inverse)
+        }
+        mouse::Cursor::Unavailable => mouse::Cursor::Unavailable,
+    }
+}
+
+fn map_event(event: Event, inverse: Transformation) -> Event {
+    match event {
+        Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: position * inverse,
+            })
+        }
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Pixels { x, y },
+        }) => {
+            // Only the linear part applies to distances
+            let delta = Vector::new(x, y) * inverse;
+
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels {
+                    x: delta.x,
+                    y: delta.y,
+                },
+            })
+        }
+        Event::Touch(touch::Event::FingerPressed { id, position }) => {
+            Event::Touch(touch::Event::FingerPressed {
+                id,
+                position: position * inverse,
+            })
+        }
+        Event::Touch(touch::Event::FingerMoved { id, position }) => {
+            Event::Touch(touch::Event::FingerMoved {
+                id,
+                position: position * inverse,
+            })
+        }
+        Event::Touch(touch::Event::FingerLifted { id, position }) => {
+            Event::Touch(touch::Event::FingerLifted {
+                id,
+                position: position * inverse,
+            })
+        }
+        Event::Touch(touch::Event::FingerLost { id, position }) => {
+            Event::Touch(touch::Event::FingerLost {
+                id,
+                position: position * inverse,
+            })
+        }
+        _ => event,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Point, Size};
+
+    #[test]
+    fn cursor_is_mapped_into_the_content() {
+        let bounds =
+            Rectangle::new(Point::new(100.0, 100.0), Size::new(50.0, 20.0));
+
+        let transform = Transform::<(), crate::core::renderer::Null>::new(
+            crate::Space::new(50.0, 20.0),
+            Transformation::scale(2.0, 2.0),
+        );
+
+        let inverse = transform.transformation(bounds).inverse().unwrap();
+
+        // The top-left corner stays in place, everything else is zoomed
+        assert_eq!(
+            map_cursor(
+                mouse::Cursor::Available(Point::new(100.0, 100.0)),
+                inverse
+            ),
+            mouse::Cursor::Available(Point::new(100.0, 100.0))
+        );
+        assert_eq!(
+            map_cursor(
+                mouse::Cursor::Available(Point::new(180.0, 120.0)),
+                inverse
+            ),
+            mouse::Cursor::Available(Point::new(140.0, 110.0))
+        );
+
+        let inverse = transform
+            .centered()
+            .transformation(bounds)
+            .inverse()
+            .unwrap();
+
+        assert_eq!(
+            map_cursor(
+                mouse::Cursor::Available(Point::new(125.0, 110.0)),
+                inverse
+            ),
+            mouse::Cursor::Available(Point::new(125.0, 110.0))
+        );
+    }
+
+    #[test]
+    fn pixel_scroll_deltas_are_mapped_into_the_content() {
+        let inverse = Transformation::translate(100.0, 100.0)
+            * Transformation::scale(2.0, 4.0);
+        let inverse = inverse.inverse().unwrap();
+
+        let scroll = |delta| {
+            map_event(
+                Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                inverse,
+            )
+        };
+
+        // The translation does not affect distances
+        assert_eq!(
+            scroll(mouse::ScrollDelta::Pixels { x: 10.0, y: 20.0 }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Pixels { x: 5.0, y: 5.0 }
+            })
+        );
+        assert_eq!(
+            scroll(mouse::ScrollDelta::Lines { x: 1.0, y: 2.0 }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 1.0, y: 2.0 }
+            })
+        );
+    }
+}
@@ -0,0 +1,1115 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+use crate::core::alignment;
+use crate::core::event::{self, Event};
+use crate::core::keyboard;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Alignment, Background, Clipboard, Color, Element, Length, Padding, Pixels,
+    Point, Rectangle, Shell, Size, Widget,
+};
+use crate::runtime::Command;
+use crate::selection::select;
+
+use std::hash::Hash;
+
+pub use crate::selection::SelectionMode;
+pub use crate::style::tree_view::{Appearance, StyleSheet};
+
+/// A widget that displays a hierarchy of [`Node`]s, indenting the children
+/// of every expanded node.
+///
+/// The hierarchy, the expanded nodes and the selection are owned by the
+/// application. The children of a collapsed node do not need to exist until
+/// it is expanded, so they can be loaded lazily when the [`TreeView`]
+/// produces the message of [`TreeView::on_toggle`].
+///
+/// When focused, the [`TreeView`] can be navigated with the arrow keys.
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Key, Message, Renderer = crate::Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    id: Option<Id>,
+    rows: Vec<Row<'a, Key, Message, Renderer>>,
+    selected: Vec<Key>,
+    selection_mode: SelectionMode,
+    indent: f32,
+    padding: Padding,
+    width: Length,
+    on_toggle: Option<Box<dyn Fn(Key, bool) -> Message + 'a>>,
+    on_select: Option<Box<dyn Fn(Vec<Key>) -> Message + 'a>>,
+    on_drop: Option<Box<dyn Fn(DropEvent<Key>) -> Message + 'a>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Key, Message, Renderer> TreeView<'a, Key, Message, Renderer>
+where
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The default indentation of every level of a [`TreeView`].
+    pub const DEFAULT_INDENT: f32 = 16.0;
+
+    /// The default [`Padding`] of the nodes of a [`TreeView`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(3.0);
+
+    /// Creates a new [`TreeView`] with the given root [`Node`]s.
+    pub fn new(
+        roots: impl IntoIterator<Item = Node<'a, Key, Message, Renderer>>,
+    ) -> Self {
+        let mut rows = Vec::new();
+
+        for root in roots {
+            root.flatten(0, None, &mut rows);
+        }
+
+        TreeView {
+            id: None,
+            rows,
+            selected: Vec::new(),
+            selection_mode: SelectionMode::Single,
+            indent: Self::DEFAULT_INDENT,
+            padding: Self::DEFAULT_PADDING,
+            width: Length::Fill,
+            on_toggle: None,
+            on_select: None,
+            on_drop: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the keys of the selected nodes of the [`TreeView`].
+    pub fn selected(mut self, keys: impl IntoIterator<Item = Key>) -> Self {
+        self.selected = keys.into_iter().collect();
+        self
+    }
+
+    /// Sets the [`SelectionMode`] of the [`TreeView`].
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    pub fn indent(mut self, indent: impl Into<Pixels>) -> Self {
+        self.indent = indent.into().0;
+        self
+    }
+
+    /// Sets the [`Padding`] of the nodes of the [`TreeView`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the message that will be produced when a node is expanded or
+    /// collapsed, containing its key and whether it should be expanded.
+    pub fn on_toggle(mut self, f: impl Fn(Key, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the selection of the
+    /// [`TreeView`] changes, containing the keys of the selected nodes.
+    pub fn on_select(mut self, f: impl Fn(Vec<Key>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Enables dragging the nodes of the [`TreeView`] to reorder them, which
+    /// will use the provided function to produce messages.
+    pub fn on_drop(
+        mut self,
+        f: impl Fn(DropEvent<Key>) -> Message + 'a,
+    ) -> Self {
+        self.on_drop = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer::Theme as StyleSheet>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A node of a [`TreeView`].
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Key, Message, Renderer = crate::Renderer> {
+    key: Key,
+    label: Element<'a, Message, Renderer>,
+    is_expandable: bool,
+    is_expanded: bool,
+    children: Vec<Node<'a, Key, Message, Renderer>>,
+}
+
+impl<'a, Key, Message, Renderer> Node<'a, Key, Message, Renderer> {
+    /// Creates a new [`Node`] with the given key and label.
+    pub fn new(
+        key: Key,
+        label: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Node {
+            key,
+            label: label.into(),
+            is_expandable: false,
+            is_expanded: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets whether the [`Node`] is expanded, displaying its children.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded even if it has no children.
+    ///
+    /// This is useful for nodes whose children are loaded lazily.
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    /// Adds a child to the [`Node`].
+    pub fn push(mut self, child: Node<'a, Key, Message, Renderer>) -> Self {
+        self.is_expandable = true;
+        self.children.push(child);
+        self
+    }
+
+    /// Adds the given children to the [`Node`].
+    pub fn children(
+        self,
+        children: impl IntoIterator<Item = Node<'a, Key, Message, Renderer>>,
+    ) -> Self {
+        children.into_iter().fold(self, Self::push)
+    }
+
+    fn flatten(
+        self,
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<Row<'a, Key, Message, Renderer>>,
+    ) {
+        let index = rows.len();
+
+        rows.push(Row {
+            key: self.key,
+            label: self.label,
+            depth,
+            parent,
+            is_expandable: self.is_expandable,
+            is_expanded: self.is_expanded,
+        });
+
+        if self.is_expanded {
+            for child in self.children {
+                child.flatten(depth + 1, Some(index), rows);
+            }
+        }
+    }
+}
+
+/// A visible [`Node`] of a [`TreeView`].
+struct Row<'a, Key, Message, Renderer> {
+    key: Key,
+    label: Element<'a, Message, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+/// An event produced when a node of a [`TreeView`] is dragged and dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropEvent<Key> {
+    /// The key of the dragged node.
+    pub key: Key,
+
+    /// The key of the node where the dragged node was dropped.
+    pub target: Key,
+
+    /// The [`Position`] of the dropped node relative to the target.
+    pub position: Position,
+}
+
+/// The position where a dragged node of a [`TreeView`] is dropped, relative
+/// to its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// Right before the target, as its sibling.
+    Before,
+    /// Right after the target, as its sibling.
+    After,
+    /// Inside of the target, as its child.
+    Inside,
+}
+
+struct State<Key> {
+    keys: Vec<Key>,
+    is_focused: bool,
+    cursor: Option<Key>,
+    anchor: Option<Key>,
+    modifiers: keyboard::Modifiers,
+    drag: Option<Drag<Key>>,
+}
+
+struct Drag<Key> {
+    key: Key,
+    origin: Point,
+    is_dragging: bool,
+    target: Option<(usize, Position)>,
+}
+
+/// The distance the cursor needs to travel before a node is dragged.
+const DRAG_THRESHOLD: f32 = 5.0;
+
+impl<Key> operation::Focusable for State<Key> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, Key, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, Key, Message, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Key>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            keys: self.rows.iter().map(|row| row.key.clone()).collect(),
+            is_focused: false,
+            cursor: None,
+            anchor: None,
+            modifiers: keyboard::Modifiers::default(),
+            drag: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows.iter().map(|row| Tree::new(&row.label)).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+        let keys: Vec<Key> =
+            self.rows.iter().map(|row| row.key.clone()).collect();
+
+        tree::diff_children_keyed(
+            children,
+            &state.keys,
+            &self.rows,
+            &keys,
+            |tree, row| tree.diff(&row.label),
+            |row| Tree::new(&row.label),
+        );
+
+        if state.keys != keys {
+            // The dragged node may be gone and its target may have moved
+            if let Some(drag) = &mut state.drag {
+                drag.target = None;
+            }
+
+            if state
+                .drag
+                .as_ref()
+                .is_some_and(|drag| !keys.contains(&drag.key))
+            {
+                state.drag = None;
+            }
+
+            state.keys = keys;
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+        let arrow = f32::from(renderer.default_size());
+
+        let mut y = 0.0;
+        let mut content_width = 0.0f32;
+
+        let labels: Vec<_> = self
+            .rows
+            .iter()
+            .zip(&mut tree.children)
+            .map(|(row, tree)| {
+                let x =
+                    self.padding.left + row.depth as f32 * self.indent + arrow;
+
+                let mut label = row.label.as_widget().layout(
+                    tree,
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(
+                            (max_width - x - self.padding.right).max(0.0),
+                            f32::INFINITY,
+                        ),
+                    ),
+                );
+
+                let size = label.size();
+                let height = size.height.max(arrow) + self.padding.vertical();
+
+                label.move_to(Point::new(x, self.padding.top));
+                label.align(
+                    Alignment::Start,
+                    Alignment::Center,
+                    Size::new(size.width, height - self.padding.vertical()),
+                );
+
+                content_width =
+                    content_width.max(x + size.width + self.padding.right);
+
+                let offset = y;
+                y += height;
+
+                (label, offset, height)
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(content_width, y));
+
+        layout::Node::with_children(
+            size,
+            labels
+                .into_iter()
+                .map(|(label, y, height)| {
+                    let mut node = layout::Node::with_children(
+                        Size::new(size.width, height),
+                        vec![label],
+                    );
+
+                    node.move_to(Point::new(0.0, y));
+                    node
+                })
+                .collect(),
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State<Key>>();
+        let id = self.id.as_ref().map(|id| &id.0);
+
+        operation.focusable(state, id, layout.bounds());
+
+        operation.container(id, layout.bounds(), &mut |operation| {
+            self.rows
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children())
+                .for_each(|((row, state), layout)| {
+                    row.label.as_widget().operate(
+                        state,
+                        label(layout),
+                        renderer,
+                        operation,
+                    );
+                });
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        let state = state.downcast_mut::<State<Key>>();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if state.is_focused => {
+                let cursor = state.cursor.as_ref().and_then(|key| {
+                    self.rows.iter().position(|row| &row.key == key)
+                });
+
+                match navigate(&self.rows, cursor, key_code) {
+                    Some(Action::Move(index)) => {
+                        self.select(state, index, shell);
+                        return event::Status::Captured;
+                    }
+                    Some(Action::Toggle(index, is_expanded)) => {
+                        if let Some(on_toggle) = &self.on_toggle {
+                            shell.publish(on_toggle(
+                                self.rows[index].key.clone(),
+                                is_expanded,
+                            ));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                    None => {}
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let (Some(drag), Some(position)) =
+                    (&mut state.drag, cursor.position())
+                {
+                    if !drag.is_dragging
+                        && position.distance(drag.origin) > DRAG_THRESHOLD
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        drag.target = drop_target(
+                            &self.rows, layout, &drag.key, position,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = state.drag.take() {
+                    if drag.is_dragging {
+                        if let (Some(on_drop), Some((index, position))) =
+                            (&self.on_drop, drag.target)
+                        {
+                            shell.publish(on_drop(DropEvent {
+                                key: drag.key,
+                                target: self.rows[index].key.clone(),
+                                position,
+                            }));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let status = self
+            .rows
+            .iter_mut()
+            .zip(children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.label.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    label(layout),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+        | Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            let Some(position) = cursor.position_over(layout.bounds()) else {
+                state.is_focused = false;
+                return status;
+            };
+
+            state.is_focused = true;
+
+            if status == event::Status::Captured {
+                return status;
+            }
+
+            let Some(index) = layout
+                .children()
+                .position(|row| row.bounds().contains(position))
+            else {
+                return event::Status::Captured;
+            };
+
+            let row = &self.rows[index];
+
+            if row.is_expandable
+                && self
+                    .arrow_bounds(row, layout.children().nth(index), renderer)
+                    .is_some_and(|bounds| bounds.contains(position))
+            {
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(row.key.clone(), !row.is_expanded));
+                }
+
+                return event::Status::Captured;
+            }
+
+            if self.on_drop.is_some() {
+                state.drag = Some(Drag {
+                    key: row.key.clone(),
+                    origin: position,
+                    is_dragging: false,
+                    target: None,
+                });
+            }
+
+            self.select(state, index, shell);
+
+            return event::Status::Captured;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Key>>();
+
+        if state.drag.as_ref().is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let interaction = self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((row, tree), layout)| {
+                row.label.as_widget().mouse_interaction(
+                    tree,
+                    label(layout),
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default();
+
+        if interaction == mouse::Interaction::default() {
+            let is_over_arrow =
+                self.rows
+                    .iter()
+                    .zip(layout.children())
+                    .any(|(row, layout)| {
+                        row.is_expandable
+                            && self
+                                .arrow_bounds(row, Some(layout), renderer)
+                                .is_some_and(|bounds| cursor.is_over(bounds))
+                    });
+
+            if is_over_arrow {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Key>>();
+        let appearance = theme.appearance(&self.style);
+        let arrow = f32::from(renderer.default_size());
+
+        let drag = state.drag.as_ref().filter(|drag| drag.is_dragging);
+
+        for (index, ((row, tree), layout)) in self
+            .rows
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .enumerate()
+        {
+            let bounds = layout.bounds();
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            let is_selected = self.selected.contains(&row.key);
+
+            let background = if is_selected {
+                Some(appearance.selected_background)
+            } else if drag.is_none() && cursor.is_over(bounds) {
+                appearance.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+
+            if state.is_focused && state.cursor.as_ref() == Some(&row.key) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_width: 1.0,
+                        border_color: appearance.focused_border_color,
+                        ..renderer::Quad::default()
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            for level in 0..row.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x
+                                + self.padding.left
+                                + level as f32 * self.indent
+                                + arrow / 2.0,
+                            y: bounds.y,
+                            width: 1.0,
+                            height: bounds.height,
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    Background::Color(appearance.guide_color),
+                );
+            }
+
+            if row.is_expandable {
+                if let Some(arrow_bounds) =
+                    self.arrow_bounds(row, Some(layout), renderer)
+                {
+                    renderer.fill_text(
+                        Text {
+                            content: if row.is_expanded {
+                                "▾"
+                            } else {
+                                "▸"
+                            },
+                            size: renderer.default_size(),
+                            line_height: text::LineHeight::default(),
+                            font: renderer.default_font(),
+                            bounds: arrow_bounds.size(),
+                            horizontal_alignment: alignment::Horizontal::Center,
+                            vertical_alignment: alignment::Vertical::Center,
+                            shaping: text::Shaping::Advanced,
+                        },
+                        arrow_bounds.center(),
+                        appearance.arrow_color,
+                    );
+                }
+            }
+
+            row.label.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &if is_selected {
+                    renderer::Style {
+                        text_color: appearance.selected_text_color,
+                    }
+                } else {
+                    *style
+                },
+                label(layout),
+                cursor,
+                viewport,
+            );
+
+            if let Some((_, position)) = drag
+                .and_then(|drag| drag.target)
+                .filter(|(target, _)| *target == index)
+            {
+                let (bounds, background) = match position {
+                    Position::Before => (
+                        Rectangle {
+                            height: 2.0,
+                            ..bounds
+                        },
+                        appearance.drop_indicator_color,
+                    ),
+                    Position::After => (
+                        Rectangle {
+                            y: bounds.y + bounds.height - 2.0,
+                            height: 2.0,
+                            ..bounds
+                        },
+                        appearance.drop_indicator_color,
+                    ),
+                    Position::Inside => (bounds, Color::TRANSPARENT),
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_width: if position == Position::Inside {
+                            2.0
+                        } else {
+                            0.0
+                        },
+                        border_color: appearance.drop_indicator_color,
+                        ..renderer::Quad::default()
+                    },
+                    background,
+                );
+            }
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .filter_map(|((row, state), layout)| {
+                row.label.as_widget_mut().overlay(
+                    state,
+                    label(layout),
+                    renderer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty())
+            .then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Key, Message, Renderer> TreeView<'a, Key, Message, Renderer>
+where
+    Key: Clone + Eq + Hash,
+    Renderer: text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Moves the keyboard cursor to the row with the given index and
+    /// selects it.
+    fn select(
+        &self,
+        state: &mut State<Key>,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let key = self.rows[index].key.clone();
+
+        if let Some(on_select) = &self.on_select {
+            let anchor = state.anchor.as_ref().and_then(|anchor| {
+                self.rows.iter().position(|row| row.key == *anchor)
+            });
+
+            let (selected, anchor) = select(
+                &self.selected,
+                anchor,
+                index,
+                state.modifiers,
+                self.selection_mode,
+                |index| self.rows[index].key.clone(),
+            );
+
+            state.anchor = Some(self.rows[anchor].key.clone());
+            shell.publish(on_select(selected));
+        }
+
+        state.cursor = Some(key);
+    }
+
+    /// Returns the bounds of the expand arrow of a row.
+    fn arrow_bounds(
+        &self,
+        row: &Row<'a, Key, Message, Renderer>,
+        layout: Option<Layout<'_>>,
+        renderer: &Renderer,
+    ) -> Option<Rectangle> {
+        let bounds = layout?.bounds();
+        let arrow = f32::from(renderer.default_size());
+
+        Some(Rectangle {
+            x: bounds.x + self.padding.left + row.depth as f32 * self.indent,
+            width: arrow,
+            ..bounds
+        })
+    }
+}
+
+impl<'a, Key, Message, Renderer> From<TreeView<'a, Key, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Key: Clone + Eq + Hash + 'static,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Key, Message, Renderer>) -> Self {
+        Self::new(tree_view)
+    }
+}
+
+/// The identifier of a [`TreeView`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(widget::Id);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+        Self(widget::Id::new(id))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        Self(widget::Id::unique())
+    }
+}
+
+impl From<Id> for widget::Id {
+    fn from(id: Id) -> Self {
+        id.0
+    }
+}
+
+/// Produces a [`Command`] that focuses the [`TreeView`] with the given
+/// [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id.0))
+}
+
+/// Returns the layout of the label of a row.
+fn label(row: Layout<'_>) -> Layout<'_> {
+    row.children().next().expect("Tree view row label")
+}
+
+/// An action triggered by a key press in a focused [`TreeView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Move(usize),
+    Toggle(usize, bool),
+}
+
+/// Returns the [`Action`] of the given key, with the keyboard cursor at the
+/// given row.
+fn navigate<Key, Message, Renderer>(
+    rows: &[Row<'_, Key, Message, Renderer>],
+    cursor: Option<usize>,
+    key_code: keyboard::KeyCode,
+) -> Option<Action> {
+    use keyboard::KeyCode;
+
+    let last = rows.len().checked_sub(1)?;
+
+    let Some(current) = cursor else {
+        return match key_code {
+            KeyCode::Up | KeyCode::End => Some(Action::Move(last)),
+            KeyCode::Down | KeyCode::Home => Some(Action::Move(0)),
+            _ => None,
+        };
+    };
+
+    let row = &rows[current];
+
+    match key_code {
+        KeyCode::Up => Some(Action::Move(current.saturating_sub(1))),
+        KeyCode::Down => Some(Action::Move((current + 1).min(last))),
+        KeyCode::Home => Some(Action::Move(0)),
+        KeyCode::End => Some(Action::Move(last)),
+        KeyCode::Right if row.is_expandable && !row.is_expanded => {
+            Some(Action::Toggle(current, true))
+        }
+        KeyCode::Right => rows
+            .get(current + 1)
+            .filter(|child| child.parent == Some(current))
+            .map(|_| Action::Move(current + 1)),
+        KeyCode::Left if row.is_expanded => {
+            Some(Action::Toggle(current, false))
+        }
+        KeyCode::Left => row.parent.map(Action::Move),
+        KeyCode::Enter | KeyCode::Space if row.is_expandable => {
+            Some(Action::Toggle(current, !row.is_expanded))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the row and [`Position`] where the dragged node with the given
+/// key would be dropped.
+///
+/// A node cannot be dropped on itself nor on its descendants.
+fn drop_target<Key, Message, Renderer>(
+    rows: &[Row<'_, Key, Message, Renderer>],
+    layout: Layout<'_>,
+    dragged: &Key,
+    position: Point,
+) -> Option<(usize, Position)>
+where
+    Key: PartialEq,
+{
+    let (index, bounds) = layout
+        .children()
+        .map(|row| row.bounds())
+        .enumerate()
+        .find(|(_, bounds)| bounds.contains(position))?;
+
+    let mut ancestor = Some(index);
+
+    while let Some(current) = ancestor {
+        if &rows[current].key == dragged {
+            return None;
+        }
+
+        ancestor = rows[current].parent;
+    }
+
+    let ratio = (position.y - bounds.y) / bounds.height;
+
+    let position = if ratio < 0.25 {
+        Position::Before
+    } else if ratio > 0.75 {
+        Position::After
+    } else {
+        Position::Inside
+    };
+
+    Some((index, position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{navigate, Action, Node, Row};
+    use crate::core::keyboard::KeyCode;
+    use crate::Space;
+
+    fn rows() -> Vec<Row<'static, usize, (), crate::Renderer>> {
+        let leaf = |key| Node::new(key, Space::with_width(0));
+
+        let mut rows = Vec::new();
+
+        Node::new(0, Space::with_width(0))
+            .expanded(true)
+            .push(leaf(1).expandable(true))
+            .push(leaf(2))
+            .flatten(0, None, &mut rows);
+
+        rows
+    }
+
+    #[test]
+    fn arrow_keys_navigate_the_hierarchy() {
+        let rows = rows();
+
+        assert_eq!(navigate(&rows, None, KeyCode::Down), Some(Action::Move(0)));
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Right),
+            Some(Action::Move(1))
+        );
+        assert_eq!(
+            navigate(&rows, Some(1), KeyCode::Right),
+            Some(Action::Toggle(1, true))
+        );
+        assert_eq!(
+            navigate(&rows, Some(2), KeyCode::Left),
+            Some(Action::Move(0))
+        );
+        assert_eq!(
+            navigate(&rows, Some(0), KeyCode::Left),
+            Some(Action::Toggle(0, false))
+        );
+        assert_eq!(
+            navigate(&rows, Some(2), KeyCode::Down),
+            Some(Action::Move(2))
+        );
+        assert_eq!(navigate(&rows, Some(2), KeyCode::Right), None);
+    }
+}
@@ -0,0 +1,270 @@
+//! Publish the accessibility tree of a user interface with [`accesskit`].
+//!
+//! [`accesskit`]: https://github.com/AccessKit/accesskit
+use crate::core::accessibility::{self, Tree};
+use crate::core::{Event, Rectangle};
+
+use accesskit::{
+    ActionData, ActionHandler, ActionRequest, CheckedState, NodeBuilder,
+    NodeClassSet, NodeId, Rect, Role, TreeUpdate,
+};
+
+use std::num::NonZeroU128;
+use std::sync::{Arc, Mutex};
+
+/// The [`NodeId`] of the window, which is the root of the accessibility tree.
+const ROOT: NodeId = NodeId(match NonZeroU128::new(1) {
+    Some(id) => id,
+    None => unreachable!(),
+});
+
+/// Publishes the accessibility [`Tree`] of a window to assistive
+/// technologies and collects the actions they request.
+///
+/// Requested actions are queued and the window is asked to redraw, which
+/// wakes up the event loop; call [`Adapter::events`] to turn them into
+/// [`Event::Accessibility`] events for the user interface.
+#[allow(missing_debug_implementations)]
+pub struct Adapter {
+    raw: accesskit_winit::Adapter,
+    requests: Arc<Mutex<Vec<ActionRequest>>>,
+    tree: Tree,
+    classes: NodeClassSet,
+}
+
+impl Adapter {
+    /// Creates a new [`Adapter`] for the given window.
+    ///
+    /// The window must not be visible yet.
+    pub fn new(window: &Arc<winit::window::Window>, title: &str) -> Self {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let initial_tree = {
+            let title = title.to_owned();
+
+            move || {
+                let mut root = NodeBuilder::new(Role::Window);
+                root.set_name(title);
+
+                TreeUpdate {
+                    nodes: vec![(ROOT, root.build(&mut NodeClassSet::new()))],
+                    tree: Some(accesskit::Tree::new(ROOT)),
+                    focus: None,
+                }
+            }
+        };
+
+        let raw = accesskit_winit::Adapter::with_action_handler(
+            window,
+            initial_tree,
+            Box::new(Handler {
+                requests: requests.clone(),
+                window: window.clone(),
+            }),
+        );
+
+        Self {
+            raw,
+            requests,
+            tree: Tree::default(),
+            classes: NodeClassSet::new(),
+        }
+    }
+
+    /// Lets the [`Adapter`] process a window event.
+    ///
+    /// Returns true if the event should be processed by the application.
+    pub fn on_event(
+        &self,
+        window: &winit::window::Window,
+        event: &winit::event::WindowEvent<'_>,
+    ) -> bool {
+        self.raw.on_event(window, event)
+    }
+
+    /// Publishes the accessibility [`Tree`] produced by the given function,
+    /// if any assistive technology is listening.
+    pub fn update(
+        &mut self,
+        title: &str,
+        scale_factor: f64,
+        tree: impl FnOnce() -> Tree,
+    ) {
+        let Self {
+            raw,
+            tree: current,
+            classes,
+            ..
+        } = self;
+
+        raw.update_if_active(|| {
+            *current = tree();
+
+            tree_update(current, title, scale_factor, classes)
+        });
+    }
+
+    /// Returns the [`Event`] of the actions requested since the last call.
+    pub fn events(&mut self) -> Vec<Event> {
+        let requests = std::mem::take(
+            &mut *self.requests.lock().expect("Lock accessibility requests"),
+        );
+
+        requests
+            .into_iter()
+            .filter_map(|request| {
+                let entry = self
+                    .tree
+                    .iter()
+                    .find(|entry| node_id(entry.id) == request.target)?;
+
+                let action = match request.action {
+                    accesskit::Action::Focus => accessibility::Action::Focus,
+                    accesskit::Action::Default => accessibility::Action::Click,
+                    accesskit::Action::SetValue => {
+                        accessibility::Action::SetValue
+                    }
+                    _ => return None,
+                };
+
+                if !entry.node.actions.contains(&action) {
+                    return None;
+                }
+
+                let value = match request.data {
+                    Some(ActionData::Value(value)) => Some(value.into()),
+                    Some(ActionData::NumericValue(value)) => {
+                        Some(value.to_string())
+                    }
+                    _ => None,
+                };
+
+                Some(Event::Accessibility(entry.event(action, value)))
+            })
+            .collect()
+    }
+}
+
+struct Handler {
+    requests: Arc<Mutex<Vec<ActionRequest>>>,
+    window: Arc<winit::window::Window>,
+}
+
+impl ActionHandler for Handler {
+    fn do_action(&self, request: ActionRequest) {
+        self.requests
+            .lock()
+            .expect("Lock accessibility requests")
+            .push(request);
+
+        // The event loop may be waiting for events, and the requests are
+        // only processed once it wakes up
+        self.window.request_redraw();
+    }
+}
+
+/// Converts an accessibility [`Tree`] into a [`TreeUpdate`].
+pub fn tree_update(
+    tree: &Tree,
+    title: &str,
+    scale_factor: f64,
+    classes: &mut NodeClassSet,
+) -> TreeUpdate {
+    let mut root = NodeBuilder::new(Role::Window);
+    root.set_name(title);
+    root.set_children(
+        tree.roots()
+            .iter()
+            .copied()
+            .map(node_id)
+            .collect::<Vec<_>>(),
+    );
+
+    let mut nodes = vec![(ROOT, root.build(classes))];
+
+    nodes.extend(tree.iter().map(|entry| {
+        let node = &entry.node;
+        let mut builder = NodeBuilder::new(role(node.role));
+
+        if let Some(label) = &node.label {
+            builder.set_name(label.as_str());
+        }
+
+        if let Some(value) = &node.value {
+            builder.set_value(value.as_str());
+        }
+
+        if let Some(toggled) = node.toggled {
+            builder.set_checked_state(if toggled {
+                CheckedState::True
+            } else {
+                CheckedState::False
+            });
+        }
+
+        if node.is_disabled {
+            builder.set_disabled();
+        }
+
+        for action in &node.actions {
+            builder.add_action(match action {
+                accessibility::Action::Focus => accesskit::Action::Focus,
+                accessibility::Action::Click => accesskit::Action::Default,
+                accessibility::Action::SetValue => accesskit::Action::SetValue,
+            });
+        }
+
+        builder.set_bounds(rect(entry.visible_bounds(), scale_factor));
+        builder.set_children(
+            entry
+                .children
+                .iter()
+                .copied()
+                .map(node_id)
+                .collect::<Vec<_>>(),
+        );
+
+        (node_id(entry.id), builder.build(classes))
+    }));
+
+    TreeUpdate {
+        nodes,
+        tree: Some(accesskit::Tree::new(ROOT)),
+        focus: tree.focus().map(node_id),
+    }
+}
+
+/// Converts an [`accessibility::Id`] into a [`NodeId`].
+pub fn node_id(id: accessibility::Id) -> NodeId {
+    // The root of the tree takes the first identifier
+    NodeId(
+        NonZeroU128::new(u128::from(id.0) + 2)
+            .expect("Accessibility id is non-zero"),
+    )
+}
+
+/// Converts an [`accessibility::Role`] into a [`Role`].
+pub fn role(role: accessibility::Role) -> Role {
+    match role {
+        accessibility::Role::Button => Role::Button,
+        accessibility::Role::CheckBox => Role::CheckBox,
+        accessibility::Role::RadioButton => Role::RadioButton,
+        accessibility::Role::Toggler => Role::Switch,
+        accessibility::Role::Slider => Role::Slider,
+        accessibility::Role::TextInput => Role::TextField,
+        accessibility::Role::Text => Role::StaticText,
+        accessibility::Role::Image => Role::Image,
+        accessibility::Role::ProgressBar => Role::ProgressIndicator,
+        accessibility::Role::ScrollView => Role::ScrollView,
+        accessibility::Role::Group => Role::Group,
+    }
+}
+
+fn rect(bounds: Rectangle, scale_factor: f64) -> Rect {
+    Rect {
+        x0: f64::from(bounds.x) * scale_factor,
+        y0: f64::from(bounds.y) * scale_factor,
+        x1: f64::from(bounds.x + bounds.width) * scale_factor,
+        y1: f64::from(bounds.y + bounds.height) * scale_factor,
+    }
+}
@@ -26,6 +26,7 @@ use crate::{Clipboard, Error, Proxy, Settings};
 use futures::channel::mpsc;
 
 use std::mem::ManuallyDrop;
+use std::sync::Arc;
 
 #[cfg(feature = "trace")]
 pub use profiler::Profiler;
@@ -148,14 +149,15 @@ where
     let target = settings.window.platform_specific.target.clone();
 
     let should_be_visible = settings.window.visible;
-    let builder = settings
-        .window
-        .into_builder(
-            &application.title(),
-            event_loop.primary_monitor(),
-            settings.id,
-        )
-        .with_visible(false);
+    let exit_on_close_request = settings.window.exit_on_close_request;
+
+    let builder = conversion::window_settings(
+        settings.window,
+        &application.title(),
+        event_loop.primary_monitor(),
+        settings.id,
+    )
+    .with_visible(false);
 
     log::debug!("Window builder: {builder:#?}");
 
@@ -209,9 +211,9 @@ where
             event_receiver,
             control_sender,
             init_command,
-            window,
+            Arc::new(window),
             should_be_visible,
-            settings.exit_on_close_request,
+            exit_on_close_request,
         );
 
         #[cfg(feature = "trace")]
@@ -276,7 +278,7 @@ async fn run_instance<A, E, C>(
     >,
     mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     init_command: Command<A::Message>,
-    window: winit::window::Window,
+    window: Arc<winit::window::Window>,
     should_be_visible: bool,
     exit_on_close_request: bool,
 ) where
@@ -296,12 +298,16 @@ async fn run_instance<A, E, C>(
     let mut clipboard = Clipboard::connect(&window);
     let mut cache = user_interface::Cache::default();
     let mut surface = compositor.create_surface(
-        &window,
+        &*window,
         physical_size.width,
         physical_size.height,
     );
     let mut should_exit = false;
 
+    #[cfg(feature = "a11y")]
+    let mut accessibility =
+        crate::accessibility::Adapter::new(&window, state.title());
+
     if should_be_visible {
         window.set_visible(true);
     }
@@ -349,6 +355,9 @@ async fn run_instance<A, E, C>(
                 );
             }
             event::Event::MainEventsCleared => {
+                #[cfg(feature = "a11y")]
+                events.extend(accessibility.events());
+
                 if !redraw_pending && events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -419,6 +428,7 @@ async fn run_instance<A, E, C>(
                 // Then, we can use the `interface_state` here to decide if a redraw
                 // is needed right away, or simply wait until a specific time.
                 let redraw_event = Event::Window(
+                    window::Id::MAIN,
                     window::Event::RedrawRequested(Instant::now()),
                 );
 
@@ -430,6 +440,26 @@ async fn run_instance<A, E, C>(
                     &mut messages,
                 );
 
+                if let user_interface::State::Updated { input_method, .. } =
+                    interface_state
+                {
+                    state.request_input_method(&window, input_method);
+                }
+
+                #[cfg(feature = "a11y")]
+                accessibility.update(
+                    state.title(),
+                    state.scale_factor(),
+                    || {
+                        let mut builder =
+                            core::widget::operation::accessibility::Builder::new();
+
+                        user_interface.operate(&renderer, &mut builder);
+
+                        builder.build()
+                    },
+                );
+
                 debug.draw_started();
                 let new_mouse_interaction = user_interface.draw(
                     &mut renderer,
@@ -455,6 +485,7 @@ async fn run_instance<A, E, C>(
                 let _ = control_sender.start_send(match interface_state {
                     user_interface::State::Updated {
                         redraw_request: Some(redraw_request),
+                        ..
                     } => match redraw_request {
                         window::RedrawRequest::NextFrame => ControlFlow::Poll,
                         window::RedrawRequest::At(at) => {
@@ -568,9 +599,13 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                #[cfg(feature = "a11y")]
+                let _ = accessibility.on_event(&window, &window_event);
+
                 state.update(&window, &window_event, &mut debug);
 
                 if let Some(event) = conversion::window_event(
+                    window::Id::MAIN,
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
@@ -742,19 +777,25 @@ pub fn run_command<A, C, E>(
                 }
             },
             command::Action::Window(action) => match action {
-                window::Action::Close => {
+                window::Action::Close(_id) => {
                     *should_exit = true;
                 }
-                window::Action::Drag => {
+                window::Action::Spawn(_id, _settings) => {
+                    log::warn!(
+                        "Spawning a window is only available with \
+                        multi-window applications."
+                    );
+                }
+                window::Action::Drag(_id) => {
                     let _res = window.drag_window();
                 }
-                window::Action::Resize(size) => {
+                window::Action::Resize(_id, size) => {
                     window.set_inner_size(winit::dpi::LogicalSize {
                         width: size.width,
                         height: size.height,
                     });
                 }
-                window::Action::FetchSize(callback) => {
+                window::Action::FetchSize(_id, callback) => {
                     let size = window.inner_size();
 
                     proxy
@@ -764,29 +805,29 @@ pub fn run_command<A, C, E>(
                         )))
                         .expect("Send message to event loop");
                 }
-                window::Action::Maximize(maximized) => {
+                window::Action::Maximize(_id, maximized) => {
                     window.set_maximized(maximized);
                 }
-                window::Action::Minimize(minimized) => {
+                window::Action::Minimize(_id, minimized) => {
                     window.set_minimized(minimized);
                 }
-                window::Action::Move { x, y } => {
+                window::Action::Move { x, y, .. } => {
                     window.set_outer_position(winit::dpi::LogicalPosition {
                         x,
                         y,
                     });
                 }
-                window::Action::ChangeMode(mode) => {
+                window::Action::ChangeMode(_id, mode) => {
                     window.set_visible(conversion::visible(mode));
                     window.set_fullscreen(conversion::fullscreen(
                         window.current_monitor(),
                         mode,
                     ));
                 }
-                window::Action::ChangeIcon(icon) => {
+                window::Action::ChangeIcon(_id, icon) => {
                     window.set_window_icon(conversion::icon(icon));
                 }
-                window::Action::FetchMode(tag) => {
+                window::Action::FetchMode(_id, tag) => {
                     let mode = if window.is_visible().unwrap_or(true) {
                         conversion::mode(window.fullscreen())
                     } else {
@@ -797,29 +838,29 @@ pub fn run_command<A, C, E>(
                         .send_event(tag(mode))
                         .expect("Send message to event loop");
                 }
-                window::Action::ToggleMaximize => {
+                window::Action::ToggleMaximize(_id) => {
                     window.set_maximized(!window.is_maximized());
                 }
-                window::Action::ToggleDecorations => {
+                window::Action::ToggleDecorations(_id) => {
                     window.set_decorations(!window.is_decorated());
                 }
-                window::Action::RequestUserAttention(user_attention) => {
+                window::Action::RequestUserAttention(_id, user_attention) => {
                     window.request_user_attention(
                         user_attention.map(conversion::user_attention),
                     );
                 }
-                window::Action::GainFocus => {
+                window::Action::GainFocus(_id) => {
                     window.focus_window();
                 }
-                window::Action::ChangeLevel(level) => {
+                window::Action::ChangeLevel(_id, level) => {
                     window.set_window_level(conversion::window_level(level));
                 }
-                window::Action::FetchId(tag) => {
+                window::Action::FetchId(_id, tag) => {
                     proxy
                         .send_event(tag(window.id().into()))
                         .expect("Send message to event loop");
                 }
-                window::Action::Screenshot(tag) => {
+                window::Action::Screenshot(_id, tag) => {
                     let bytes = compositor.screenshot(
                         renderer,
                         surface,
@@ -902,7 +943,7 @@ pub fn run_command<A, C, E>(
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         mut event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
@@ -924,7 +965,7 @@ mod platform {
 }
 
 #[cfg(target_arch = "wasm32")]
-mod platform {
+pub(crate) mod platform {
     pub fn run<T, F>(
         event_loop: winit::event_loop::EventLoop<T>,
         event_handler: F,
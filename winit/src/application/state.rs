@@ -2,7 +2,7 @@ use crate::application::{self, StyleSheet as _};
 use crate::conversion;
 use crate::core;
 use crate::core::mouse;
-use crate::core::{Color, Size};
+use crate::core::{Color, InputMethod, Size};
 use crate::graphics::Viewport;
 use crate::runtime::Debug;
 use crate::Application;
@@ -25,6 +25,7 @@ where
     modifiers: winit::event::ModifiersState,
     theme: <A::Renderer as core::Renderer>::Theme,
     appearance: application::Appearance,
+    input_method: InputMethod,
     application: PhantomData<A>,
 }
 
@@ -57,10 +58,16 @@ where
             modifiers: winit::event::ModifiersState::default(),
             theme,
             appearance,
+            input_method: InputMethod::Disabled,
             application: PhantomData,
         }
     }
 
+    /// Returns the current title of the window of the [`State`].
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
     /// Returns the current [`Viewport`] of the [`State`].
     pub fn viewport(&self) -> &Viewport {
         &self.viewport
@@ -217,4 +224,35 @@ where
         self.theme = application.theme();
         self.appearance = self.theme.appearance(&application.style());
     }
+
+    /// Applies the [`InputMethod`] requested by the user interface to the
+    /// window, if it changed.
+    pub fn request_input_method(
+        &mut self,
+        window: &Window,
+        input_method: InputMethod,
+    ) {
+        if self.input_method == input_method {
+            return;
+        }
+
+        match input_method {
+            InputMethod::Disabled => {
+                window.set_ime_allowed(false);
+            }
+            InputMethod::Enabled { position, purpose } => {
+                if !self.input_method.is_enabled() {
+                    window.set_ime_allowed(true);
+                }
+
+                window.set_ime_purpose(conversion::ime_purpose(purpose));
+                window.set_ime_position(conversion::physical_position(
+                    position,
+                    self.viewport.scale_factor(),
+                ));
+            }
+        }
+
+        self.input_method = input_method;
+    }
 }
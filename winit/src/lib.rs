@@ -33,17 +33,20 @@ pub use iced_runtime::futures;
 pub use iced_style as style;
 pub use winit;
 
+#[cfg(feature = "a11y")]
+pub mod accessibility;
 #[cfg(feature = "application")]
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+#[cfg(feature = "multi-window")]
+pub mod multi_window;
 pub mod settings;
 
 #[cfg(feature = "system")]
 pub mod system;
 
 mod error;
-mod position;
 mod proxy;
 
 #[cfg(feature = "application")]
@@ -52,7 +55,6 @@ pub use application::Application;
 pub use application::Profiler;
 pub use clipboard::Clipboard;
 pub use error::Error;
-pub use position::Position;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
@@ -0,0 +1,1124 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+mod window_manager;
+
+pub use state::State;
+
+use crate::application::{platform, requests_exit};
+use crate::conversion;
+use crate::core;
+use crate::core::renderer;
+use crate::core::time::Instant;
+use crate::core::widget::operation;
+use crate::core::window;
+use crate::core::Size;
+use crate::futures::futures;
+use crate::futures::{Executor, Runtime, Subscription};
+use crate::graphics::compositor::{self, Compositor};
+use crate::runtime::clipboard;
+use crate::runtime::multi_window::Program;
+use crate::runtime::user_interface::{self, UserInterface};
+use crate::runtime::{Command, Debug};
+use crate::style::application::StyleSheet;
+use crate::{Clipboard, Error, Proxy, Settings};
+
+use window_manager::WindowManager;
+
+use futures::channel::mpsc;
+
+use std::collections::HashMap;
+use std::mem::ManuallyDrop;
+
+/// An interactive, native, cross-platform, multi-windowed application.
+///
+/// This trait is the main entrypoint of multi-window Iced. Once implemented,
+/// you can run your GUI application by simply calling [`run`]. It will run in
+/// its own window.
+///
+/// Additional windows can be opened with [`window::spawn`] and closed with
+/// [`window::close`].
+///
+/// When using an [`Application`] with the `debug` feature enabled, a debug view
+/// can be toggled by pressing `F12`.
+///
+/// [`window::spawn`]: crate::runtime::window::spawn
+/// [`window::close`]: crate::runtime::window::close
+pub trait Application: Program
+where
+    <Self::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    /// The data needed to initialize your [`Application`].
+    type Flags;
+
+    /// Initializes the [`Application`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// Here is where you should return the initial state of your app.
+    ///
+    /// Additionally, you can return a [`Command`] if you need to perform some
+    /// async action in the background on startup. This is useful if you want to
+    /// load state from a file, perform an initial HTTP request, etc.
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given [`window::Id`].
+    ///
+    /// This title can be dynamic! The runtime will automatically update the
+    /// title of your windows when necessary.
+    fn title(&self, window: window::Id) -> String;
+
+    /// Returns the current `Theme` of the window with the given
+    /// [`window::Id`].
+    fn theme(
+        &self,
+        window: window::Id,
+    ) -> <Self::Renderer as core::Renderer>::Theme;
+
+    /// Returns the `Style` variation of the `Theme`.
+    fn style(
+        &self,
+    ) -> <<Self::Renderer as core::Renderer>::Theme as StyleSheet>::Style {
+        Default::default()
+    }
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// The messages produced by the `Subscription` will be handled by
+    /// [`update`](#tymethod.update).
+    ///
+    /// A `Subscription` will be kept alive as long as you keep returning it!
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// It can be used to dynamically control the size of the UI at runtime
+    /// (i.e. zooming).
+    ///
+    /// For instance, a scale factor of `2.0` will make widgets twice as big,
+    /// while a scale factor of `0.5` will shrink them to half their size.
+    ///
+    /// By default, it returns `1.0`.
+    #[allow(unused_variables)]
+    fn scale_factor(&self, window: window::Id) -> f64 {
+        1.0
+    }
+}
+
+enum Event<Message: 'static> {
+    EventLoopAwakened(winit::event::Event<'static, Message>),
+    WindowCreated {
+        id: window::Id,
+        window: winit::window::Window,
+        exit_on_close_request: bool,
+        should_be_visible: bool,
+    },
+}
+
+enum Control {
+    ChangeFlow(winit::event_loop::ControlFlow),
+    CreateWindow {
+        id: window::Id,
+        settings: window::Settings,
+        title: String,
+    },
+}
+
+/// Runs an [`Application`] with an executor, compositor, and the provided
+/// settings.
+///
+/// The [`Settings`] configure the main window of the [`Application`], which
+/// is identified by [`window::Id::MAIN`].
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoopBuilder;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoopBuilder::with_user_event().build();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let should_main_be_visible = settings.window.visible;
+    let exit_on_close_request = settings.window.exit_on_close_request;
+
+    let builder = conversion::window_settings(
+        settings.window,
+        &application.title(window::Id::MAIN),
+        event_loop.primary_monitor(),
+        settings.id,
+    )
+    .with_visible(false);
+
+    log::debug!("Window builder: {builder:#?}");
+
+    let main_window = builder
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let (compositor, renderer) =
+        C::new(compositor_settings, Some(&main_window))?;
+
+    let (mut event_sender, event_receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        compositor,
+        renderer,
+        runtime,
+        proxy,
+        debug,
+        event_receiver,
+        control_sender,
+        init_command,
+        main_window,
+        should_main_be_visible,
+        exit_on_close_request,
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    platform::run(event_loop, move |event, window_target, control_flow| {
+        use winit::event_loop::ControlFlow;
+
+        if let ControlFlow::ExitWithCode(_) = control_flow {
+            return;
+        }
+
+        let event = match event {
+            winit::event::Event::WindowEvent {
+                event:
+                    winit::event::WindowEvent::ScaleFactorChanged {
+                        new_inner_size,
+                        ..
+                    },
+                window_id,
+            } => Some(winit::event::Event::WindowEvent {
+                event: winit::event::WindowEvent::Resized(*new_inner_size),
+                window_id,
+            }),
+            _ => event.to_static(),
+        };
+
+        if let Some(event) = event {
+            event_sender
+                .start_send(Event::EventLoopAwakened(event))
+                .expect("Send event");
+
+            loop {
+                let poll = instance.as_mut().poll(&mut context);
+
+                match poll {
+                    task::Poll::Pending => match control_receiver.try_next() {
+                        Ok(Some(control)) => match control {
+                            Control::ChangeFlow(flow) => {
+                                *control_flow = flow;
+                            }
+                            Control::CreateWindow {
+                                id,
+                                settings,
+                                title,
+                            } => {
+                                let exit_on_close_request =
+                                    settings.exit_on_close_request;
+                                let should_be_visible = settings.visible;
+
+                                let window = conversion::window_settings(
+                                    settings,
+                                    &title,
+                                    window_target.primary_monitor(),
+                                    None,
+                                )
+                                .with_visible(false)
+                                .build(window_target)
+                                .expect("Build window");
+
+                                event_sender
+                                    .start_send(Event::WindowCreated {
+                                        id,
+                                        window,
+                                        exit_on_close_request,
+                                        should_be_visible,
+                                    })
+                                    .expect("Send event");
+                            }
+                        },
+                        _ => {
+                            break;
+                        }
+                    },
+                    task::Poll::Ready(_) => {
+                        *control_flow = ControlFlow::Exit;
+                        break;
+                    }
+                };
+            }
+        }
+    })
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut event_receiver: mpsc::UnboundedReceiver<Event<A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<Control>,
+    init_command: Command<A::Message>,
+    main_window: winit::window::Window,
+    should_main_be_visible: bool,
+    exit_on_main_close_request: bool,
+) where
+    A: Application + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use futures::stream::StreamExt;
+    use winit::event;
+    use winit::event_loop::ControlFlow;
+
+    let mut clipboard = Clipboard::connect(&main_window);
+    let mut window_manager = WindowManager::new();
+
+    let main_window = window_manager.insert(
+        window::Id::MAIN,
+        main_window,
+        &application,
+        &mut compositor,
+        exit_on_main_close_request,
+    );
+
+    if should_main_be_visible {
+        main_window.raw.set_visible(true);
+    }
+
+    let mut ui_caches = HashMap::new();
+    let _ =
+        ui_caches.insert(window::Id::MAIN, user_interface::Cache::default());
+
+    run_command(
+        &application,
+        &mut compositor,
+        &mut renderer,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut control_sender,
+        &mut proxy,
+        &mut debug,
+        &mut window_manager,
+        &mut ui_caches,
+    );
+    runtime.track(application.subscription().into_recipes());
+
+    let mut user_interfaces = ManuallyDrop::new(build_user_interfaces(
+        &application,
+        &mut debug,
+        &mut window_manager,
+        ui_caches,
+        &mut renderer,
+    ));
+
+    let mut events: Vec<(Option<window::Id>, core::Event)> = Vec::new();
+    let mut messages = Vec::new();
+    let mut redraw_pending = false;
+
+    debug.startup_finished();
+
+    'main: while let Some(event) = event_receiver.next().await {
+        match event {
+            Event::WindowCreated {
+                id,
+                window,
+                exit_on_close_request,
+                should_be_visible,
+            } => {
+                let window = window_manager.insert(
+                    id,
+                    window,
+                    &application,
+                    &mut compositor,
+                    exit_on_close_request,
+                );
+
+                if should_be_visible {
+                    window.raw.set_visible(true);
+                }
+
+                let logical_size = window.state.logical_size();
+
+                let _ = user_interfaces.insert(
+                    id,
+                    build_user_interface(
+                        &application,
+                        user_interface::Cache::default(),
+                        &mut renderer,
+                        logical_size,
+                        &mut debug,
+                        id,
+                    ),
+                );
+            }
+            Event::EventLoopAwakened(event) => match event {
+                event::Event::NewEvents(start_cause) => {
+                    redraw_pending = matches!(
+                        start_cause,
+                        event::StartCause::Init
+                            | event::StartCause::Poll
+                            | event::StartCause::ResumeTimeReached { .. }
+                    );
+                }
+                event::Event::MainEventsCleared => {
+                    if window_manager.is_empty() {
+                        break 'main;
+                    }
+
+                    #[cfg(feature = "a11y")]
+                    for (id, window) in window_manager.iter_mut() {
+                        events.extend(
+                            window
+                                .accessibility
+                                .events()
+                                .into_iter()
+                                .map(|event| (Some(id), event)),
+                        );
+                    }
+
+                    if !redraw_pending
+                        && events.is_empty()
+                        && messages.is_empty()
+                    {
+                        continue;
+                    }
+
+                    debug.event_processing_started();
+                    let mut uis_stale = false;
+
+                    for (id, window) in window_manager.iter_mut() {
+                        let window_events: Vec<_> = events
+                            .iter()
+                            .filter(|(window_id, _)| {
+                                window_id.is_none() || *window_id == Some(id)
+                            })
+                            .map(|(_, event)| event.clone())
+                            .collect();
+
+                        let Some(user_interface) = user_interfaces.get_mut(&id)
+                        else {
+                            continue;
+                        };
+
+                        let (ui_state, statuses) = user_interface.update(
+                            &window_events,
+                            window.state.cursor(),
+                            &mut renderer,
+                            &mut clipboard,
+                            &mut messages,
+                        );
+
+                        uis_stale = uis_stale
+                            || matches!(
+                                ui_state,
+                                user_interface::State::Outdated
+                            );
+
+                        for (event, status) in
+                            window_events.into_iter().zip(statuses)
+                        {
+                            runtime.broadcast(event, status);
+                        }
+                    }
+
+                    events.clear();
+                    debug.event_processing_finished();
+
+                    if !messages.is_empty() || uis_stale {
+                        let mut cached_interfaces: HashMap<_, _> =
+                            ManuallyDrop::into_inner(user_interfaces)
+                                .into_iter()
+                                .map(|(id, ui)| (id, ui.into_cache()))
+                                .collect();
+
+                        // Update application
+                        update(
+                            &mut application,
+                            &mut compositor,
+                            &mut renderer,
+                            &mut runtime,
+                            &mut clipboard,
+                            &mut control_sender,
+                            &mut proxy,
+                            &mut debug,
+                            &mut messages,
+                            &mut window_manager,
+                            &mut cached_interfaces,
+                        );
+
+                        // Update windows
+                        for (id, window) in window_manager.iter_mut() {
+                            window.state.synchronize(
+                                &application,
+                                id,
+                                &window.raw,
+                            );
+                        }
+
+                        user_interfaces =
+                            ManuallyDrop::new(build_user_interfaces(
+                                &application,
+                                &mut debug,
+                                &mut window_manager,
+                                cached_interfaces,
+                                &mut renderer,
+                            ));
+
+                        if window_manager.is_empty() {
+                            break 'main;
+                        }
+                    }
+
+                    // TODO: Avoid redrawing all the time by forcing widgets to
+                    // request redraws on state changes
+                    //
+                    // Then, we can use the `interface_state` here to decide if a redraw
+                    // is needed right away, or simply wait until a specific time.
+                    let mut next_redraw: Option<window::RedrawRequest> = None;
+
+                    for (id, window) in window_manager.iter_mut() {
+                        let Some(user_interface) = user_interfaces.get_mut(&id)
+                        else {
+                            continue;
+                        };
+
+                        let redraw_event = core::Event::Window(
+                            id,
+                            window::Event::RedrawRequested(Instant::now()),
+                        );
+
+                        let (ui_state, _) = user_interface.update(
+                            &[redraw_event.clone()],
+                            window.state.cursor(),
+                            &mut renderer,
+                            &mut clipboard,
+                            &mut messages,
+                        );
+
+                        if let user_interface::State::Updated {
+                            redraw_request,
+                            input_method,
+                        } = ui_state
+                        {
+                            if let Some(redraw_request) = redraw_request {
+                                next_redraw = Some(match next_redraw {
+                                    Some(current) => {
+                                        current.min(redraw_request)
+                                    }
+                                    None => redraw_request,
+                                });
+                            }
+
+                            window.state.request_input_method(
+                                &window.raw,
+                                input_method,
+                            );
+                        }
+
+                        #[cfg(feature = "a11y")]
+                        window.accessibility.update(
+                            window.state.title(),
+                            window.state.scale_factor(),
+                            || {
+                                let mut builder = core::widget::operation::accessibility::Builder::new();
+
+                                user_interface.operate(&renderer, &mut builder);
+
+                                builder.build()
+                            },
+                        );
+
+                        window.raw.request_redraw();
+                        runtime.broadcast(
+                            redraw_event,
+                            core::event::Status::Ignored,
+                        );
+                    }
+
+                    let _ = control_sender.start_send(Control::ChangeFlow(
+                        match next_redraw {
+                            Some(window::RedrawRequest::NextFrame) => {
+                                ControlFlow::Poll
+                            }
+                            Some(window::RedrawRequest::At(at)) => {
+                                ControlFlow::WaitUntil(at)
+                            }
+                            None => ControlFlow::Wait,
+                        },
+                    ));
+
+                    redraw_pending = false;
+                }
+                event::Event::PlatformSpecific(
+                    event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
+                        url,
+                    )),
+                ) => {
+                    use crate::core::event;
+
+                    events.push((
+                        None,
+                        core::Event::PlatformSpecific(
+                            event::PlatformSpecific::MacOS(
+                                event::MacOS::ReceivedUrl(url),
+                            ),
+                        ),
+                    ));
+                }
+                event::Event::UserEvent(message) => {
+                    messages.push(message);
+                }
+                event::Event::RedrawRequested(window_id) => {
+                    let Some((id, window)) =
+                        window_manager.get_mut_alias(window_id)
+                    else {
+                        continue;
+                    };
+
+                    let physical_size = window.state.physical_size();
+
+                    if physical_size.width == 0 || physical_size.height == 0 {
+                        continue;
+                    }
+
+                    debug.render_started();
+
+                    let current_viewport_version =
+                        window.state.viewport_version();
+
+                    if window.viewport_version != current_viewport_version {
+                        let logical_size = window.state.logical_size();
+
+                        if let Some(user_interface) =
+                            user_interfaces.remove(&id)
+                        {
+                            debug.layout_started();
+                            let _ = user_interfaces.insert(
+                                id,
+                                user_interface
+                                    .relayout(logical_size, &mut renderer),
+                            );
+                            debug.layout_finished();
+                        }
+
+                        compositor.configure_surface(
+                            &mut window.surface,
+                            physical_size.width,
+                            physical_size.height,
+                        );
+
+                        window.viewport_version = current_viewport_version;
+                    }
+
+                    // The renderer is shared by every window, so the user
+                    // interface must be drawn right before presenting it.
+                    if let Some(user_interface) = user_interfaces.get_mut(&id) {
+                        debug.draw_started();
+                        let new_mouse_interaction = user_interface.draw(
+                            &mut renderer,
+                            window.state.theme(),
+                            &renderer::Style {
+                                text_color: window.state.text_color(),
+                            },
+                            window.state.cursor(),
+                        );
+                        debug.draw_finished();
+
+                        if new_mouse_interaction != window.mouse_interaction {
+                            window.raw.set_cursor_icon(
+                                conversion::mouse_interaction(
+                                    new_mouse_interaction,
+                                ),
+                            );
+
+                            window.mouse_interaction = new_mouse_interaction;
+                        }
+                    }
+
+                    match compositor.present(
+                        &mut renderer,
+                        &mut window.surface,
+                        window.state.viewport(),
+                        window.state.background_color(),
+                        &debug.overlay(),
+                    ) {
+                        Ok(()) => {
+                            debug.render_finished();
+                        }
+                        Err(error) => match error {
+                            // This is an unrecoverable error.
+                            compositor::SurfaceError::OutOfMemory => {
+                                panic!("{error:?}");
+                            }
+                            _ => {
+                                debug.render_finished();
+
+                                // Try rendering again next frame.
+                                window.raw.request_redraw();
+                            }
+                        },
+                    }
+                }
+                event::Event::WindowEvent {
+                    event: window_event,
+                    window_id,
+                } => {
+                    let Some((id, window)) =
+                        window_manager.get_mut_alias(window_id)
+                    else {
+                        continue;
+                    };
+
+                    #[cfg(feature = "a11y")]
+                    let _ = window
+                        .accessibility
+                        .on_event(&window.raw, &window_event);
+
+                    if requests_exit(&window_event, window.state.modifiers())
+                        && window.exit_on_close_request
+                    {
+                        let _ = window_manager.remove(id);
+                        let _ = user_interfaces.remove(&id);
+
+                        if window_manager.is_empty() {
+                            break 'main;
+                        }
+                    } else {
+                        window.state.update(
+                            &window.raw,
+                            &window_event,
+                            &mut debug,
+                        );
+
+                        if let Some(event) = conversion::window_event(
+                            id,
+                            &window_event,
+                            window.state.scale_factor(),
+                            window.state.modifiers(),
+                        ) {
+                            events.push((Some(id), event));
+                        }
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    // Manually drop the user interfaces
+    drop(ManuallyDrop::into_inner(user_interfaces));
+}
+
+/// Builds a [`UserInterface`] for the window with the given [`window::Id`] of
+/// the provided [`Application`], logging [`struct@Debug`] information
+/// accordingly.
+pub fn build_user_interface<'a, A: Application>(
+    application: &'a A,
+    cache: user_interface::Cache,
+    renderer: &mut A::Renderer,
+    size: Size,
+    debug: &mut Debug,
+    id: window::Id,
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build(view, size, cache, renderer);
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Updates a multi-window [`Application`] by feeding it the provided
+/// messages, spawning any resulting [`Command`], and tracking its
+/// [`Subscription`].
+fn update<A: Application, C, E: Executor>(
+    application: &mut A,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    window_manager: &mut WindowManager<A, C>,
+    ui_caches: &mut HashMap<window::Id, user_interface::Cache>,
+) where
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started();
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            compositor,
+            renderer,
+            command,
+            runtime,
+            clipboard,
+            control_sender,
+            proxy,
+            debug,
+            window_manager,
+            ui_caches,
+        );
+    }
+
+    let subscription = application.subscription();
+    runtime.track(subscription.into_recipes());
+}
+
+/// Runs the actions of a [`Command`].
+fn run_command<A, C, E>(
+    application: &A,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    control_sender: &mut mpsc::UnboundedSender<Control>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    debug: &mut Debug,
+    window_manager: &mut WindowManager<A, C>,
+    ui_caches: &mut HashMap<window::Id, user_interface::Cache>,
+) where
+    A: Application,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    use crate::runtime::command;
+    use crate::runtime::system;
+    use crate::runtime::window;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Window(action) => match action {
+                window::Action::Spawn(id, settings) => {
+                    control_sender
+                        .start_send(Control::CreateWindow {
+                            id,
+                            settings,
+                            title: application.title(id),
+                        })
+                        .expect("Send control action");
+                }
+                window::Action::Close(id) => {
+                    let _ = window_manager.remove(id);
+                    let _ = ui_caches.remove(&id);
+                }
+                window::Action::Drag(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let _ = window.raw.drag_window();
+                    }
+                }
+                window::Action::Resize(id, size) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_inner_size(winit::dpi::LogicalSize {
+                            width: size.width,
+                            height: size.height,
+                        });
+                    }
+                }
+                window::Action::FetchSize(id, callback) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let size = window.raw.inner_size();
+
+                        proxy
+                            .send_event(callback(Size::new(
+                                size.width,
+                                size.height,
+                            )))
+                            .expect("Send message to event loop");
+                    }
+                }
+                window::Action::Maximize(id, maximized) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_maximized(maximized);
+                    }
+                }
+                window::Action::Minimize(id, minimized) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_minimized(minimized);
+                    }
+                }
+                window::Action::Move { id, x, y } => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_outer_position(
+                            winit::dpi::LogicalPosition { x, y },
+                        );
+                    }
+                }
+                window::Action::ChangeMode(id, mode) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_visible(conversion::visible(mode));
+                        window.raw.set_fullscreen(conversion::fullscreen(
+                            window.raw.current_monitor(),
+                            mode,
+                        ));
+                    }
+                }
+                window::Action::ChangeIcon(id, icon) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_window_icon(conversion::icon(icon));
+                    }
+                }
+                window::Action::FetchMode(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        let mode = if window.raw.is_visible().unwrap_or(true) {
+                            conversion::mode(window.raw.fullscreen())
+                        } else {
+                            core::window::Mode::Hidden
+                        };
+
+                        proxy
+                            .send_event(tag(mode))
+                            .expect("Send message to event loop");
+                    }
+                }
+                window::Action::ToggleMaximize(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_maximized(!window.raw.is_maximized());
+                    }
+                }
+                window::Action::ToggleDecorations(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.set_decorations(!window.raw.is_decorated());
+                    }
+                }
+                window::Action::RequestUserAttention(id, user_attention) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.request_user_attention(
+                            user_attention.map(conversion::user_attention),
+                        );
+                    }
+                }
+                window::Action::GainFocus(id) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window.raw.focus_window();
+                    }
+                }
+                window::Action::ChangeLevel(id, level) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        window
+                            .raw
+                            .set_window_level(conversion::window_level(level));
+                    }
+                }
+                window::Action::FetchId(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        proxy
+                            .send_event(tag(window.raw.id().into()))
+                            .expect("Send message to event loop");
+                    }
+                }
+                window::Action::Screenshot(id, tag) => {
+                    if let Some(window) = window_manager.get_mut(id) {
+                        // The renderer is shared by every window, so the
+                        // user interface must be drawn before capturing it.
+                        let mut user_interface = build_user_interface(
+                            application,
+                            ui_caches.remove(&id).unwrap_or_default(),
+                            renderer,
+                            window.state.logical_size(),
+                            debug,
+                            id,
+                        );
+
+                        let _ = user_interface.draw(
+                            renderer,
+                            window.state.theme(),
+                            &renderer::Style {
+                                text_color: window.state.text_color(),
+                            },
+                            window.state.cursor(),
+                        );
+
+                        let _ =
+                            ui_caches.insert(id, user_interface.into_cache());
+
+                        let bytes = compositor.screenshot(
+                            renderer,
+                            &mut window.surface,
+                            window.state.viewport(),
+                            window.state.background_color(),
+                            &debug.overlay(),
+                        );
+
+                        proxy
+                            .send_event(tag(window::Screenshot::new(
+                                bytes,
+                                window.state.physical_size(),
+                            )))
+                            .expect("Send message to event loop.");
+                    }
+                }
+            },
+            command::Action::System(action) => match action {
+                system::Action::QueryInformation(_tag) => {
+                    #[cfg(feature = "system")]
+                    {
+                        let graphics_info = compositor.fetch_information();
+                        let proxy = proxy.clone();
+
+                        let _ = std::thread::spawn(move || {
+                            let information =
+                                crate::system::information(graphics_info);
+
+                            let message = _tag(information);
+
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        });
+                    }
+                }
+            },
+            command::Action::Widget(action) => {
+                let mut current_operation = Some(action);
+
+                let mut user_interfaces = build_user_interfaces(
+                    application,
+                    debug,
+                    window_manager,
+                    std::mem::take(ui_caches),
+                    renderer,
+                );
+
+                while let Some(mut operation) = current_operation.take() {
+                    for user_interface in user_interfaces.values_mut() {
+                        user_interface.operate(renderer, operation.as_mut());
+                    }
+
+                    match operation.finish() {
+                        operation::Outcome::None => {}
+                        operation::Outcome::Some(message) => {
+                            proxy
+                                .send_event(message)
+                                .expect("Send message to event loop");
+                        }
+                        operation::Outcome::Chain(next) => {
+                            current_operation = Some(next);
+                        }
+                    }
+                }
+
+                *ui_caches = user_interfaces
+                    .into_iter()
+                    .map(|(id, user_interface)| {
+                        (id, user_interface.into_cache())
+                    })
+                    .collect();
+            }
+            command::Action::LoadFont { bytes, tagger } => {
+                use crate::core::text::Renderer;
+
+                // TODO: Error handling (?)
+                renderer.load_font(bytes);
+
+                proxy
+                    .send_event(tagger(Ok(())))
+                    .expect("Send message to event loop");
+            }
+        }
+    }
+}
+
+/// Builds a [`UserInterface`] for every window of the [`Application`] that
+/// has a cache.
+fn build_user_interfaces<'a, A, C>(
+    application: &'a A,
+    debug: &mut Debug,
+    window_manager: &mut WindowManager<A, C>,
+    mut cached_user_interfaces: HashMap<window::Id, user_interface::Cache>,
+    renderer: &mut A::Renderer,
+) -> HashMap<window::Id, UserInterface<'a, A::Message, A::Renderer>>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    cached_user_interfaces
+        .drain()
+        .filter_map(|(id, cache)| {
+            let window = window_manager.get_mut(id)?;
+
+            Some((
+                id,
+                build_user_interface(
+                    application,
+                    cache,
+                    renderer,
+                    window.state.logical_size(),
+                    debug,
+                    id,
+                ),
+            ))
+        })
+        .collect()
+}
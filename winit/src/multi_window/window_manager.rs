@@ -0,0 +1,150 @@
+use crate::core::{self, mouse, window};
+use crate::graphics::Compositor;
+use crate::multi_window::{Application, State};
+use crate::style::application::StyleSheet;
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// The windows of a multi-window [`Application`], indexed by [`window::Id`].
+#[allow(missing_debug_implementations)]
+pub struct WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    aliases: BTreeMap<winit::window::WindowId, window::Id>,
+    entries: BTreeMap<window::Id, Window<A, C>>,
+}
+
+impl<A, C> WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    /// Creates a new empty [`WindowManager`].
+    pub fn new() -> Self {
+        Self {
+            aliases: BTreeMap::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Starts managing the given winit window with the given [`window::Id`],
+    /// creating its [`State`] and surface.
+    pub fn insert(
+        &mut self,
+        id: window::Id,
+        raw: winit::window::Window,
+        application: &A,
+        compositor: &mut C,
+        exit_on_close_request: bool,
+    ) -> &mut Window<A, C> {
+        let raw = Arc::new(raw);
+        let state = State::new(application, id, &raw);
+        let physical_size = state.physical_size();
+        let surface = compositor.create_surface(
+            &*raw,
+            physical_size.width,
+            physical_size.height,
+        );
+
+        #[cfg(feature = "a11y")]
+        let accessibility =
+            crate::accessibility::Adapter::new(&raw, state.title());
+
+        let _ = self.aliases.insert(raw.id(), id);
+
+        let _ = self.entries.insert(
+            id,
+            Window {
+                raw,
+                state,
+                viewport_version: 0,
+                exit_on_close_request,
+                surface,
+                mouse_interaction: mouse::Interaction::Idle,
+                #[cfg(feature = "a11y")]
+                accessibility,
+            },
+        );
+
+        self.entries
+            .get_mut(&id)
+            .expect("Get window that was just inserted")
+    }
+
+    /// Returns true if no windows are being managed.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the managed windows.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (window::Id, &mut Window<A, C>)> {
+        self.entries.iter_mut().map(|(k, v)| (*k, v))
+    }
+
+    /// Returns the window with the given [`window::Id`], if any.
+    pub fn get_mut(&mut self, id: window::Id) -> Option<&mut Window<A, C>> {
+        self.entries.get_mut(&id)
+    }
+
+    /// Returns the [`window::Id`] and the window of the given winit window,
+    /// if it is being managed.
+    pub fn get_mut_alias(
+        &mut self,
+        id: winit::window::WindowId,
+    ) -> Option<(window::Id, &mut Window<A, C>)> {
+        let id = self.aliases.get(&id).copied()?;
+
+        Some((id, self.get_mut(id)?))
+    }
+
+    /// Stops managing the window with the given [`window::Id`], returning it.
+    pub fn remove(&mut self, id: window::Id) -> Option<Window<A, C>> {
+        let window = self.entries.remove(&id)?;
+        let _ = self.aliases.remove(&window.raw.id());
+
+        Some(window)
+    }
+}
+
+impl<A, C> Default for WindowManager<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A window managed by a [`WindowManager`].
+#[allow(missing_debug_implementations)]
+pub struct Window<A, C>
+where
+    A: Application,
+    C: Compositor<Renderer = A::Renderer>,
+    <A::Renderer as core::Renderer>::Theme: StyleSheet,
+{
+    /// The winit window.
+    pub raw: Arc<winit::window::Window>,
+    /// The [`State`] of the window.
+    pub state: State<A>,
+    /// The version of the viewport last presented to the surface.
+    pub viewport_version: usize,
+    /// Whether the window closes when the user requests it.
+    pub exit_on_close_request: bool,
+    /// The current mouse interaction of the window.
+    pub mouse_interaction: mouse::Interaction,
+    /// The surface of the window.
+    pub surface: C::Surface,
+    /// The accessibility adapter of the window.
+    #[cfg(feature = "a11y")]
+    pub accessibility: crate::accessibility::Adapter,
+}